@@ -0,0 +1,63 @@
+// alazar::chacha::block
+//
+//! The ChaCha block function.
+//
+
+/// The number of words in a block.
+pub(super) const BLOCK_WORDS: usize = 16;
+
+/// The constant words, the string `"expand 32-byte k"` in little endian order.
+const SIGMA: [u32; 4] = [0x6170_7865, 0x3320_646E, 0x7962_2D32, 0x6B20_6574];
+
+/// Returns the keystream block of the given `key`, block `counter` and `stream`,
+/// after `ROUNDS` rounds.
+///
+/// This is the original construction by Daniel J. Bernstein, with a 64-bit
+/// block counter followed by a 64-bit stream id.
+pub(super) const fn block<const ROUNDS: usize>(
+    key: &[u32; 8],
+    counter: u64,
+    stream: u64,
+) -> [u32; BLOCK_WORDS] {
+    #[rustfmt::skip]
+    let input = [
+        SIGMA[0], SIGMA[1], SIGMA[2], SIGMA[3],
+        key[0], key[1], key[2], key[3],
+        key[4], key[5], key[6], key[7],
+        counter as u32, (counter >> 32) as u32, stream as u32, (stream >> 32) as u32,
+    ];
+    let mut x = input;
+    let mut round = 0;
+    while round < ROUNDS {
+        // column round
+        quarter_round(&mut x, 0, 4, 8, 12);
+        quarter_round(&mut x, 1, 5, 9, 13);
+        quarter_round(&mut x, 2, 6, 10, 14);
+        quarter_round(&mut x, 3, 7, 11, 15);
+        // diagonal round
+        quarter_round(&mut x, 0, 5, 10, 15);
+        quarter_round(&mut x, 1, 6, 11, 12);
+        quarter_round(&mut x, 2, 7, 8, 13);
+        quarter_round(&mut x, 3, 4, 9, 14);
+        round += 2;
+    }
+    let mut i = 0;
+    while i < BLOCK_WORDS {
+        x[i] = x[i].wrapping_add(input[i]);
+        i += 1;
+    }
+    x
+}
+
+/// Mixes the words at the given indices of the working state.
+#[inline(always)]
+const fn quarter_round(x: &mut [u32; BLOCK_WORDS], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
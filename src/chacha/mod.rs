@@ -0,0 +1,20 @@
+// alazar::chacha
+//
+//! A cryptographically secure pseudo-random number generator based on
+//! the [ChaCha] stream cipher.
+//!
+//! This module defines the [`ChaCha`] generator, generic over its number of
+//! rounds, and the [`ChaCha8`], [`ChaCha12`] and [`ChaCha20`] aliases.
+//!
+//! Unlike the rest of the generators in this crate, its output can't be
+//! predicted without knowing its key, so it can be used for generating
+//! secrets like tokens or nonces, as long as it's seeded from a secure source
+//! of entropy. It's implemented without `unsafe` code and doesn't allocate.
+//!
+//! [ChaCha]: https://cr.yp.to/chacha.html
+//
+
+mod block;
+mod rng;
+
+pub use rng::{ChaCha, ChaCha12, ChaCha20, ChaCha8};
@@ -0,0 +1,368 @@
+// alazar::chacha::rng
+//
+//! The ChaCha generator.
+//
+
+use super::block::{block, BLOCK_WORDS};
+use crate::{misc::SplitMix64, prng::impl_prng};
+use core::fmt;
+
+/// The number of blocks generated at once.
+const BUF_BLOCKS: u64 = 4;
+
+/// The number of words in the buffer.
+const BUF_WORDS: usize = BLOCK_WORDS * BUF_BLOCKS as usize;
+
+/// The number of words at the start of the buffer that are used as the next
+/// key in fast key erasure mode, and never returned.
+const KEY_WORDS: usize = 8;
+
+/// The `ChaCha` cryptographically secure pseudo-random number generator,
+/// with `ROUNDS` rounds.
+///
+/// It has a 256-bit key, a 64-bit stream id and a 64-bit block counter, and
+/// generates 32-bit numbers. Each key and stream pair produces a keystream
+/// of `2^68` words, which can be sought with
+/// [`set_word_pos`][Self::set_word_pos].
+///
+/// The output is the original ChaCha keystream by Daniel J. Bernstein, read
+/// as little endian words, so it's the same as that of other implementations
+/// using a 64-bit counter and stream, like the `rand_chacha` crate.
+///
+/// `ROUNDS` must be even and non-zero. More rounds give a bigger security
+/// margin at the cost of speed: [`ChaCha20`] is the standard, [`ChaCha12`]
+/// is still considered secure, and [`ChaCha8`] is the fastest one without
+/// any known practical attack.
+///
+/// # Fast key erasure
+/// By default, anyone who learns the state of the generator can reproduce
+/// both its past and its future outputs. After calling
+/// [`with_key_erasure`][Self::with_key_erasure], the first 8 words of each
+/// batch of 4 blocks replace the key instead of being returned, and every word
+/// is wiped from the buffer as it's returned, so a compromised state doesn't
+/// reveal any earlier outputs. This follows [*Fast-key-erasure random-number
+/// generators*][erasure] by Daniel J. Bernstein (2017).
+///
+/// Its [`Debug`] implementation doesn't show the key nor the buffer.
+///
+/// [erasure]: https://blog.cr.yp.to/20170723-random.html
+#[derive(Clone, PartialEq, Eq)]
+pub struct ChaCha<const ROUNDS: usize> {
+    key: [u32; 8],
+    stream: u64,
+    /// The block counter of the start of the buffer.
+    counter: u64,
+    buf: [u32; BUF_WORDS],
+    /// The index of the next word in the buffer.
+    index: usize,
+    erasure: bool,
+}
+
+/// The [`ChaCha`] generator with 8 rounds.
+pub type ChaCha8 = ChaCha<8>;
+
+/// The [`ChaCha`] generator with 12 rounds.
+pub type ChaCha12 = ChaCha<12>;
+
+/// The [`ChaCha`] generator with 20 rounds.
+pub type ChaCha20 = ChaCha<20>;
+
+impl<const ROUNDS: usize> fmt::Debug for ChaCha<ROUNDS> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ChaCha")
+            .field("rounds", &ROUNDS)
+            .field("erasure", &self.erasure)
+            .finish_non_exhaustive()
+    }
+}
+
+// private associated items
+impl<const ROUNDS: usize> ChaCha<ROUNDS> {
+    /// Returns a field by field copy, since the type is not `Copy`.
+    #[inline]
+    const fn copy(&self) -> Self {
+        Self {
+            key: self.key,
+            stream: self.stream,
+            counter: self.counter,
+            buf: self.buf,
+            index: self.index,
+            erasure: self.erasure,
+        }
+    }
+
+    /// Fills the buffer with the blocks starting at the current counter.
+    ///
+    /// In fast key erasure mode it also replaces the key with the first words.
+    const fn generate(&mut self) {
+        let mut b = 0;
+        while b < BUF_BLOCKS {
+            let words = block::<ROUNDS>(&self.key, self.counter.wrapping_add(b), self.stream);
+            let mut i = 0;
+            while i < BLOCK_WORDS {
+                self.buf[b as usize * BLOCK_WORDS + i] = words[i];
+                i += 1;
+            }
+            b += 1;
+        }
+        if self.erasure {
+            let mut i = 0;
+            while i < KEY_WORDS {
+                self.key[i] = self.buf[i];
+                self.buf[i] = 0;
+                i += 1;
+            }
+            self.index = KEY_WORDS;
+        } else {
+            self.index = 0;
+        }
+    }
+
+    /// Advances the counter past the buffer and fills it again.
+    #[inline]
+    const fn refill(&mut self) {
+        self.counter = self.counter.wrapping_add(BUF_BLOCKS);
+        self.generate();
+    }
+
+    /// Consumes the current word.
+    #[inline]
+    const fn step(&mut self) {
+        if self.erasure {
+            self.buf[self.index] = 0;
+        }
+        self.index += 1;
+        if self.index == BUF_WORDS {
+            self.refill();
+        }
+    }
+}
+
+impl<const ROUNDS: usize> ChaCha<ROUNDS> {
+    /// Returns a seeded `ChaCha` generator from the given 256-bit `key`
+    /// and `stream` id.
+    ///
+    /// The key is read as 8 little endian words.
+    #[must_use]
+    pub const fn new(key: [u8; 32], stream: u64) -> Self {
+        const {
+            assert![
+                ROUNDS > 0 && ROUNDS % 2 == 0,
+                "ROUNDS must be even and non-zero"
+            ]
+        };
+        let mut words = [0; 8];
+        let mut i = 0;
+        while i < 8 {
+            let k = i * 4;
+            words[i] = u32::from_le_bytes([key[k], key[k + 1], key[k + 2], key[k + 3]]);
+            i += 1;
+        }
+        let mut new = Self {
+            key: words,
+            stream,
+            counter: 0,
+            buf: [0; BUF_WORDS],
+            index: 0,
+            erasure: false,
+        };
+        new.generate();
+        new
+    }
+
+    /// Returns the generator in fast key erasure mode.
+    ///
+    /// The buffered words are discarded, and the next key is taken
+    /// from the following blocks, which haven't been returned.
+    ///
+    /// Afterwards, [`set_word_pos`][Self::set_word_pos] can't be used anymore.
+    #[must_use]
+    pub const fn with_key_erasure(mut self) -> Self {
+        if !self.erasure {
+            self.erasure = true;
+            self.refill();
+        }
+        self
+    }
+
+    /// Returns `true` if the generator is in fast key erasure mode.
+    #[inline]
+    #[must_use]
+    pub const fn is_key_erasure(&self) -> bool {
+        self.erasure
+    }
+
+    /// Returns the stream id.
+    #[inline]
+    #[must_use]
+    pub const fn stream(&self) -> u64 {
+        self.stream
+    }
+
+    /// Sets the stream id, keeping the position in the keystream.
+    ///
+    /// In fast key erasure mode the position is not kept, and the new stream
+    /// starts with the following blocks.
+    pub const fn set_stream(&mut self, stream: u64) {
+        self.stream = stream;
+        if self.erasure {
+            self.refill();
+        } else {
+            let index = self.index;
+            self.generate();
+            self.index = index;
+        }
+    }
+
+    /// Returns the position of the next word in the keystream,
+    /// between `0` and `2^68`.
+    ///
+    /// In fast key erasure mode, where each batch of blocks uses a different
+    /// key, it only tracks the block counter.
+    #[must_use]
+    pub const fn word_pos(&self) -> u128 {
+        let pos = self.counter as u128 * BLOCK_WORDS as u128 + self.index as u128;
+        pos % (1 << 68)
+    }
+
+    /// Sets the position of the next word in the keystream.
+    ///
+    /// Only the lowest 68 bits of `pos` are used.
+    ///
+    /// Returns `None` in fast key erasure mode, where seeking isn't possible
+    /// since each key depends on the previous keystream.
+    pub const fn set_word_pos(&mut self, pos: u128) -> Option<()> {
+        if self.erasure {
+            return None;
+        }
+        self.counter = (pos / BLOCK_WORDS as u128) as u64;
+        self.generate();
+        self.index = (pos % BLOCK_WORDS as u128) as usize;
+        Some(())
+    }
+
+    /// Returns the current random `u32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u32(&self) -> u32 {
+        self.buf[self.index]
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        let result = self.current_u32();
+        self.step();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut new = self.copy();
+        new.step();
+        new
+    }
+}
+
+/// # Extra constructors
+impl<const ROUNDS: usize> ChaCha<ROUNDS> {
+    /// Returns a seeded `ChaCha` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`] into the key, with stream `0`.
+    ///
+    /// This is only meant for reproducible results, since the key will be
+    /// one of only `2^64` and easy to guess. Use [`new`][Self::new] with a
+    /// key from a secure source of entropy for unpredictable results.
+    #[must_use]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new(SplitMix64::seed_u8s(seed), 0)
+    }
+}
+
+impl_prng![@[const ROUNDS: usize] ChaCha<ROUNDS>: u32, current_u32, next_u32];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::ChaCha;
+    use crate::prng::impl_rand_core;
+    use rand_core::{CryptoRng, SeedableRng};
+
+    impl_rand_core![@[const ROUNDS: usize] ChaCha<ROUNDS>];
+
+    impl<const ROUNDS: usize> CryptoRng for ChaCha<ROUNDS> {}
+
+    impl<const ROUNDS: usize> SeedableRng for ChaCha<ROUNDS> {
+        type Seed = [u8; 32];
+
+        /// Uses the seed as the key, with stream `0`.
+        fn from_seed(seed: Self::Seed) -> Self {
+            Self::new(seed, 0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // test vectors 1 and 2 from
+        // https://tools.ietf.org/html/draft-nir-cfrg-chacha20-poly1305-04
+        let mut rng = ChaCha20::new([0; 32], 0);
+        for expected in [
+            0xADE0_B876,
+            0x903D_F1A0,
+            0xE56A_5D40,
+            0x28BD_8653,
+            0xB819_D2BD,
+            0x1AED_8DA0,
+            0xCCEF_36A8,
+            0xC70D_778B,
+            0x7C59_41DA,
+            0x8D48_5751,
+            0x3FE0_2477,
+            0x374A_D8B8,
+            0xF4B8_436A,
+            0x1CA1_1815,
+            0x69B6_87C3,
+            0x8665_EEB2,
+            0xBEE7_079F,
+            0x7A38_5155,
+        ] {
+            assert_eq![rng.next_u32(), expected];
+        }
+    }
+
+    #[test]
+    fn seek() {
+        let mut a = ChaCha8::from_u64_seed(7);
+        let mut b = a.clone();
+        for _ in 0..100 {
+            let _ = a.next_u32();
+        }
+        b.set_word_pos(100).unwrap();
+        assert_eq![a.word_pos(), 100];
+        assert_eq![b.word_pos(), 100];
+        for _ in 0..100 {
+            assert_eq![a.next_u32(), b.next_u32()];
+        }
+    }
+
+    #[test]
+    fn key_erasure() {
+        let mut e = ChaCha8::from_u64_seed(7).with_key_erasure();
+        assert![e.set_word_pos(0).is_none()];
+        // the first batch skips the buffered one, and its first 8 words
+        let mut k = ChaCha8::from_u64_seed(7);
+        k.set_word_pos(72).unwrap();
+        for _ in 0..56 {
+            assert_eq![e.next_u32(), k.next_u32()];
+        }
+        // the next batch uses a new key
+        assert![e.next_u32() != k.next_u32()];
+    }
+}
@@ -0,0 +1,297 @@
+// alazar::chaotic::jsf
+//
+//! Jenkins small fast generators.
+//
+
+use crate::prng::impl_prng;
+use devela::convert::{
+    u32_from_u16_le, u32_from_u8_le, u64_from_u16_le, u64_from_u32_le, u64_from_u8_le,
+};
+
+/// The `JSF32` pseudo-random number generator (*Jenkins Small Fast*).
+///
+/// It has a 128-bit state and generates 32-bit numbers.
+///
+/// It has no guaranteed period, but its seeding routine fixes the first
+/// word of the state and discards the first 20 results, and none of the
+/// `2^32` seeds it accepts is known to lead into a short cycle.
+/// The average period is about `2^126`.
+///
+/// It was published by Bob Jenkins as [*A small noncryptographic PRNG*][link]
+/// (2007), and is also known as `ranval`.
+///
+/// [link]: https://burtleburtle.net/bob/rand/smallprng.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Jsf32 {
+    a: u32,
+    b: u32,
+    c: u32,
+    d: u32,
+}
+
+impl Default for Jsf32 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Jsf32 {
+    const DEFAULT_SEED: u32 = 0xDEFA0017;
+
+    /// The fixed first word of the seeded state.
+    const A: u32 = 0xF1EA_5EED;
+
+    /// The number of initial results discarded by the seeding routine.
+    const WARMUP: usize = 20;
+}
+
+impl Jsf32 {
+    /// Returns a seeded `JSF32` generator from the given 32-bit seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u32) -> Self {
+        let mut new = Self {
+            a: Self::A,
+            b: seed,
+            c: seed,
+            d: seed,
+        };
+        let mut i = 0;
+        while i < Self::WARMUP {
+            new = new.next_new();
+            i += 1;
+        }
+        new
+    }
+
+    /// Returns the current random `u32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u32(&self) -> u32 {
+        self.d
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        *self = self.next_new();
+        self.d
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let e = self.a.wrapping_sub(self.b.rotate_left(27));
+        let a = self.b ^ self.c.rotate_left(17);
+        let b = self.c.wrapping_add(self.d);
+        let c = self.d.wrapping_add(e);
+        let d = e.wrapping_add(a);
+        Self { a, b, c, d }
+    }
+}
+
+/// # Extra constructors
+impl Jsf32 {
+    /// Returns a seeded `JSF32` generator from the given 32-bit seed.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new1_u32(seed: u32) -> Self {
+        Self::new(seed)
+    }
+
+    /// Returns a seeded `JSF32` generator from the given 2 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new2_u16(seeds: [u16; 2]) -> Self {
+        Self::new(u32_from_u16_le(seeds))
+    }
+
+    /// Returns a seeded `JSF32` generator from the given 4 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u8(seeds: [u8; 4]) -> Self {
+        Self::new(u32_from_u8_le(seeds))
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// The `JSF64` pseudo-random number generator (*Jenkins Small Fast*).
+///
+/// It has a 256-bit state and generates 64-bit numbers.
+///
+/// This is the 64-bit version of [`Jsf32`], with the same seeding routine.
+/// It has no guaranteed period, but no short cycles are known and the
+/// average is about `2^255`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Jsf64 {
+    a: u64,
+    b: u64,
+    c: u64,
+    d: u64,
+}
+
+impl Default for Jsf64 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Jsf64 {
+    const DEFAULT_SEED: u64 = 0xDEFA0017_DEFA0017;
+
+    /// The fixed first word of the seeded state.
+    const A: u64 = 0xF1EA_5EED;
+
+    /// The number of initial results discarded by the seeding routine.
+    const WARMUP: usize = 20;
+}
+
+impl Jsf64 {
+    /// Returns a seeded `JSF64` generator from the given 64-bit seed.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        let mut new = Self {
+            a: Self::A,
+            b: seed,
+            c: seed,
+            d: seed,
+        };
+        let mut i = 0;
+        while i < Self::WARMUP {
+            new = new.next_new();
+            i += 1;
+        }
+        new
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        self.d
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        *self = self.next_new();
+        self.d
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let e = self.a.wrapping_sub(self.b.rotate_left(7));
+        let a = self.b ^ self.c.rotate_left(13);
+        let b = self.c.wrapping_add(self.d.rotate_left(37));
+        let c = self.d.wrapping_add(e);
+        let d = e.wrapping_add(a);
+        Self { a, b, c, d }
+    }
+}
+
+/// # Extra constructors
+impl Jsf64 {
+    /// Returns a seeded `JSF64` generator from the given 64-bit seed.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new1_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+
+    /// Returns a seeded `JSF64` generator from the given 2 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new2_u32(seeds: [u32; 2]) -> Self {
+        Self::new(u64_from_u32_le(seeds))
+    }
+
+    /// Returns a seeded `JSF64` generator from the given 4 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u16(seeds: [u16; 4]) -> Self {
+        Self::new(u64_from_u16_le(seeds))
+    }
+
+    /// Returns a seeded `JSF64` generator from the given 8 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u8(seeds: [u8; 8]) -> Self {
+        Self::new(u64_from_u8_le(seeds))
+    }
+}
+
+impl_prng![Jsf32: u32, current_u32, next_u32];
+impl_prng![Jsf64: u64, current_u64, next_u64];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::{Jsf32, Jsf64};
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![Jsf32];
+
+    impl SeedableRng for Jsf32 {
+        type Seed = [u8; 4];
+
+        fn from_seed(seed: Self::Seed) -> Self {
+            Self::new(u32::from_le_bytes(seed))
+        }
+    }
+
+    impl_rand_core![Jsf64];
+
+    impl SeedableRng for Jsf64 {
+        type Seed = [u8; 8];
+
+        fn from_seed(seed: Self::Seed) -> Self {
+            Self::new(u64::from_le_bytes(seed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The expected values are from the reference implementation at
+    // https://burtleburtle.net/bob/rand/smallprng.html, seeded with `raninit(42)`.
+
+    #[test]
+    fn jsf32_reference() {
+        let mut rng = Jsf32::new(42);
+        for expected in [1230419127, 4080097750, 2014035305, 565785200] {
+            assert_eq![rng.next_u32(), expected];
+        }
+    }
+
+    #[test]
+    fn jsf64_reference() {
+        let mut rng = Jsf64::new(42);
+        for expected in [
+            11921485425870369842,
+            6950967119895308506,
+            3738120138616583258,
+            12954590915796698081,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+}
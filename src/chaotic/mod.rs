@@ -0,0 +1,22 @@
+// alazar::chaotic
+//
+//! Small fast chaotic pseudo-random number generators.
+//!
+//! This module defines several types:
+//! - *Small Fast Counting* generators with a counter that guarantees a
+//!   minimum period: ([`Sfc64`], [`Sfc32`]).
+//! - *Jenkins Small Fast* generators: ([`Jsf64`], [`Jsf32`]).
+//! - *Romu* generators, the fastest ones here: ([`RomuTrio`], [`RomuDuo`]).
+//!
+//! Unlike linear generators like [*XorShift*][crate::xorshift], their state
+//! transition is nonlinear, so most of them don't have a single known period,
+//! and rely on their seeding routine to avoid the known short cycles.
+//
+
+mod jsf;
+mod romu;
+mod sfc;
+
+pub use jsf::{Jsf32, Jsf64};
+pub use romu::{RomuDuo, RomuTrio};
+pub use sfc::{Sfc32, Sfc64};
@@ -0,0 +1,438 @@
+// alazar::chaotic::romu
+//
+//! Romu generators.
+//
+
+use crate::{misc::SplitMix64, prng::impl_prng};
+use devela::convert::{u128_into_u64_le, u64_from_u16_le, u64_from_u32_le, u64_from_u8_le};
+
+/// The multiplier shared by the Romu generators.
+const MULTIPLIER: u64 = 15241094284759029579;
+
+/// The `RomuTrio` pseudo-random number generator.
+///
+/// It has a 192-bit state and generates 64-bit numbers.
+///
+/// It combines rotations and a multiplication instead of additions, which
+/// makes it one of the fastest generators on modern processors, thanks to
+/// its instruction-level parallelism.
+///
+/// It has no guaranteed period, but for random seeds the probability of
+/// falling into a cycle too short for any practical use is negligible.
+/// The all-zero state is forbidden, and the recommended way to seed it
+/// is [`from_u64_seed`][Self::from_u64_seed].
+///
+/// It was published by Mark A. Overton in
+/// [*Romu: Fast Nonlinear Pseudo-Random Number Generators Providing
+/// High Quality*][paper] (2020).
+///
+/// [paper]: https://arxiv.org/abs/2002.11331
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RomuTrio([u64; 3]);
+
+impl Default for RomuTrio {
+    fn default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl RomuTrio {
+    const DEFAULT_SEED: [u64; 3] = [0xDEFA0017_DEFA0017; 3];
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+
+    #[cold]
+    #[inline]
+    #[allow(dead_code)]
+    const fn cold_path_default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+impl RomuTrio {
+    /// Returns a seeded `RomuTrio` generator from the given 3 × 64-bit seeds.
+    ///
+    /// Returns `None` if all given seeds are `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(seeds: [u64; 3]) -> Option<Self> {
+        if (seeds[0] | seeds[1] | seeds[2]) == 0 {
+            Self::cold_path_result()
+        } else {
+            Some(Self(seeds))
+        }
+    }
+
+    /// Returns a seeded `RomuTrio` generator from the given 3 × 64-bit seeds,
+    /// unchecked.
+    ///
+    /// The seeds must not be all `0`, otherwise every result will also be `0`.
+    ///
+    /// # Panics
+    /// Panics in debug if the seeds are all `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(seeds: [u64; 3]) -> Self {
+        debug_assert![
+            (seeds[0] | seeds[1] | seeds[2]) != 0,
+            "Seeds must be non-zero"
+        ];
+        Self(seeds)
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        self.0[0]
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.current_u64();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let [x, y, z] = self.0;
+        Self([
+            z.wrapping_mul(MULTIPLIER),
+            y.wrapping_sub(x).rotate_left(12),
+            z.wrapping_sub(y).rotate_left(44),
+        ])
+    }
+}
+
+/// # Extra constructors
+impl RomuTrio {
+    /// Returns a seeded `RomuTrio` generator from the given 3 × 64-bit seeds.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new3_u64(seeds: [u64; 3]) -> Option<Self> {
+        Self::new(seeds)
+    }
+
+    /// Returns a seeded `RomuTrio` generator from the given 6 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new6_u32(seeds: [u32; 6]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u32_le([s[0], s[1]]),
+            u64_from_u32_le([s[2], s[3]]),
+            u64_from_u32_le([s[4], s[5]]),
+        ])
+    }
+
+    /// Returns a seeded `RomuTrio` generator from the given 12 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new12_u16(seeds: [u16; 12]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u16_le([s[0], s[1], s[2], s[3]]),
+            u64_from_u16_le([s[4], s[5], s[6], s[7]]),
+            u64_from_u16_le([s[8], s[9], s[10], s[11]]),
+        ])
+    }
+
+    /// Returns a seeded `RomuTrio` generator from the given 24 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new24_u8(seeds: [u8; 24]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u8_le([s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]]),
+            u64_from_u8_le([s[8], s[9], s[10], s[11], s[12], s[13], s[14], s[15]]),
+            u64_from_u8_le([s[16], s[17], s[18], s[19], s[20], s[21], s[22], s[23]]),
+        ])
+    }
+
+    /// Returns a seeded `RomuTrio` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_u64s(seed))
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// The `RomuDuo` pseudo-random number generator.
+///
+/// It has a 128-bit state and generates 64-bit numbers.
+///
+/// It's a smaller version of [`RomuTrio`], a bit faster when its results
+/// are used in fewer computations, but with a shorter expected period,
+/// which makes it better suited for smaller jobs.
+///
+/// The all-zero state is forbidden, and the recommended way to seed it
+/// is [`from_u64_seed`][Self::from_u64_seed].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RomuDuo([u64; 2]);
+
+impl Default for RomuDuo {
+    fn default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl RomuDuo {
+    const DEFAULT_SEED: [u64; 2] = [0xDEFA0017_DEFA0017; 2];
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+
+    #[cold]
+    #[inline]
+    #[allow(dead_code)]
+    const fn cold_path_default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+impl RomuDuo {
+    /// Returns a seeded `RomuDuo` generator from the given 2 × 64-bit seeds.
+    ///
+    /// Returns `None` if all given seeds are `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(seeds: [u64; 2]) -> Option<Self> {
+        if (seeds[0] | seeds[1]) == 0 {
+            Self::cold_path_result()
+        } else {
+            Some(Self(seeds))
+        }
+    }
+
+    /// Returns a seeded `RomuDuo` generator from the given 2 × 64-bit seeds,
+    /// unchecked.
+    ///
+    /// The seeds must not be all `0`, otherwise every result will also be `0`.
+    ///
+    /// # Panics
+    /// Panics in debug if the seeds are all `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(seeds: [u64; 2]) -> Self {
+        debug_assert![(seeds[0] | seeds[1]) != 0, "Seeds must be non-zero"];
+        Self(seeds)
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        self.0[0]
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.current_u64();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let [x, y] = self.0;
+        Self([
+            y.wrapping_mul(MULTIPLIER),
+            y.rotate_left(36)
+                .wrapping_add(y.rotate_left(15))
+                .wrapping_sub(x),
+        ])
+    }
+}
+
+/// # Extra constructors
+impl RomuDuo {
+    /// Returns a seeded `RomuDuo` generator from the given 128-bit seed.
+    ///
+    /// The seed will be split in little endian order.
+    #[inline]
+    pub const fn new1_u128(seed: u128) -> Option<Self> {
+        Self::new(u128_into_u64_le(seed))
+    }
+
+    /// Returns a seeded `RomuDuo` generator from the given 2 × 64-bit seeds.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new2_u64(seeds: [u64; 2]) -> Option<Self> {
+        Self::new(seeds)
+    }
+
+    /// Returns a seeded `RomuDuo` generator from the given 4 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u32(seeds: [u32; 4]) -> Option<Self> {
+        Self::new([
+            u64_from_u32_le([seeds[0], seeds[1]]),
+            u64_from_u32_le([seeds[2], seeds[3]]),
+        ])
+    }
+
+    /// Returns a seeded `RomuDuo` generator from the given 8 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u16(seeds: [u16; 8]) -> Option<Self> {
+        Self::new([
+            u64_from_u16_le([seeds[0], seeds[1], seeds[2], seeds[3]]),
+            u64_from_u16_le([seeds[4], seeds[5], seeds[6], seeds[7]]),
+        ])
+    }
+
+    /// Returns a seeded `RomuDuo` generator from the given 16 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new16_u8(seeds: [u8; 16]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u8_le([s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]]),
+            u64_from_u8_le([s[8], s[9], s[10], s[11], s[12], s[13], s[14], s[15]]),
+        ])
+    }
+
+    /// Returns a seeded `RomuDuo` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_u64s(seed))
+    }
+}
+
+impl_prng![RomuTrio: u64, current_u64, next_u64];
+impl_prng![RomuDuo: u64, current_u64, next_u64];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::{RomuDuo, RomuTrio};
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![RomuTrio];
+
+    impl SeedableRng for RomuTrio {
+        type Seed = [u8; 24];
+
+        /// When seeded with zero this implementation uses the default seed
+        /// value as the cold path.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut seed_u64s = [0u64; 3];
+            if seed == [0; 24] {
+                Self::cold_path_default()
+            } else {
+                for i in 0..3 {
+                    seed_u64s[i] = u64::from_le_bytes([
+                        seed[i * 8],
+                        seed[i * 8 + 1],
+                        seed[i * 8 + 2],
+                        seed[i * 8 + 3],
+                        seed[i * 8 + 4],
+                        seed[i * 8 + 5],
+                        seed[i * 8 + 6],
+                        seed[i * 8 + 7],
+                    ]);
+                }
+                Self::new_unchecked(seed_u64s)
+            }
+        }
+    }
+
+    impl_rand_core![RomuDuo];
+
+    impl SeedableRng for RomuDuo {
+        type Seed = [u8; 16];
+
+        /// When seeded with zero this implementation uses the default seed
+        /// value as the cold path.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut seed_u64s = [0u64; 2];
+            if seed == [0; 16] {
+                Self::cold_path_default()
+            } else {
+                for i in 0..2 {
+                    seed_u64s[i] = u64::from_le_bytes([
+                        seed[i * 8],
+                        seed[i * 8 + 1],
+                        seed[i * 8 + 2],
+                        seed[i * 8 + 3],
+                        seed[i * 8 + 4],
+                        seed[i * 8 + 5],
+                        seed[i * 8 + 6],
+                        seed[i * 8 + 7],
+                    ]);
+                }
+                Self::new_unchecked(seed_u64s)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The expected values are from the reference implementation in the paper,
+    // starting from the states `(1, 2, 3)` and `(1, 2)`.
+
+    #[test]
+    fn romu_trio_reference() {
+        let mut rng = RomuTrio::new([1, 2, 3]).unwrap();
+        for expected in [
+            1,
+            8829794706857985505,
+            14228190636816728064,
+            7047022733925001397,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+        assert![RomuTrio::new([0; 3]).is_none()];
+    }
+
+    #[test]
+    fn romu_duo_reference() {
+        let mut rng = RomuDuo::new([1, 2]).unwrap();
+        for expected in [
+            1,
+            12035444495808507542,
+            6091112088061520053,
+            15247473810760332814,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+        assert![RomuDuo::new([0; 2]).is_none()];
+    }
+}
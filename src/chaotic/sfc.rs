@@ -0,0 +1,361 @@
+// alazar::chaotic::sfc
+//
+//! Small fast counting generators.
+//
+
+use crate::{misc::SplitMix64, prng::impl_prng};
+use devela::convert::{
+    u32_from_u16_le, u32_from_u8_le, u64_from_u16_le, u64_from_u32_le, u64_from_u8_le,
+};
+
+/// The `SFC64` pseudo-random number generator (*Small Fast Counting*).
+///
+/// It has a 256-bit state and generates 64-bit numbers.
+///
+/// Its state is made of 3 chaotic words and a counter that increments on
+/// each step, so every cycle is at least `2^64` long, and there are no
+/// forbidden states. The average period is about `2^255`.
+///
+/// It was designed by Chris Doty-Humphrey for [PractRand].
+///
+/// [PractRand]: https://pracrand.sourceforge.net/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sfc64 {
+    a: u64,
+    b: u64,
+    c: u64,
+    counter: u64,
+}
+
+impl Default for Sfc64 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Sfc64 {
+    const DEFAULT_SEED: [u64; 3] = [0xDEFA0017_DEFA0017; 3];
+
+    /// The number of initial results discarded by the seeding routine.
+    const WARMUP: usize = 18;
+}
+
+impl Sfc64 {
+    /// Returns a seeded `SFC64` generator from the given 3 × 64-bit seeds.
+    ///
+    /// The counter starts at `1`, and the first 18 results are discarded
+    /// so that similar seeds diverge.
+    #[inline]
+    #[must_use]
+    pub const fn new(seeds: [u64; 3]) -> Self {
+        let mut new = Self {
+            a: seeds[0],
+            b: seeds[1],
+            c: seeds[2],
+            counter: 1,
+        };
+        let mut i = 0;
+        while i < Self::WARMUP {
+            new = new.next_new();
+            i += 1;
+        }
+        new
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        self.a.wrapping_add(self.b).wrapping_add(self.counter)
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.current_u64();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let tmp = self.current_u64();
+        Self {
+            a: self.b ^ (self.b >> 11),
+            b: self.c.wrapping_add(self.c << 3),
+            c: self.c.rotate_left(24).wrapping_add(tmp),
+            counter: self.counter.wrapping_add(1),
+        }
+    }
+}
+
+/// # Extra constructors
+impl Sfc64 {
+    /// Returns a seeded `SFC64` generator from the given 3 × 64-bit seeds.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new3_u64(seeds: [u64; 3]) -> Self {
+        Self::new(seeds)
+    }
+
+    /// Returns a seeded `SFC64` generator from the given 6 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new6_u32(seeds: [u32; 6]) -> Self {
+        let s = seeds;
+        Self::new([
+            u64_from_u32_le([s[0], s[1]]),
+            u64_from_u32_le([s[2], s[3]]),
+            u64_from_u32_le([s[4], s[5]]),
+        ])
+    }
+
+    /// Returns a seeded `SFC64` generator from the given 12 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new12_u16(seeds: [u16; 12]) -> Self {
+        let s = seeds;
+        Self::new([
+            u64_from_u16_le([s[0], s[1], s[2], s[3]]),
+            u64_from_u16_le([s[4], s[5], s[6], s[7]]),
+            u64_from_u16_le([s[8], s[9], s[10], s[11]]),
+        ])
+    }
+
+    /// Returns a seeded `SFC64` generator from the given 24 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new24_u8(seeds: [u8; 24]) -> Self {
+        let s = seeds;
+        Self::new([
+            u64_from_u8_le([s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]]),
+            u64_from_u8_le([s[8], s[9], s[10], s[11], s[12], s[13], s[14], s[15]]),
+            u64_from_u8_le([s[16], s[17], s[18], s[19], s[20], s[21], s[22], s[23]]),
+        ])
+    }
+
+    /// Returns a seeded `SFC64` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new(SplitMix64::seed_u64s(seed))
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// The `SFC32` pseudo-random number generator (*Small Fast Counting*).
+///
+/// It has a 128-bit state and generates 32-bit numbers.
+///
+/// This is the 32-bit version of [`Sfc64`], with cycles of at least `2^32`
+/// and an average period of about `2^127`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sfc32 {
+    a: u32,
+    b: u32,
+    c: u32,
+    counter: u32,
+}
+
+impl Default for Sfc32 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Sfc32 {
+    const DEFAULT_SEED: [u32; 3] = [0xDEFA0017; 3];
+
+    /// The number of initial results discarded by the seeding routine.
+    const WARMUP: usize = 15;
+}
+
+impl Sfc32 {
+    /// Returns a seeded `SFC32` generator from the given 3 × 32-bit seeds.
+    ///
+    /// The counter starts at `1`, and the first 15 results are discarded
+    /// so that similar seeds diverge.
+    #[inline]
+    #[must_use]
+    pub const fn new(seeds: [u32; 3]) -> Self {
+        let mut new = Self {
+            a: seeds[0],
+            b: seeds[1],
+            c: seeds[2],
+            counter: 1,
+        };
+        let mut i = 0;
+        while i < Self::WARMUP {
+            new = new.next_new();
+            i += 1;
+        }
+        new
+    }
+
+    /// Returns the current random `u32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u32(&self) -> u32 {
+        self.a.wrapping_add(self.b).wrapping_add(self.counter)
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        let result = self.current_u32();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let tmp = self.current_u32();
+        Self {
+            a: self.b ^ (self.b >> 9),
+            b: self.c.wrapping_add(self.c << 3),
+            c: self.c.rotate_left(21).wrapping_add(tmp),
+            counter: self.counter.wrapping_add(1),
+        }
+    }
+}
+
+/// # Extra constructors
+impl Sfc32 {
+    /// Returns a seeded `SFC32` generator from the given 3 × 32-bit seeds.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new3_u32(seeds: [u32; 3]) -> Self {
+        Self::new(seeds)
+    }
+
+    /// Returns a seeded `SFC32` generator from the given 6 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new6_u16(seeds: [u16; 6]) -> Self {
+        Self::new([
+            u32_from_u16_le([seeds[0], seeds[1]]),
+            u32_from_u16_le([seeds[2], seeds[3]]),
+            u32_from_u16_le([seeds[4], seeds[5]]),
+        ])
+    }
+
+    /// Returns a seeded `SFC32` generator from the given 12 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new12_u8(seeds: [u8; 12]) -> Self {
+        Self::new([
+            u32_from_u8_le([seeds[0], seeds[1], seeds[2], seeds[3]]),
+            u32_from_u8_le([seeds[4], seeds[5], seeds[6], seeds[7]]),
+            u32_from_u8_le([seeds[8], seeds[9], seeds[10], seeds[11]]),
+        ])
+    }
+
+    /// Returns a seeded `SFC32` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new(SplitMix64::seed_u32s(seed))
+    }
+}
+
+impl_prng![Sfc64: u64, current_u64, next_u64];
+impl_prng![Sfc32: u32, current_u32, next_u32];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::{Sfc32, Sfc64};
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![Sfc64];
+
+    impl SeedableRng for Sfc64 {
+        type Seed = [u8; 24];
+
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut seed_u64s = [0u64; 3];
+            for i in 0..3 {
+                seed_u64s[i] = u64::from_le_bytes([
+                    seed[i * 8],
+                    seed[i * 8 + 1],
+                    seed[i * 8 + 2],
+                    seed[i * 8 + 3],
+                    seed[i * 8 + 4],
+                    seed[i * 8 + 5],
+                    seed[i * 8 + 6],
+                    seed[i * 8 + 7],
+                ]);
+            }
+            Self::new(seed_u64s)
+        }
+    }
+
+    impl_rand_core![Sfc32];
+
+    impl SeedableRng for Sfc32 {
+        type Seed = [u8; 12];
+
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut seed_u32s = [0u32; 3];
+            for i in 0..3 {
+                seed_u32s[i] = u32::from_le_bytes([
+                    seed[i * 4],
+                    seed[i * 4 + 1],
+                    seed[i * 4 + 2],
+                    seed[i * 4 + 3],
+                ]);
+            }
+            Self::new(seed_u32s)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The expected values are from the `sfc64` and `sfc32` generators
+    // of PractRand, seeded with `seed(1, 2, 3)`.
+
+    #[test]
+    fn sfc64_reference() {
+        let mut rng = Sfc64::new([1, 2, 3]);
+        for expected in [
+            13778394407655932397,
+            14795018124788897094,
+            10212032389680752616,
+            12812145998341648405,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn sfc32_reference() {
+        let mut rng = Sfc32::new([1, 2, 3]);
+        for expected in [3987121759, 92551270, 1122162139, 24194941] {
+            assert_eq![rng.next_u32(), expected];
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// alazar::counter
+//
+//! Counter-based pseudo-random number generators.
+//!
+//! This module defines several types from the [Random123] library:
+//! - *Philox* generators, based on multiplications:
+//!   ([`Philox4x32`], [`Philox4x64`]).
+//! - *Threefry* generators, based on additions, rotations and xors:
+//!   ([`Threefry4x32`], [`Threefry4x64`]).
+//!
+//! And the smaller *Squares* generators, based on squarings:
+//! ([`Squares32`], [`Squares64`]).
+//!
+//! Their output is a keyed function of a counter, so they have almost no
+//! state, and any block of the sequence can be computed in *O(1)* with their
+//! `at` method, which makes them well suited for reproducible parallel
+//! computations: each worker can use its own key, or its own counter range.
+//! They also implement the sequential API of the rest of the generators,
+//! returning the words of each block in order.
+//!
+//! [Random123]: https://github.com/DEShawResearch/random123
+//
+
+mod philox;
+mod squares;
+mod threefry;
+
+pub use philox::{Philox4x32, Philox4x64};
+pub use squares::{Squares32, Squares64};
+pub use threefry::{Threefry4x32, Threefry4x64};
+
+/// Returns the given counter plus one, in little endian word order.
+const fn increment_u32(mut counter: [u32; 4]) -> [u32; 4] {
+    let mut i = 0;
+    while i < 4 {
+        counter[i] = counter[i].wrapping_add(1);
+        if counter[i] != 0 {
+            break;
+        }
+        i += 1;
+    }
+    counter
+}
+
+/// Returns the given counter plus one, in little endian word order.
+const fn increment_u64(mut counter: [u64; 4]) -> [u64; 4] {
+    let mut i = 0;
+    while i < 4 {
+        counter[i] = counter[i].wrapping_add(1);
+        if counter[i] != 0 {
+            break;
+        }
+        i += 1;
+    }
+    counter
+}
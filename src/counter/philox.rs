@@ -0,0 +1,537 @@
+// alazar::counter::philox
+//
+//! Philox generators.
+//
+
+use super::{increment_u32, increment_u64};
+use crate::{misc::SplitMix64, prng::impl_prng};
+use devela::convert::{
+    u128_into_u64_le, u32_from_u16_le, u32_from_u8_le, u64_from_u16_le, u64_from_u32_le,
+    u64_from_u8_le, u64_into_u32_le,
+};
+
+/// The number of rounds of the Philox generators.
+const PHILOX_ROUNDS: usize = 10;
+
+/// Returns the Philox4x32-10 block of the given `counter` and `key`.
+const fn philox4x32(counter: [u32; 4], key: [u32; 2]) -> [u32; 4] {
+    const M0: u64 = 0xD251_1F53;
+    const M1: u64 = 0xCD9E_8D57;
+    const W0: u32 = 0x9E37_79B9; // golden ratio
+    const W1: u32 = 0xBB67_AE85; // sqrt(3) - 1
+    let (mut x, mut k) = (counter, key);
+    let mut round = 0;
+    while round < PHILOX_ROUNDS {
+        if round > 0 {
+            k = [k[0].wrapping_add(W0), k[1].wrapping_add(W1)];
+        }
+        let p0 = M0 * x[0] as u64;
+        let p1 = M1 * x[2] as u64;
+        x = [
+            (p1 >> 32) as u32 ^ x[1] ^ k[0],
+            p1 as u32,
+            (p0 >> 32) as u32 ^ x[3] ^ k[1],
+            p0 as u32,
+        ];
+        round += 1;
+    }
+    x
+}
+
+/// Returns the Philox4x64-10 block of the given `counter` and `key`.
+const fn philox4x64(counter: [u64; 4], key: [u64; 2]) -> [u64; 4] {
+    const M0: u128 = 0xD2E7_470E_E14C_6C93;
+    const M1: u128 = 0xCA5A_8263_9512_1157;
+    const W0: u64 = 0x9E37_79B9_7F4A_7C15; // golden ratio
+    const W1: u64 = 0xBB67_AE85_84CA_A73B; // sqrt(3) - 1
+    let (mut x, mut k) = (counter, key);
+    let mut round = 0;
+    while round < PHILOX_ROUNDS {
+        if round > 0 {
+            k = [k[0].wrapping_add(W0), k[1].wrapping_add(W1)];
+        }
+        let p0 = M0 * x[0] as u128;
+        let p1 = M1 * x[2] as u128;
+        x = [
+            (p1 >> 64) as u64 ^ x[1] ^ k[0],
+            p1 as u64,
+            (p0 >> 64) as u64 ^ x[3] ^ k[1],
+            p0 as u64,
+        ];
+        round += 1;
+    }
+    x
+}
+
+/// The `Philox4x32-10` counter-based pseudo-random number generator.
+///
+/// It has a 64-bit key and a 128-bit counter, and generates 32-bit numbers,
+/// 4 at a time. Its period for each key is `2^130`.
+///
+/// Each block of 4 words is a keyed bijection of its counter, computed by
+/// 10 rounds of multiplications whose high and low halves are mixed with
+/// the key. Any block can be computed with [`at`][Self::at], without
+/// generating the previous ones.
+///
+/// It was published by John K. Salmon et al. in
+/// [*Parallel random numbers: as easy as 1, 2, 3*][paper] (2011),
+/// as part of the Random123 library.
+///
+/// [paper]: https://doi.org/10.1145/2063384.2063405
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Philox4x32 {
+    key: [u32; 2],
+    /// The counter of the buffered block.
+    counter: [u32; 4],
+    buf: [u32; 4],
+    /// The index of the next word in the buffer.
+    index: usize,
+}
+
+impl Default for Philox4x32 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Philox4x32 {
+    const DEFAULT_SEED: [u32; 2] = [0xDEFA0017; 2];
+}
+
+impl Philox4x32 {
+    /// Returns a `Philox4x32` generator with the given 2 × 32-bit key,
+    /// starting at counter `0`.
+    ///
+    /// Every key is valid, and each one selects an independent sequence.
+    #[inline]
+    #[must_use]
+    pub const fn new(key: [u32; 2]) -> Self {
+        Self::with_counter(key, [0; 4])
+    }
+
+    /// Returns a `Philox4x32` generator with the given 2 × 32-bit key,
+    /// starting at the given `counter`.
+    #[inline]
+    #[must_use]
+    pub const fn with_counter(key: [u32; 2], counter: [u32; 4]) -> Self {
+        let buf = philox4x32(counter, key);
+        Self {
+            key,
+            counter,
+            buf,
+            index: 0,
+        }
+    }
+
+    /// Returns the block of 4 random words at the given `counter`.
+    ///
+    /// This doesn't depend on nor modify the position of the generator.
+    #[inline]
+    #[must_use]
+    pub const fn at(&self, counter: [u32; 4]) -> [u32; 4] {
+        philox4x32(counter, self.key)
+    }
+
+    /// Returns the counter of the block of the next word.
+    #[inline]
+    #[must_use]
+    pub const fn counter(&self) -> [u32; 4] {
+        self.counter
+    }
+
+    /// Sets the counter, so that the next word is the first one of its block.
+    #[inline]
+    pub const fn set_counter(&mut self, counter: [u32; 4]) {
+        self.counter = counter;
+        self.buf = self.at(counter);
+        self.index = 0;
+    }
+
+    /// Returns the current random `u32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u32(&self) -> u32 {
+        self.buf[self.index]
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        let result = self.current_u32();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut new = *self;
+        new.index += 1;
+        if new.index == 4 {
+            new.set_counter(increment_u32(new.counter));
+        }
+        new
+    }
+}
+
+/// # Extra constructors
+impl Philox4x32 {
+    /// Returns a seeded `Philox4x32` generator from the given 64-bit key.
+    ///
+    /// The key will be split in little endian order.
+    #[inline]
+    pub const fn new1_u64(key: u64) -> Self {
+        Self::new(u64_into_u32_le(key))
+    }
+
+    /// Returns a seeded `Philox4x32` generator from the given 2 × 32-bit key words.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new2_u32(key: [u32; 2]) -> Self {
+        Self::new(key)
+    }
+
+    /// Returns a seeded `Philox4x32` generator from the given 4 × 16-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u16(key: [u16; 4]) -> Self {
+        Self::new([
+            u32_from_u16_le([key[0], key[1]]),
+            u32_from_u16_le([key[2], key[3]]),
+        ])
+    }
+
+    /// Returns a seeded `Philox4x32` generator from the given 8 × 8-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u8(key: [u8; 8]) -> Self {
+        Self::new([
+            u32_from_u8_le([key[0], key[1], key[2], key[3]]),
+            u32_from_u8_le([key[4], key[5], key[6], key[7]]),
+        ])
+    }
+
+    /// Returns a seeded `Philox4x32` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`] into the key.
+    ///
+    /// Nearby seeds result in unrelated keys.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new(SplitMix64::seed_u32s(seed))
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// The `Philox4x64-10` counter-based pseudo-random number generator.
+///
+/// It has a 128-bit key and a 256-bit counter, and generates 64-bit numbers,
+/// 4 at a time. Its period for each key is `2^258`.
+///
+/// This is the 64-bit version of [`Philox4x32`], using 64 × 64 → 128-bit
+/// multiplications.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Philox4x64 {
+    key: [u64; 2],
+    /// The counter of the buffered block.
+    counter: [u64; 4],
+    buf: [u64; 4],
+    /// The index of the next word in the buffer.
+    index: usize,
+}
+
+impl Default for Philox4x64 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Philox4x64 {
+    const DEFAULT_SEED: [u64; 2] = [0xDEFA0017_DEFA0017; 2];
+}
+
+impl Philox4x64 {
+    /// Returns a `Philox4x64` generator with the given 2 × 64-bit key,
+    /// starting at counter `0`.
+    ///
+    /// Every key is valid, and each one selects an independent sequence.
+    #[inline]
+    #[must_use]
+    pub const fn new(key: [u64; 2]) -> Self {
+        Self::with_counter(key, [0; 4])
+    }
+
+    /// Returns a `Philox4x64` generator with the given 2 × 64-bit key,
+    /// starting at the given `counter`.
+    #[inline]
+    #[must_use]
+    pub const fn with_counter(key: [u64; 2], counter: [u64; 4]) -> Self {
+        let buf = philox4x64(counter, key);
+        Self {
+            key,
+            counter,
+            buf,
+            index: 0,
+        }
+    }
+
+    /// Returns the block of 4 random words at the given `counter`.
+    ///
+    /// This doesn't depend on nor modify the position of the generator.
+    #[inline]
+    #[must_use]
+    pub const fn at(&self, counter: [u64; 4]) -> [u64; 4] {
+        philox4x64(counter, self.key)
+    }
+
+    /// Returns the counter of the block of the next word.
+    #[inline]
+    #[must_use]
+    pub const fn counter(&self) -> [u64; 4] {
+        self.counter
+    }
+
+    /// Sets the counter, so that the next word is the first one of its block.
+    #[inline]
+    pub const fn set_counter(&mut self, counter: [u64; 4]) {
+        self.counter = counter;
+        self.buf = self.at(counter);
+        self.index = 0;
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        self.buf[self.index]
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.current_u64();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut new = *self;
+        new.index += 1;
+        if new.index == 4 {
+            new.set_counter(increment_u64(new.counter));
+        }
+        new
+    }
+}
+
+/// # Extra constructors
+impl Philox4x64 {
+    /// Returns a seeded `Philox4x64` generator from the given 128-bit key.
+    ///
+    /// The key will be split in little endian order.
+    #[inline]
+    pub const fn new1_u128(key: u128) -> Self {
+        Self::new(u128_into_u64_le(key))
+    }
+
+    /// Returns a seeded `Philox4x64` generator from the given 2 × 64-bit key words.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new2_u64(key: [u64; 2]) -> Self {
+        Self::new(key)
+    }
+
+    /// Returns a seeded `Philox4x64` generator from the given 4 × 32-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u32(key: [u32; 4]) -> Self {
+        Self::new([
+            u64_from_u32_le([key[0], key[1]]),
+            u64_from_u32_le([key[2], key[3]]),
+        ])
+    }
+
+    /// Returns a seeded `Philox4x64` generator from the given 8 × 16-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u16(key: [u16; 8]) -> Self {
+        Self::new([
+            u64_from_u16_le([key[0], key[1], key[2], key[3]]),
+            u64_from_u16_le([key[4], key[5], key[6], key[7]]),
+        ])
+    }
+
+    /// Returns a seeded `Philox4x64` generator from the given 16 × 8-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new16_u8(key: [u8; 16]) -> Self {
+        let k = key;
+        Self::new([
+            u64_from_u8_le([k[0], k[1], k[2], k[3], k[4], k[5], k[6], k[7]]),
+            u64_from_u8_le([k[8], k[9], k[10], k[11], k[12], k[13], k[14], k[15]]),
+        ])
+    }
+
+    /// Returns a seeded `Philox4x64` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`] into the key.
+    ///
+    /// Nearby seeds result in unrelated keys.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new(SplitMix64::seed_u64s(seed))
+    }
+}
+
+impl_prng![Philox4x32: u32, current_u32, next_u32];
+impl_prng![Philox4x64: u64, current_u64, next_u64];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::{Philox4x32, Philox4x64};
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![Philox4x32];
+
+    impl SeedableRng for Philox4x32 {
+        type Seed = [u8; 8];
+
+        /// Uses the seed as the key, starting at counter `0`.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut key = [0u32; 2];
+            for i in 0..2 {
+                key[i] = u32::from_le_bytes([
+                    seed[i * 4],
+                    seed[i * 4 + 1],
+                    seed[i * 4 + 2],
+                    seed[i * 4 + 3],
+                ]);
+            }
+            Self::new(key)
+        }
+    }
+
+    impl_rand_core![Philox4x64];
+
+    impl SeedableRng for Philox4x64 {
+        type Seed = [u8; 16];
+
+        /// Uses the seed as the key, starting at counter `0`.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut key = [0u64; 2];
+            for i in 0..2 {
+                key[i] = u64::from_le_bytes([
+                    seed[i * 8],
+                    seed[i * 8 + 1],
+                    seed[i * 8 + 2],
+                    seed[i * 8 + 3],
+                    seed[i * 8 + 4],
+                    seed[i * 8 + 5],
+                    seed[i * 8 + 6],
+                    seed[i * 8 + 7],
+                ]);
+            }
+            Self::new(key)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The known answers are from the `kat_vectors` file of Random123.
+
+    #[test]
+    fn philox4x32_known_answers() {
+        assert_eq![
+            Philox4x32::new([0; 2]).at([0; 4]),
+            [0x6627_E8D5, 0xE169_C58D, 0xBC57_AC4C, 0x9B00_DBD8]
+        ];
+        assert_eq![
+            Philox4x32::new([u32::MAX; 2]).at([u32::MAX; 4]),
+            [0x408F_276D, 0x41C8_3B0E, 0xA20B_C7C6, 0x6D54_51FD]
+        ];
+        assert_eq![
+            Philox4x32::new([0xA409_3822, 0x299F_31D0]).at([
+                0x243F_6A88,
+                0x85A3_08D3,
+                0x1319_8A2E,
+                0x0370_7344
+            ]),
+            [0xD16C_FE09, 0x94FD_CCEB, 0x5001_E420, 0x2412_6EA1]
+        ];
+    }
+
+    #[test]
+    fn philox4x32_sequential() {
+        let mut rng = Philox4x32::with_counter([1; 2], [u32::MAX, u32::MAX, 0, 0]);
+        let blocks = [rng.at([u32::MAX, u32::MAX, 0, 0]), rng.at([0, 0, 1, 0])];
+        for expected in blocks.iter().flatten() {
+            assert_eq![rng.next_u32(), *expected];
+        }
+        assert_eq![rng.counter(), [1, 0, 1, 0]];
+    }
+
+    #[test]
+    fn philox4x64_known_answers() {
+        assert_eq![
+            Philox4x64::new([0; 2]).at([0; 4]),
+            [
+                0x1655_4D9E_CA36_314C,
+                0xDB20_FE9D_672D_0FDC,
+                0xD7E7_72CE_E186_176B,
+                0x7E68_B68A_EC7B_A23B,
+            ]
+        ];
+        assert_eq![
+            Philox4x64::new([u64::MAX; 2]).at([u64::MAX; 4]),
+            [
+                0x87B0_92C3_013F_E90B,
+                0x438C_3C67_BE8D_0224,
+                0x9CC7_D7C6_9CD7_77B6,
+                0xA09C_AEBF_594F_0BA0,
+            ]
+        ];
+        assert_eq![
+            Philox4x64::new([0x4528_21E6_38D0_1377, 0xBE54_66CF_34E9_0C6C]).at([
+                0x243F_6A88_85A3_08D3,
+                0x1319_8A2E_0370_7344,
+                0xA409_3822_299F_31D0,
+                0x082E_FA98_EC4E_6C89,
+            ]),
+            [
+                0xA528_F454_03E6_1D95,
+                0x38C7_2DBD_566E_9788,
+                0xA5A1_610E_72FD_18B5,
+                0x57BD_43B5_E52B_7FE6,
+            ]
+        ];
+    }
+
+    #[test]
+    fn philox4x64_sequential() {
+        let mut rng = Philox4x64::with_counter([1; 2], [u64::MAX, u64::MAX, 0, 0]);
+        let blocks = [rng.at([u64::MAX, u64::MAX, 0, 0]), rng.at([0, 0, 1, 0])];
+        for expected in blocks.iter().flatten() {
+            assert_eq![rng.next_u64(), *expected];
+        }
+        assert_eq![rng.counter(), [1, 0, 1, 0]];
+    }
+}
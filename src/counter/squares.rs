@@ -0,0 +1,380 @@
+// alazar::counter::squares
+//
+//! Squares generators.
+//
+
+use crate::{misc::SplitMix64, prng::impl_prng};
+use devela::convert::{u64_from_u16_le, u64_from_u32_le, u64_from_u8_le};
+
+/// Returns the square of `x` plus `y`, with its 32-bit halves swapped.
+#[inline(always)]
+const fn square_swap(x: u64, y: u64) -> u64 {
+    x.wrapping_mul(x).wrapping_add(y).rotate_left(32)
+}
+
+/// Returns the 32-bit Squares output of the given `counter` and `key`.
+const fn squares32(counter: u64, key: u64) -> u32 {
+    let y = counter.wrapping_mul(key);
+    let z = y.wrapping_add(key);
+    let x = square_swap(y, y);
+    let x = square_swap(x, z);
+    let x = square_swap(x, y);
+    (x.wrapping_mul(x).wrapping_add(z) >> 32) as u32
+}
+
+/// Returns the 64-bit Squares output of the given `counter` and `key`.
+const fn squares64(counter: u64, key: u64) -> u64 {
+    let y = counter.wrapping_mul(key);
+    let z = y.wrapping_add(key);
+    let x = square_swap(y, y);
+    let x = square_swap(x, z);
+    let x = square_swap(x, y);
+    let t = x.wrapping_mul(x).wrapping_add(z);
+    let x = t.rotate_left(32);
+    t ^ (x.wrapping_mul(x).wrapping_add(y) >> 32)
+}
+
+/// The `Squares32` counter-based pseudo-random number generator.
+///
+/// It has a 64-bit key and a 64-bit counter, and generates 32-bit numbers.
+/// Its period for each key is `2^64`.
+///
+/// Each output is computed from the product of its counter and the key,
+/// by 4 rounds of squaring it and swapping the halves, like in von Neumann's
+/// middle-square method. Any output can be computed with [`at`][Self::at],
+/// without generating the previous ones.
+///
+/// The key should be odd and have an irregular bit pattern, with about as
+/// many ones as zeros, like the ones made by
+/// [`from_u64_seed`][Self::from_u64_seed].
+///
+/// It was published by Bernard Widynski in
+/// [*Squares: A Fast Counter-Based RNG*][paper] (2020).
+///
+/// [paper]: https://arxiv.org/abs/2004.06278
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Squares32 {
+    key: u64,
+    /// The counter of the next word.
+    counter: u64,
+}
+
+impl Default for Squares32 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Squares32 {
+    const DEFAULT_SEED: u64 = 0xDEFA0017_DEFA0017;
+}
+
+impl Squares32 {
+    /// Returns a `Squares32` generator with the given 64-bit key,
+    /// starting at counter `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(key: u64) -> Self {
+        Self::with_counter(key, 0)
+    }
+
+    /// Returns a `Squares32` generator with the given 64-bit key,
+    /// starting at the given `counter`.
+    #[inline]
+    #[must_use]
+    pub const fn with_counter(key: u64, counter: u64) -> Self {
+        Self { key, counter }
+    }
+
+    /// Returns the random `u32` at the given `counter`.
+    ///
+    /// This doesn't depend on nor modify the position of the generator.
+    #[inline]
+    #[must_use]
+    pub const fn at(&self, counter: u64) -> u32 {
+        squares32(counter, self.key)
+    }
+
+    /// Returns the counter of the next word.
+    #[inline]
+    #[must_use]
+    pub const fn counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// Sets the counter of the next word.
+    #[inline]
+    pub const fn set_counter(&mut self, counter: u64) {
+        self.counter = counter;
+    }
+
+    /// Returns the current random `u32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u32(&self) -> u32 {
+        self.at(self.counter)
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        let result = self.current_u32();
+        self.counter = self.counter.wrapping_add(1);
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        Self::with_counter(self.key, self.counter.wrapping_add(1))
+    }
+}
+
+/// # Extra constructors
+impl Squares32 {
+    /// Returns a seeded `Squares32` generator from the given 64-bit key.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new1_u64(key: u64) -> Self {
+        Self::new(key)
+    }
+
+    /// Returns a seeded `Squares32` generator from the given 2 × 32-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new2_u32(key: [u32; 2]) -> Self {
+        Self::new(u64_from_u32_le(key))
+    }
+
+    /// Returns a seeded `Squares32` generator from the given 4 × 16-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u16(key: [u16; 4]) -> Self {
+        Self::new(u64_from_u16_le(key))
+    }
+
+    /// Returns a seeded `Squares32` generator from the given 8 × 8-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u8(key: [u8; 8]) -> Self {
+        Self::new(u64_from_u8_le(key))
+    }
+
+    /// Returns a seeded `Squares32` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`] into the key.
+    ///
+    /// The key is odd, its 16 hexadecimal digits are non-zero, and the
+    /// 8 digits of each 32-bit half are all different.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new(SplitMix64::seed_weyl_key(seed))
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// The `Squares64` counter-based pseudo-random number generator.
+///
+/// It has a 64-bit key and a 64-bit counter, and generates 64-bit numbers.
+/// Its period for each key is `2^64`.
+///
+/// This is the 64-bit version of [`Squares32`], with a fifth round whose
+/// upper half is xored with the result of the fourth one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Squares64 {
+    key: u64,
+    /// The counter of the next word.
+    counter: u64,
+}
+
+impl Default for Squares64 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Squares64 {
+    const DEFAULT_SEED: u64 = 0xDEFA0017_DEFA0017;
+}
+
+impl Squares64 {
+    /// Returns a `Squares64` generator with the given 64-bit key,
+    /// starting at counter `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(key: u64) -> Self {
+        Self::with_counter(key, 0)
+    }
+
+    /// Returns a `Squares64` generator with the given 64-bit key,
+    /// starting at the given `counter`.
+    #[inline]
+    #[must_use]
+    pub const fn with_counter(key: u64, counter: u64) -> Self {
+        Self { key, counter }
+    }
+
+    /// Returns the random `u64` at the given `counter`.
+    ///
+    /// This doesn't depend on nor modify the position of the generator.
+    #[inline]
+    #[must_use]
+    pub const fn at(&self, counter: u64) -> u64 {
+        squares64(counter, self.key)
+    }
+
+    /// Returns the counter of the next word.
+    #[inline]
+    #[must_use]
+    pub const fn counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// Sets the counter of the next word.
+    #[inline]
+    pub const fn set_counter(&mut self, counter: u64) {
+        self.counter = counter;
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        self.at(self.counter)
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.current_u64();
+        self.counter = self.counter.wrapping_add(1);
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        Self::with_counter(self.key, self.counter.wrapping_add(1))
+    }
+}
+
+/// # Extra constructors
+impl Squares64 {
+    /// Returns a seeded `Squares64` generator from the given 64-bit key.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new1_u64(key: u64) -> Self {
+        Self::new(key)
+    }
+
+    /// Returns a seeded `Squares64` generator from the given 2 × 32-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new2_u32(key: [u32; 2]) -> Self {
+        Self::new(u64_from_u32_le(key))
+    }
+
+    /// Returns a seeded `Squares64` generator from the given 4 × 16-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u16(key: [u16; 4]) -> Self {
+        Self::new(u64_from_u16_le(key))
+    }
+
+    /// Returns a seeded `Squares64` generator from the given 8 × 8-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u8(key: [u8; 8]) -> Self {
+        Self::new(u64_from_u8_le(key))
+    }
+
+    /// Returns a seeded `Squares64` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`] into the key.
+    ///
+    /// The key is odd, its 16 hexadecimal digits are non-zero, and the
+    /// 8 digits of each 32-bit half are all different.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new(SplitMix64::seed_weyl_key(seed))
+    }
+}
+
+impl_prng![Squares32: u32, current_u32, next_u32];
+impl_prng![Squares64: u64, current_u64, next_u64];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::{Squares32, Squares64};
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![Squares32];
+
+    impl SeedableRng for Squares32 {
+        type Seed = [u8; 8];
+
+        /// Uses the seed as the key, starting at counter `0`.
+        fn from_seed(seed: Self::Seed) -> Self {
+            Self::new(u64::from_le_bytes(seed))
+        }
+    }
+
+    impl_rand_core![Squares64];
+
+    impl SeedableRng for Squares64 {
+        type Seed = [u8; 8];
+
+        /// Uses the seed as the key, starting at counter `0`.
+        fn from_seed(seed: Self::Seed) -> Self {
+            Self::new(u64::from_le_bytes(seed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The expected values are from the C implementation in the paper.
+    const KEY: u64 = 0x548C_9DEC_BCE6_5297;
+
+    #[test]
+    fn squares32_reference() {
+        let mut rng = Squares32::new(KEY);
+        for expected in [0x36D8_8366, 0x9447_16E0, 0xC8A8_F4E0, 0x35CC_666A] {
+            assert_eq![rng.next_u32(), expected];
+        }
+        assert_eq![rng.counter(), 4];
+        assert_eq![rng.at(u64::MAX), 0x4345_06A4];
+    }
+
+    #[test]
+    fn squares64_reference() {
+        let mut rng = Squares64::new(KEY);
+        for expected in [
+            0x36D8_8366_CEE6_33A5,
+            0x9447_16E0_0E60_DFAA,
+            0xC8A8_F4E0_6786_54BF,
+            0x35CC_666A_AB11_C80D,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+        assert_eq![rng.counter(), 4];
+        assert_eq![rng.at(u64::MAX), 0x4345_06A4_4577_7020];
+    }
+}
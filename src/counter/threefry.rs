@@ -0,0 +1,612 @@
+// alazar::counter::threefry
+//
+//! Threefry generators.
+//
+
+use super::{increment_u32, increment_u64};
+use crate::{misc::SplitMix64, prng::impl_prng};
+use devela::convert::{
+    u128_into_u32_le, u128_into_u64_le, u32_from_u16_le, u32_from_u8_le, u64_from_u16_le,
+    u64_from_u32_le, u64_from_u8_le, u64_into_u32_le,
+};
+
+/// The number of rounds of the Threefry generators.
+const THREEFRY_ROUNDS: usize = 20;
+
+/// Returns the Threefry4x32-20 block of the given `counter` and `key`.
+const fn threefry4x32(counter: [u32; 4], key: [u32; 4]) -> [u32; 4] {
+    const ROTATIONS: [[u32; 2]; 8] = [
+        [10, 26],
+        [11, 21],
+        [13, 27],
+        [23, 5],
+        [6, 20],
+        [17, 11],
+        [25, 10],
+        [18, 20],
+    ];
+    const PARITY: u32 = 0x1BD1_1BDA;
+    let ks = [
+        key[0],
+        key[1],
+        key[2],
+        key[3],
+        PARITY ^ key[0] ^ key[1] ^ key[2] ^ key[3],
+    ];
+    let mut x = [
+        counter[0].wrapping_add(ks[0]),
+        counter[1].wrapping_add(ks[1]),
+        counter[2].wrapping_add(ks[2]),
+        counter[3].wrapping_add(ks[3]),
+    ];
+    let mut round = 0;
+    while round < THREEFRY_ROUNDS {
+        let [r0, r1] = ROTATIONS[round % 8];
+        let (a, b) = if round % 2 == 0 { (1, 3) } else { (3, 1) };
+        x[0] = x[0].wrapping_add(x[a]);
+        x[a] = x[a].rotate_left(r0) ^ x[0];
+        x[2] = x[2].wrapping_add(x[b]);
+        x[b] = x[b].rotate_left(r1) ^ x[2];
+        round += 1;
+        // key injection every 4 rounds
+        if round % 4 == 0 {
+            let s = round / 4;
+            x[0] = x[0].wrapping_add(ks[s % 5]);
+            x[1] = x[1].wrapping_add(ks[(s + 1) % 5]);
+            x[2] = x[2].wrapping_add(ks[(s + 2) % 5]);
+            x[3] = x[3].wrapping_add(ks[(s + 3) % 5]).wrapping_add(s as u32);
+        }
+    }
+    x
+}
+
+/// Returns the Threefry4x64-20 block of the given `counter` and `key`.
+const fn threefry4x64(counter: [u64; 4], key: [u64; 4]) -> [u64; 4] {
+    const ROTATIONS: [[u32; 2]; 8] = [
+        [14, 16],
+        [52, 57],
+        [23, 40],
+        [5, 37],
+        [25, 33],
+        [46, 12],
+        [58, 22],
+        [32, 32],
+    ];
+    const PARITY: u64 = 0x1BD1_1BDA_A9FC_1A22;
+    let ks = [
+        key[0],
+        key[1],
+        key[2],
+        key[3],
+        PARITY ^ key[0] ^ key[1] ^ key[2] ^ key[3],
+    ];
+    let mut x = [
+        counter[0].wrapping_add(ks[0]),
+        counter[1].wrapping_add(ks[1]),
+        counter[2].wrapping_add(ks[2]),
+        counter[3].wrapping_add(ks[3]),
+    ];
+    let mut round = 0;
+    while round < THREEFRY_ROUNDS {
+        let [r0, r1] = ROTATIONS[round % 8];
+        let (a, b) = if round % 2 == 0 { (1, 3) } else { (3, 1) };
+        x[0] = x[0].wrapping_add(x[a]);
+        x[a] = x[a].rotate_left(r0) ^ x[0];
+        x[2] = x[2].wrapping_add(x[b]);
+        x[b] = x[b].rotate_left(r1) ^ x[2];
+        round += 1;
+        // key injection every 4 rounds
+        if round % 4 == 0 {
+            let s = round / 4;
+            x[0] = x[0].wrapping_add(ks[s % 5]);
+            x[1] = x[1].wrapping_add(ks[(s + 1) % 5]);
+            x[2] = x[2].wrapping_add(ks[(s + 2) % 5]);
+            x[3] = x[3].wrapping_add(ks[(s + 3) % 5]).wrapping_add(s as u64);
+        }
+    }
+    x
+}
+
+/// The `Threefry4x32-20` counter-based pseudo-random number generator.
+///
+/// It has a 128-bit key and a 128-bit counter, and generates 32-bit numbers,
+/// 4 at a time. Its period for each key is `2^130`.
+///
+/// This is the 32-bit version of [`Threefry4x64`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Threefry4x32 {
+    key: [u32; 4],
+    /// The counter of the buffered block.
+    counter: [u32; 4],
+    buf: [u32; 4],
+    /// The index of the next word in the buffer.
+    index: usize,
+}
+
+impl Default for Threefry4x32 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Threefry4x32 {
+    const DEFAULT_SEED: [u32; 4] = [0xDEFA0017; 4];
+}
+
+impl Threefry4x32 {
+    /// Returns a `Threefry4x32` generator with the given 4 × 32-bit key,
+    /// starting at counter `0`.
+    ///
+    /// Every key is valid, and each one selects an independent sequence.
+    #[inline]
+    #[must_use]
+    pub const fn new(key: [u32; 4]) -> Self {
+        Self::with_counter(key, [0; 4])
+    }
+
+    /// Returns a `Threefry4x32` generator with the given 4 × 32-bit key,
+    /// starting at the given `counter`.
+    #[inline]
+    #[must_use]
+    pub const fn with_counter(key: [u32; 4], counter: [u32; 4]) -> Self {
+        let buf = threefry4x32(counter, key);
+        Self {
+            key,
+            counter,
+            buf,
+            index: 0,
+        }
+    }
+
+    /// Returns the block of 4 random words at the given `counter`.
+    ///
+    /// This doesn't depend on nor modify the position of the generator.
+    #[inline]
+    #[must_use]
+    pub const fn at(&self, counter: [u32; 4]) -> [u32; 4] {
+        threefry4x32(counter, self.key)
+    }
+
+    /// Returns the counter of the block of the next word.
+    #[inline]
+    #[must_use]
+    pub const fn counter(&self) -> [u32; 4] {
+        self.counter
+    }
+
+    /// Sets the counter, so that the next word is the first one of its block.
+    #[inline]
+    pub const fn set_counter(&mut self, counter: [u32; 4]) {
+        self.counter = counter;
+        self.buf = self.at(counter);
+        self.index = 0;
+    }
+
+    /// Returns the current random `u32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u32(&self) -> u32 {
+        self.buf[self.index]
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        let result = self.current_u32();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut new = *self;
+        new.index += 1;
+        if new.index == 4 {
+            new.set_counter(increment_u32(new.counter));
+        }
+        new
+    }
+}
+
+/// # Extra constructors
+impl Threefry4x32 {
+    /// Returns a seeded `Threefry4x32` generator from the given 128-bit key.
+    ///
+    /// The key will be split in little endian order.
+    #[inline]
+    pub const fn new1_u128(key: u128) -> Self {
+        Self::new(u128_into_u32_le(key))
+    }
+
+    /// Returns a seeded `Threefry4x32` generator from the given 2 × 64-bit key words.
+    ///
+    /// The key words will be split in little endian order.
+    #[inline]
+    pub const fn new2_u64(key: [u64; 2]) -> Self {
+        let [a, b] = u64_into_u32_le(key[0]);
+        let [c, d] = u64_into_u32_le(key[1]);
+        Self::new([a, b, c, d])
+    }
+
+    /// Returns a seeded `Threefry4x32` generator from the given 4 × 32-bit key words.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new4_u32(key: [u32; 4]) -> Self {
+        Self::new(key)
+    }
+
+    /// Returns a seeded `Threefry4x32` generator from the given 8 × 16-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u16(key: [u16; 8]) -> Self {
+        Self::new([
+            u32_from_u16_le([key[0], key[1]]),
+            u32_from_u16_le([key[2], key[3]]),
+            u32_from_u16_le([key[4], key[5]]),
+            u32_from_u16_le([key[6], key[7]]),
+        ])
+    }
+
+    /// Returns a seeded `Threefry4x32` generator from the given 16 × 8-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new16_u8(key: [u8; 16]) -> Self {
+        let k = key;
+        Self::new([
+            u32_from_u8_le([k[0], k[1], k[2], k[3]]),
+            u32_from_u8_le([k[4], k[5], k[6], k[7]]),
+            u32_from_u8_le([k[8], k[9], k[10], k[11]]),
+            u32_from_u8_le([k[12], k[13], k[14], k[15]]),
+        ])
+    }
+
+    /// Returns a seeded `Threefry4x32` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`] into the key.
+    ///
+    /// Nearby seeds result in unrelated keys.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new(SplitMix64::seed_u32s(seed))
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// The `Threefry4x64-20` counter-based pseudo-random number generator.
+///
+/// It has a 256-bit key and a 256-bit counter, and generates 64-bit numbers,
+/// 4 at a time. Its period for each key is `2^258`.
+///
+/// Each block of 4 words is a keyed bijection of its counter, computed by
+/// 20 rounds of additions, rotations and xors, with the key injected every
+/// 4 rounds. It's based on the Threefish block cipher, used by the Skein
+/// hash function. Any block can be computed with [`at`][Self::at], without
+/// generating the previous ones.
+///
+/// It was published by John K. Salmon et al. in
+/// [*Parallel random numbers: as easy as 1, 2, 3*][paper] (2011),
+/// as part of the Random123 library.
+///
+/// [paper]: https://doi.org/10.1145/2063384.2063405
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Threefry4x64 {
+    key: [u64; 4],
+    /// The counter of the buffered block.
+    counter: [u64; 4],
+    buf: [u64; 4],
+    /// The index of the next word in the buffer.
+    index: usize,
+}
+
+impl Default for Threefry4x64 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Threefry4x64 {
+    const DEFAULT_SEED: [u64; 4] = [0xDEFA0017_DEFA0017; 4];
+}
+
+impl Threefry4x64 {
+    /// Returns a `Threefry4x64` generator with the given 4 × 64-bit key,
+    /// starting at counter `0`.
+    ///
+    /// Every key is valid, and each one selects an independent sequence.
+    #[inline]
+    #[must_use]
+    pub const fn new(key: [u64; 4]) -> Self {
+        Self::with_counter(key, [0; 4])
+    }
+
+    /// Returns a `Threefry4x64` generator with the given 4 × 64-bit key,
+    /// starting at the given `counter`.
+    #[inline]
+    #[must_use]
+    pub const fn with_counter(key: [u64; 4], counter: [u64; 4]) -> Self {
+        let buf = threefry4x64(counter, key);
+        Self {
+            key,
+            counter,
+            buf,
+            index: 0,
+        }
+    }
+
+    /// Returns the block of 4 random words at the given `counter`.
+    ///
+    /// This doesn't depend on nor modify the position of the generator.
+    #[inline]
+    #[must_use]
+    pub const fn at(&self, counter: [u64; 4]) -> [u64; 4] {
+        threefry4x64(counter, self.key)
+    }
+
+    /// Returns the counter of the block of the next word.
+    #[inline]
+    #[must_use]
+    pub const fn counter(&self) -> [u64; 4] {
+        self.counter
+    }
+
+    /// Sets the counter, so that the next word is the first one of its block.
+    #[inline]
+    pub const fn set_counter(&mut self, counter: [u64; 4]) {
+        self.counter = counter;
+        self.buf = self.at(counter);
+        self.index = 0;
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        self.buf[self.index]
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.current_u64();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut new = *self;
+        new.index += 1;
+        if new.index == 4 {
+            new.set_counter(increment_u64(new.counter));
+        }
+        new
+    }
+}
+
+/// # Extra constructors
+impl Threefry4x64 {
+    /// Returns a seeded `Threefry4x64` generator from the given 2 × 128-bit key words.
+    ///
+    /// The key words will be split in little endian order.
+    #[inline]
+    pub const fn new2_u128(key: [u128; 2]) -> Self {
+        let [a, b] = u128_into_u64_le(key[0]);
+        let [c, d] = u128_into_u64_le(key[1]);
+        Self::new([a, b, c, d])
+    }
+
+    /// Returns a seeded `Threefry4x64` generator from the given 4 × 64-bit key words.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new4_u64(key: [u64; 4]) -> Self {
+        Self::new(key)
+    }
+
+    /// Returns a seeded `Threefry4x64` generator from the given 8 × 32-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u32(key: [u32; 8]) -> Self {
+        let k = key;
+        Self::new([
+            u64_from_u32_le([k[0], k[1]]),
+            u64_from_u32_le([k[2], k[3]]),
+            u64_from_u32_le([k[4], k[5]]),
+            u64_from_u32_le([k[6], k[7]]),
+        ])
+    }
+
+    /// Returns a seeded `Threefry4x64` generator from the given 16 × 16-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new16_u16(key: [u16; 16]) -> Self {
+        let k = key;
+        Self::new([
+            u64_from_u16_le([k[0], k[1], k[2], k[3]]),
+            u64_from_u16_le([k[4], k[5], k[6], k[7]]),
+            u64_from_u16_le([k[8], k[9], k[10], k[11]]),
+            u64_from_u16_le([k[12], k[13], k[14], k[15]]),
+        ])
+    }
+
+    /// Returns a seeded `Threefry4x64` generator from the given 32 × 8-bit key words.
+    ///
+    /// The words will be joined in little endian order.
+    #[inline]
+    pub const fn new32_u8(key: [u8; 32]) -> Self {
+        let k = key;
+        Self::new([
+            u64_from_u8_le([k[0], k[1], k[2], k[3], k[4], k[5], k[6], k[7]]),
+            u64_from_u8_le([k[8], k[9], k[10], k[11], k[12], k[13], k[14], k[15]]),
+            u64_from_u8_le([k[16], k[17], k[18], k[19], k[20], k[21], k[22], k[23]]),
+            u64_from_u8_le([k[24], k[25], k[26], k[27], k[28], k[29], k[30], k[31]]),
+        ])
+    }
+
+    /// Returns a seeded `Threefry4x64` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`] into the key.
+    ///
+    /// Nearby seeds result in unrelated keys.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new(SplitMix64::seed_u64s(seed))
+    }
+}
+
+impl_prng![Threefry4x32: u32, current_u32, next_u32];
+impl_prng![Threefry4x64: u64, current_u64, next_u64];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::{Threefry4x32, Threefry4x64};
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![Threefry4x32];
+
+    impl SeedableRng for Threefry4x32 {
+        type Seed = [u8; 16];
+
+        /// Uses the seed as the key, starting at counter `0`.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut key = [0u32; 4];
+            for i in 0..4 {
+                key[i] = u32::from_le_bytes([
+                    seed[i * 4],
+                    seed[i * 4 + 1],
+                    seed[i * 4 + 2],
+                    seed[i * 4 + 3],
+                ]);
+            }
+            Self::new(key)
+        }
+    }
+
+    impl_rand_core![Threefry4x64];
+
+    impl SeedableRng for Threefry4x64 {
+        type Seed = [u8; 32];
+
+        /// Uses the seed as the key, starting at counter `0`.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut key = [0u64; 4];
+            for i in 0..4 {
+                key[i] = u64::from_le_bytes([
+                    seed[i * 8],
+                    seed[i * 8 + 1],
+                    seed[i * 8 + 2],
+                    seed[i * 8 + 3],
+                    seed[i * 8 + 4],
+                    seed[i * 8 + 5],
+                    seed[i * 8 + 6],
+                    seed[i * 8 + 7],
+                ]);
+            }
+            Self::new(key)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The known answers are from the `kat_vectors` file of Random123.
+
+    #[test]
+    fn threefry4x32_known_answers() {
+        assert_eq![
+            Threefry4x32::new([0; 4]).at([0; 4]),
+            [0x9C6C_A96A, 0xE17E_AE66, 0xFC10_ECD4, 0x5256_A7D8]
+        ];
+        assert_eq![
+            Threefry4x32::new([u32::MAX; 4]).at([u32::MAX; 4]),
+            [0x2A88_1696, 0x5701_2287, 0xF6C7_446E, 0xA16A_6732]
+        ];
+        assert_eq![
+            Threefry4x32::new([0xA409_3822, 0x299F_31D0, 0x082E_FA98, 0xEC4E_6C89]).at([
+                0x243F_6A88,
+                0x85A3_08D3,
+                0x1319_8A2E,
+                0x0370_7344
+            ]),
+            [0x59CD_1DBB, 0xB887_9579, 0x86B5_D00C, 0xAC8B_6D84]
+        ];
+    }
+
+    #[test]
+    fn threefry4x32_sequential() {
+        let mut rng = Threefry4x32::with_counter([1; 4], [u32::MAX, u32::MAX, 0, 0]);
+        let blocks = [rng.at([u32::MAX, u32::MAX, 0, 0]), rng.at([0, 0, 1, 0])];
+        for expected in blocks.iter().flatten() {
+            assert_eq![rng.next_u32(), *expected];
+        }
+        assert_eq![rng.counter(), [1, 0, 1, 0]];
+    }
+
+    #[test]
+    fn threefry4x64_known_answers() {
+        assert_eq![
+            Threefry4x64::new([0; 4]).at([0; 4]),
+            [
+                0x0921_8EBD_E6C8_5537,
+                0x5594_1F52_66D8_6105,
+                0x4BD2_5E16_2824_34DC,
+                0xEE29_EC84_6BD2_E40B,
+            ]
+        ];
+        assert_eq![
+            Threefry4x64::new([u64::MAX; 4]).at([u64::MAX; 4]),
+            [
+                0x29C2_4097_942B_BA1B,
+                0x0371_BBFB_0F6F_4E11,
+                0x3C23_1FFA_33F8_3A1C,
+                0xCD29_113F_DE32_D168,
+            ]
+        ];
+        assert_eq![
+            Threefry4x64::new([
+                0x4528_21E6_38D0_1377,
+                0xBE54_66CF_34E9_0C6C,
+                0xBE54_66CF_34E9_0C6C,
+                0xC0AC_29B7_C97C_50DD,
+            ])
+            .at([
+                0x243F_6A88_85A3_08D3,
+                0x1319_8A2E_0370_7344,
+                0xA409_3822_299F_31D0,
+                0x082E_FA98_EC4E_6C89,
+            ]),
+            [
+                0xA7E8_FDE5_9165_1BD9,
+                0xBAAF_D0C3_0138_319B,
+                0x84A5_C1A7_29E6_85B9,
+                0x901D_406C_CEBC_1BA4,
+            ]
+        ];
+    }
+
+    #[test]
+    fn threefry4x64_sequential() {
+        let mut rng = Threefry4x64::with_counter([1; 4], [u64::MAX, u64::MAX, 0, 0]);
+        let blocks = [rng.at([u64::MAX, u64::MAX, 0, 0]), rng.at([0, 0, 1, 0])];
+        for expected in blocks.iter().flatten() {
+            assert_eq![rng.next_u64(), *expected];
+        }
+        assert_eq![rng.counter(), [1, 0, 1, 0]];
+    }
+}
@@ -0,0 +1,113 @@
+// alazar::distributions::beta
+//
+//! The beta distribution.
+//
+
+use super::{Distribution, Gamma};
+use crate::{math::exp, Prng};
+
+/// The beta distribution `B(α, β)`.
+///
+/// It's sampled as `X / (X + Y)`, where `X` and `Y` are samples of
+/// `Γ(α, 1)` and `Γ(β, 1)`. When any parameter is lower than `1` the ratio is
+/// computed from the logarithms of the samples, which would otherwise underflow.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Beta {
+    gamma_a: Gamma,
+    gamma_b: Gamma,
+}
+
+impl Beta {
+    /// Returns a beta distribution with the given `alpha` and `beta`.
+    ///
+    /// Returns `None` if either parameter is not positive or not finite.
+    #[inline]
+    #[must_use]
+    pub const fn new(alpha: f64, beta: f64) -> Option<Self> {
+        if alpha.is_finite() && alpha > 0.0 && beta.is_finite() && beta > 0.0 {
+            Some(Self::new_unchecked(alpha, beta))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a beta distribution, from already validated parameters.
+    #[inline]
+    pub(crate) const fn new_unchecked(alpha: f64, beta: f64) -> Self {
+        Self {
+            gamma_a: Gamma::new_unchecked(alpha, 1.0),
+            gamma_b: Gamma::new_unchecked(beta, 1.0),
+        }
+    }
+
+    /// Returns the `alpha` parameter of the distribution.
+    #[inline]
+    #[must_use]
+    pub const fn alpha(&self) -> f64 {
+        self.gamma_a.shape()
+    }
+
+    /// Returns the `beta` parameter of the distribution.
+    #[inline]
+    #[must_use]
+    pub const fn beta(&self) -> f64 {
+        self.gamma_b.shape()
+    }
+}
+
+impl Distribution<f64> for Beta {
+    fn sample<P: Prng>(&self, prng: &mut P) -> f64 {
+        if self.alpha() >= 1.0 && self.beta() >= 1.0 {
+            let x = self.gamma_a.sample(prng);
+            let y = self.gamma_b.sample(prng);
+            x / (x + y)
+        } else {
+            let ln_x = self.gamma_a.sample_ln_unscaled(prng);
+            let ln_y = self.gamma_b.sample_ln_unscaled(prng);
+            1.0 / (1.0 + exp(ln_y - ln_x))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::assert_moments, xorshift::XorShift64};
+
+    fn check_moments(alpha: f64, beta: f64, seed: u64) {
+        let dist = Beta::new(alpha, beta).unwrap();
+        let mut rng = XorShift64::new(seed).unwrap();
+        let sample = || {
+            let x = dist.sample(&mut rng);
+            assert!((0.0..=1.0).contains(&x));
+            x
+        };
+        let ab = alpha + beta;
+        let mean = alpha / ab;
+        let variance = alpha * beta / (ab * ab * (ab + 1.0));
+        let kurtosis = 6.0
+            * ((alpha - beta) * (alpha - beta) * (ab + 1.0) - alpha * beta * (ab + 2.0))
+            / (alpha * beta * (ab + 2.0) * (ab + 3.0));
+        assert_moments(
+            sample,
+            mean,
+            variance,
+            kurtosis,
+            format_args!("B({alpha}, {beta})"),
+        );
+    }
+
+    #[test]
+    fn moments() {
+        check_moments(2.0, 5.0, 0xDEFA_0017);
+        check_moments(50.0, 20.0, 0x1234_5678_9ABC);
+        check_moments(1.0, 1.0, 42);
+    }
+
+    #[test]
+    fn moments_small_parameters() {
+        check_moments(0.5, 0.5, 0xDEFA_0017);
+        check_moments(0.3, 3.0, 0x1234_5678_9ABC);
+        check_moments(4.0, 0.2, 42);
+    }
+}
@@ -0,0 +1,302 @@
+// alazar::distributions::binomial
+//
+//! The binomial distribution.
+//
+
+use super::Distribution;
+use crate::{
+    math::{abs, exp, floor, ln, ln_1p, sqrt},
+    Prng,
+};
+
+/// The binomial distribution `B(n, p)`.
+///
+/// It's sampled exactly by one of two methods, with `r = min(p, 1 - p)`:
+/// - when `n·r` is below 10, by inversion (BINV), with a sequential search
+///   from `0`, with a single uniform sample.
+/// - otherwise, by the triangle, parallelogram, exponential (BTPE) method,
+///   described by Voratas Kachitvichyanukul and Bruce W. Schmeiser in
+///   [*Binomial random variate generation*][btpe] (1988), which takes
+///   a bounded expected number of uniform samples regardless of `n`.
+///
+/// [btpe]: https://doi.org/10.1145/42372.42381
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Binomial {
+    n: u64,
+    p: f64,
+    method: Method,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Method {
+    Zero,
+    Inversion {
+        r: f64,
+        q_pow_n: f64,
+    },
+    Btpe {
+        r: f64,
+        npq: f64,
+        m: f64,
+        p1: f64,
+        p2: f64,
+        p3: f64,
+        p4: f64,
+        x_m: f64,
+        x_l: f64,
+        x_r: f64,
+        c: f64,
+        lambda_l: f64,
+        lambda_r: f64,
+    },
+}
+
+impl Binomial {
+    /// The threshold of `n·min(p, 1 - p)` from which the BTPE method is used.
+    const BTPE_THRESHOLD: f64 = 10.0;
+
+    /// The threshold of `|y - m|` from which the squeeze is tried in BTPE.
+    const SQUEEZE_THRESHOLD: f64 = 20.0;
+
+    /// Returns a binomial distribution of `n` trials with probability `p`.
+    ///
+    /// Returns `None` if `p` is not in `[0, 1]`.
+    #[must_use]
+    pub const fn new(n: u64, p: f64) -> Option<Self> {
+        if !(p >= 0.0 && p <= 1.0) {
+            return None;
+        }
+        let r = if p <= 0.5 { p } else { 1.0 - p };
+        let q = 1.0 - r;
+        let nf = n as f64;
+
+        let method = if n == 0 || r == 0.0 {
+            Method::Zero
+        } else if nf * r < Self::BTPE_THRESHOLD {
+            Method::Inversion {
+                r,
+                // `ln(q)` loses the precision of a tiny `r`
+                q_pow_n: exp(nf * ln_1p(-r)),
+            }
+        } else {
+            let npq = nf * r * q;
+            let f_m = nf * r + r;
+            let m = floor(f_m);
+            // the half width of the triangle, whose height is 1
+            let p1 = floor(2.195 * sqrt(npq) - 4.6 * q) + 0.5;
+            let x_m = m + 0.5;
+            let x_l = x_m - p1;
+            let x_r = x_m + p1;
+            let c = 0.134 + 20.5 / (15.3 + m);
+            let a_l = (f_m - x_l) / (f_m - x_l * r);
+            let lambda_l = a_l * (1.0 + 0.5 * a_l);
+            let a_r = (x_r - f_m) / (x_r * q);
+            let lambda_r = a_r * (1.0 + 0.5 * a_r);
+            // the cumulative areas of the triangle, the parallelograms,
+            // and the left and right exponential tails
+            let p2 = p1 * (1.0 + 2.0 * c);
+            let p3 = p2 + c / lambda_l;
+            let p4 = p3 + c / lambda_r;
+            #[rustfmt::skip]
+            let method = Method::Btpe {
+                r, npq, m, p1, p2, p3, p4, x_m, x_l, x_r, c, lambda_l, lambda_r,
+            };
+            method
+        };
+        Some(Self { n, p, method })
+    }
+
+    /// Returns the number of trials.
+    #[inline]
+    #[must_use]
+    pub const fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the probability of success of each trial.
+    #[inline]
+    #[must_use]
+    pub const fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Returns a sample of `B(n, r)`.
+    fn sample_reduced<P: Prng>(&self, prng: &mut P) -> u64 {
+        let nf = self.n as f64;
+        match self.method {
+            Method::Zero => 0,
+            Method::Inversion { r, q_pow_n } => {
+                let s = r / (1.0 - r);
+                let a = (nf + 1.0) * s;
+                'retry: loop {
+                    let mut u = prng.next_f64();
+                    let (mut k, mut f) = (0, q_pow_n);
+                    while u >= f {
+                        u -= f;
+                        k += 1;
+                        f *= a / k as f64 - s;
+                        // the accumulated rounding errors can leave u above 0
+                        if k > self.n || f == 0.0 {
+                            continue 'retry;
+                        }
+                    }
+                    return k;
+                }
+            }
+            #[rustfmt::skip]
+            Method::Btpe {
+                r, npq, m, p1, p2, p3, p4, x_m, x_l, x_r, c, lambda_l, lambda_r,
+            } => loop {
+                let q = 1.0 - r;
+                let u = prng.next_f64() * p4;
+                let mut v = prng.next_f64();
+                let y;
+                if u <= p1 {
+                    // the triangle, accepted without further tests
+                    return floor(x_m - p1 * v + u) as u64;
+                } else if u <= p2 {
+                    // the parallelograms
+                    let x = x_l + (u - p1) / c;
+                    v = v * c + 1.0 - abs(x - x_m) / p1;
+                    if v > 1.0 {
+                        continue;
+                    }
+                    y = floor(x);
+                } else if u <= p3 {
+                    // the left exponential tail
+                    y = floor(x_l + ln(v) / lambda_l);
+                    if y < 0.0 || v == 0.0 {
+                        continue;
+                    }
+                    v *= (u - p2) * lambda_l;
+                } else {
+                    // the right exponential tail
+                    y = floor(x_r - ln(v) / lambda_r);
+                    if y > nf || v == 0.0 {
+                        continue;
+                    }
+                    v *= (u - p3) * lambda_r;
+                }
+
+                let k = abs(y - m);
+                if k <= Self::SQUEEZE_THRESHOLD || k >= 0.5 * npq - 1.0 {
+                    // evaluate f(y) / f(m) recursively, from the mode
+                    let s = r / q;
+                    let a = s * (nf + 1.0);
+                    let mut f = 1.0;
+                    let mut i = m;
+                    while i < y {
+                        i += 1.0;
+                        f *= a / i - s;
+                    }
+                    let mut i = y;
+                    while i < m {
+                        i += 1.0;
+                        f /= a / i - s;
+                    }
+                    if v <= f {
+                        return y as u64;
+                    }
+                    continue;
+                }
+
+                // the squeeze, with bounds of ln(f(y) / f(m))
+                let rho = (k / npq) * ((k * (k / 3.0 + 0.625) + 1.0 / 6.0) / npq + 0.5);
+                let t = -0.5 * k * k / npq;
+                let alpha = ln(v);
+                if alpha < t - rho {
+                    return y as u64;
+                } else if alpha > t + rho {
+                    continue;
+                }
+
+                // the final comparison, with Stirling's approximation,
+                // using the signs of the GSL implementation
+                let x1 = y + 1.0;
+                let f1 = m + 1.0;
+                let z = nf + 1.0 - m;
+                let w = nf - y + 1.0;
+                let bound = x_m * ln(f1 / x1)
+                    + (nf - m + 0.5) * ln(z / w)
+                    + (y - m) * ln(w * r / (x1 * q))
+                    + stirling(f1)
+                    + stirling(z)
+                    - stirling(x1)
+                    - stirling(w);
+                if alpha <= bound {
+                    return y as u64;
+                }
+            },
+        }
+    }
+}
+
+/// The correction term of the Stirling series used by BTPE.
+#[inline]
+fn stirling(a: f64) -> f64 {
+    let a2 = a * a;
+    (13860. - (462. - (132. - (99. - 140. / a2) / a2) / a2) / a2) / a / 166_320.
+}
+
+impl Distribution<u64> for Binomial {
+    #[inline]
+    fn sample<P: Prng>(&self, prng: &mut P) -> u64 {
+        let k = self.sample_reduced(prng);
+        if self.p > 0.5 {
+            self.n - k
+        } else {
+            k
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::assert_moments, xorshift::XorShift64};
+
+    fn check_moments(n: u64, p: f64, seed: u64) {
+        let binomial = Binomial::new(n, p).unwrap();
+        let mut rng = XorShift64::new(seed).unwrap();
+        let sample = || {
+            let k = binomial.sample(&mut rng);
+            assert!(k <= n);
+            k as f64
+        };
+        let (mean, variance) = (n as f64 * p, n as f64 * p * (1.0 - p));
+        let kurtosis = (1.0 - 6.0 * p * (1.0 - p)) / variance;
+        assert_moments(
+            sample,
+            mean,
+            variance,
+            kurtosis,
+            format_args!("B({n}, {p})"),
+        );
+    }
+
+    #[test]
+    fn moments_inversion() {
+        check_moments(10, 0.3, 0xDEFA_0017);
+        check_moments(1000, 0.005, 0x1234_5678_9ABC);
+        check_moments(50, 0.9, 42);
+    }
+
+    #[test]
+    fn moments_btpe() {
+        check_moments(100, 0.5, 0xDEFA_0017);
+        check_moments(1000, 0.3, 0x1234_5678_9ABC);
+        check_moments(1_000_000, 0.77, 42);
+        check_moments(1 << 40, 0.001, 7);
+    }
+
+    #[test]
+    fn edge_cases() {
+        let mut rng = XorShift64::default();
+        assert_eq!(Binomial::new(0, 0.5).unwrap().sample(&mut rng), 0);
+        assert_eq!(Binomial::new(20, 0.0).unwrap().sample(&mut rng), 0);
+        assert_eq!(Binomial::new(20, 1.0).unwrap().sample(&mut rng), 20);
+        assert!(Binomial::new(20, -0.1).is_none());
+        assert!(Binomial::new(20, 1.1).is_none());
+        assert!(Binomial::new(20, f64::NAN).is_none());
+    }
+}
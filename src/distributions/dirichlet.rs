@@ -0,0 +1,113 @@
+// alazar::distributions::dirichlet
+//
+//! The Dirichlet distribution.
+//
+
+use super::{Beta, Distribution, Gamma};
+use crate::Prng;
+
+/// The Dirichlet distribution `Dir(α)`, of `N` categories.
+///
+/// Each sample is an array of `N` values in `[0, 1]` that add up to `1`.
+///
+/// When every parameter is at least `1` it's sampled by normalizing `N`
+/// samples of `Γ(αᵢ, 1)`. Otherwise it's sampled by stick-breaking, with
+/// [`Beta`] samples, which avoids the underflow of the gamma samples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dirichlet<const N: usize> {
+    alpha: [f64; N],
+}
+
+impl<const N: usize> Dirichlet<N> {
+    /// Returns a Dirichlet distribution with the given `alpha` parameters.
+    ///
+    /// Returns `None` if `N < 2`, or if any parameter is not positive or not finite.
+    #[must_use]
+    pub const fn new(alpha: [f64; N]) -> Option<Self> {
+        if N < 2 {
+            return None;
+        }
+        let mut i = 0;
+        while i < N {
+            if !(alpha[i].is_finite() && alpha[i] > 0.0) {
+                return None;
+            }
+            i += 1;
+        }
+        Some(Self { alpha })
+    }
+
+    /// Returns the parameters of the distribution.
+    #[inline]
+    #[must_use]
+    pub const fn alpha(&self) -> &[f64; N] {
+        &self.alpha
+    }
+}
+
+impl<const N: usize> Distribution<[f64; N]> for Dirichlet<N> {
+    fn sample<P: Prng>(&self, prng: &mut P) -> [f64; N] {
+        let mut values = [0.0; N];
+
+        if self.alpha.iter().all(|&a| a >= 1.0) {
+            let mut sum = 0.0;
+            for (v, &a) in values.iter_mut().zip(&self.alpha) {
+                *v = Gamma::new_unchecked(a, 1.0).sample(prng);
+                sum += *v;
+            }
+            values.iter_mut().for_each(|v| *v /= sum);
+        } else {
+            // the sums of the parameters after each index
+            let mut rest = [0.0; N];
+            for i in (0..N - 1).rev() {
+                rest[i] = rest[i + 1] + self.alpha[i + 1];
+            }
+            let mut stick = 1.0;
+            for i in 0..N - 1 {
+                values[i] = stick * Beta::new_unchecked(self.alpha[i], rest[i]).sample(prng);
+                stick -= values[i];
+            }
+            values[N - 1] = stick;
+        }
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{math::abs, xorshift::XorShift64};
+
+    /// Checks that the components are in `[0, 1]` and add up to `1`.
+    fn check_sum<const N: usize>(alpha: [f64; N], seed: u64) {
+        let dirichlet = Dirichlet::new(alpha).unwrap();
+        let mut rng = XorShift64::new(seed).unwrap();
+        for _ in 0..10_000 {
+            let values = dirichlet.sample(&mut rng);
+            assert!(values.iter().all(|v| (0.0..=1.0).contains(v)), "{values:?}");
+            let sum: f64 = values.iter().sum();
+            assert!(abs(sum - 1.0) < 1e-12, "Dir({alpha:?}): sum {sum}");
+        }
+    }
+
+    #[test]
+    fn sum_gamma() {
+        check_sum([1.0, 1.0], 0xDEFA_0017);
+        check_sum([1.5, 2.0, 30.0], 0x1234_5678_9ABC);
+        check_sum([1.0; 10], 42);
+    }
+
+    #[test]
+    fn sum_stick_breaking() {
+        check_sum([0.5, 0.5], 0xDEFA_0017);
+        check_sum([0.1, 2.0, 0.3], 0x1234_5678_9ABC);
+        check_sum([0.05; 10], 42);
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert!(Dirichlet::new([1.0]).is_none());
+        assert!(Dirichlet::new([1.0, 0.0]).is_none());
+        assert!(Dirichlet::new([f64::NAN, 1.0, 1.0]).is_none());
+    }
+}
@@ -0,0 +1,171 @@
+// alazar::distributions::exponential
+//
+//! The exponential distribution.
+//
+
+use super::Distribution;
+use crate::{
+    math::{exp, ln},
+    Prng,
+};
+
+/// The exponential distribution `Exp(λ)`.
+///
+/// It's sampled with the ziggurat method, with 256 layers, as described by
+/// George Marsaglia and Wai Wan Tsang in [*The Ziggurat Method for Generating
+/// Random Variables*][zig] (2000). Its tables are computed at compile time.
+///
+/// [zig]: https://doi.org/10.18637/jss.v005.i08
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Exponential {
+    lambda: f64,
+}
+
+impl Default for Exponential {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+impl Exponential {
+    /// The standard exponential distribution `Exp(1)`.
+    pub const STANDARD: Self = Self { lambda: 1.0 };
+
+    /// Returns an exponential distribution with the given rate `lambda`.
+    ///
+    /// Returns `None` if `lambda` is not positive or not finite.
+    #[inline]
+    #[must_use]
+    pub const fn new(lambda: f64) -> Option<Self> {
+        if lambda.is_finite() && lambda > 0.0 {
+            Some(Self { lambda })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the rate of the distribution.
+    #[inline]
+    #[must_use]
+    pub const fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Returns a sample from the standard exponential distribution.
+    #[must_use]
+    pub fn sample_standard<P: Prng>(prng: &mut P) -> f64 {
+        let (x, f, ratio) = (&ZIGGURAT.x, &ZIGGURAT.f, &ZIGGURAT.ratio);
+        loop {
+            let bits = prng.next_u64();
+            let i = (bits & ZIG_INDEX_MASK) as usize;
+            // uniform in [0, 1), from the upper 53 bits
+            let u = (bits >> 11) as f64 * ZIG_SCALE;
+
+            // the rectangular part of a layer
+            if u < ratio[i] {
+                return u * x[i];
+            }
+            // the base layer overflows into the memoryless tail
+            if i == 0 {
+                return ZIG_R - ln(prng.next_f64_open());
+            }
+            // the wedge between the rectangle and the curve
+            let z = u * x[i];
+            if f[i] + prng.next_f64() * (f[i + 1] - f[i]) < exp(-z) {
+                return z;
+            }
+        }
+    }
+}
+
+impl Distribution<f64> for Exponential {
+    #[inline]
+    fn sample<P: Prng>(&self, prng: &mut P) -> f64 {
+        Self::sample_standard(prng) / self.lambda
+    }
+}
+
+/* ziggurat */
+
+const ZIG_LAYERS: usize = 256;
+const ZIG_INDEX_MASK: u64 = ZIG_LAYERS as u64 - 1;
+const ZIG_SCALE: f64 = 1.0 / (1_u64 << 53) as f64;
+/// The start of the tail.
+const ZIG_R: f64 = 7.697_117_470_131_05;
+/// The area of each layer.
+const ZIG_V: f64 = 3.949_659_822_581_557e-3;
+
+/// The ziggurat tables of the standard exponential distribution.
+static ZIGGURAT: Ziggurat = Ziggurat::new();
+
+struct Ziggurat {
+    /// The right edge of each layer, decreasing, where `x[0]` is the virtual
+    /// width of the base layer, `x[1]` is `ZIG_R` and `x[ZIG_LAYERS]` is `0`.
+    x: [f64; ZIG_LAYERS + 1],
+    /// The density at each edge.
+    f: [f64; ZIG_LAYERS + 1],
+    /// The ratio between the width of the layer above and of each layer.
+    ratio: [f64; ZIG_LAYERS],
+}
+
+impl Ziggurat {
+    const fn new() -> Self {
+        let mut x = [0.0; ZIG_LAYERS + 1];
+        x[0] = ZIG_V / exp(-ZIG_R);
+        x[1] = ZIG_R;
+        let mut i = 2;
+        while i < ZIG_LAYERS {
+            x[i] = -ln(ZIG_V / x[i - 1] + exp(-x[i - 1]));
+            i += 1;
+        }
+        let mut f = [0.0; ZIG_LAYERS + 1];
+        let mut ratio = [0.0; ZIG_LAYERS];
+        let mut i = 0;
+        while i < ZIG_LAYERS {
+            f[i] = exp(-x[i]);
+            ratio[i] = x[i + 1] / x[i];
+            i += 1;
+        }
+        f[ZIG_LAYERS] = 1.0;
+        Self { x, f, ratio }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::assert_moments, xorshift::XorShift64};
+
+    fn check_moments(lambda: f64, seed: u64) {
+        let exponential = Exponential::new(lambda).unwrap();
+        let mut rng = XorShift64::new(seed).unwrap();
+        let mean = 1.0 / lambda;
+        let sample = || {
+            let x = exponential.sample(&mut rng);
+            assert!(x >= 0.0);
+            x
+        };
+        assert_moments(
+            sample,
+            mean,
+            mean * mean,
+            6.0,
+            format_args!("Exp({lambda})"),
+        );
+    }
+
+    #[test]
+    fn moments() {
+        check_moments(1.0, 0xDEFA_0017);
+        check_moments(0.25, 0x1234_5678_9ABC);
+        check_moments(40.0, 42);
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert!(Exponential::new(0.0).is_none());
+        assert!(Exponential::new(-1.0).is_none());
+        assert!(Exponential::new(f64::NAN).is_none());
+        assert!(Exponential::new(f64::INFINITY).is_none());
+    }
+}
@@ -0,0 +1,288 @@
+// alazar::distributions::gamma
+//
+//! The gamma distribution and its derived chi-squared and Student's t.
+//
+
+use super::{Distribution, Normal};
+use crate::{
+    math::{exp, ln, sqrt},
+    Prng,
+};
+
+/// The gamma distribution `Γ(k, θ)`, with shape `k` and scale `θ`.
+///
+/// It's sampled with the method described by George Marsaglia and Wai Wan Tsang
+/// in [*A Simple Method for Generating Gamma Variables*][mt] (2000), which uses
+/// a [`Normal`] sample and a uniform sample, and a rejection rate below 5%.
+///
+/// When the shape is lower than `1` the sample is boosted from `Γ(k + 1, θ)`.
+///
+/// [mt]: https://doi.org/10.1145/358407.358414
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gamma {
+    shape: f64,
+    scale: f64,
+    // the constants of the method, for max(shape, shape + 1)
+    d: f64,
+    c: f64,
+}
+
+impl Gamma {
+    /// Returns a gamma distribution with the given `shape` and `scale`.
+    ///
+    /// Returns `None` if either parameter is not positive or not finite.
+    #[inline]
+    #[must_use]
+    pub const fn new(shape: f64, scale: f64) -> Option<Self> {
+        if shape.is_finite() && shape > 0.0 && scale.is_finite() && scale > 0.0 {
+            Some(Self::new_unchecked(shape, scale))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a gamma distribution, from already validated parameters.
+    #[inline]
+    pub(crate) const fn new_unchecked(shape: f64, scale: f64) -> Self {
+        let boosted = if shape < 1.0 { shape + 1.0 } else { shape };
+        let d = boosted - 1.0 / 3.0;
+        let c = 1.0 / sqrt(9.0 * d);
+        Self { shape, scale, d, c }
+    }
+
+    /// Returns the shape of the distribution.
+    #[inline]
+    #[must_use]
+    pub const fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns the scale of the distribution.
+    #[inline]
+    #[must_use]
+    pub const fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns a sample of `Γ(k, 1)`.
+    #[inline]
+    fn sample_unscaled<P: Prng>(&self, prng: &mut P) -> f64 {
+        let x = self.sample_marsaglia_tsang(prng);
+        if self.shape < 1.0 {
+            x * exp(ln(prng.next_f64_open()) / self.shape)
+        } else {
+            x
+        }
+    }
+
+    /// Returns the natural logarithm of a sample of `Γ(k, 1)`.
+    ///
+    /// This avoids the underflow of the samples when the shape is very small.
+    #[inline]
+    pub(crate) fn sample_ln_unscaled<P: Prng>(&self, prng: &mut P) -> f64 {
+        let x = ln(self.sample_marsaglia_tsang(prng));
+        if self.shape < 1.0 {
+            x + ln(prng.next_f64_open()) / self.shape
+        } else {
+            x
+        }
+    }
+
+    /// Returns a sample of `Γ(max(k, k + 1), 1)`.
+    fn sample_marsaglia_tsang<P: Prng>(&self, prng: &mut P) -> f64 {
+        let (d, c) = (self.d, self.c);
+        loop {
+            let x = Normal::sample_standard(prng);
+            let v = 1.0 + c * x;
+            if v <= 0.0 {
+                continue;
+            }
+            let v = v * v * v;
+            let u = prng.next_f64_open();
+            let x2 = x * x;
+            // the squeeze, then the full test
+            if u < 1.0 - 0.0331 * x2 * x2 || ln(u) < 0.5 * x2 + d * (1.0 - v + ln(v)) {
+                return d * v;
+            }
+        }
+    }
+}
+
+impl Distribution<f64> for Gamma {
+    #[inline]
+    fn sample<P: Prng>(&self, prng: &mut P) -> f64 {
+        self.sample_unscaled(prng) * self.scale
+    }
+}
+
+/// The chi-squared distribution `χ²(k)`, with `k` degrees of freedom.
+///
+/// It's sampled as `Γ(k/2, 2)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChiSquared {
+    k: f64,
+    gamma: Gamma,
+}
+
+impl ChiSquared {
+    /// Returns a chi-squared distribution with `k` degrees of freedom.
+    ///
+    /// Returns `None` if `k` is not positive or not finite.
+    #[inline]
+    #[must_use]
+    pub const fn new(k: f64) -> Option<Self> {
+        if k.is_finite() && k > 0.0 {
+            Some(Self {
+                k,
+                gamma: Gamma::new_unchecked(0.5 * k, 2.0),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the degrees of freedom of the distribution.
+    #[inline]
+    #[must_use]
+    pub const fn k(&self) -> f64 {
+        self.k
+    }
+}
+
+impl Distribution<f64> for ChiSquared {
+    #[inline]
+    fn sample<P: Prng>(&self, prng: &mut P) -> f64 {
+        self.gamma.sample(prng)
+    }
+}
+
+/// Student's t distribution `t(ν)`, with `ν` degrees of freedom.
+///
+/// It's sampled as `Z / √(V/ν)`, where `Z` is a standard [`Normal`]
+/// and `V` a [`ChiSquared`] with `ν` degrees of freedom.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StudentT {
+    chi: ChiSquared,
+}
+
+impl StudentT {
+    /// Returns a Student's t distribution with `nu` degrees of freedom.
+    ///
+    /// Returns `None` if `nu` is not positive or not finite.
+    #[inline]
+    #[must_use]
+    pub const fn new(nu: f64) -> Option<Self> {
+        match ChiSquared::new(nu) {
+            Some(chi) => Some(Self { chi }),
+            None => None,
+        }
+    }
+
+    /// Returns the degrees of freedom of the distribution.
+    #[inline]
+    #[must_use]
+    pub const fn nu(&self) -> f64 {
+        self.chi.k
+    }
+}
+
+impl Distribution<f64> for StudentT {
+    #[inline]
+    fn sample<P: Prng>(&self, prng: &mut P) -> f64 {
+        let z = Normal::sample_standard(prng);
+        z * sqrt(self.chi.k / self.chi.sample(prng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        math::abs,
+        test_utils::{assert_moments, assert_uniform},
+        xorshift::XorShift64,
+    };
+
+    fn check_moments(shape: f64, scale: f64, seed: u64) {
+        let gamma = Gamma::new(shape, scale).unwrap();
+        let mut rng = XorShift64::new(seed).unwrap();
+        let sample = || {
+            let x = gamma.sample(&mut rng);
+            assert!(x >= 0.0);
+            x
+        };
+        let (mean, variance) = (shape * scale, shape * scale * scale);
+        assert_moments(
+            sample,
+            mean,
+            variance,
+            6.0 / shape,
+            format_args!("Γ({shape}, {scale})"),
+        );
+    }
+
+    #[test]
+    fn moments_small_shape() {
+        check_moments(0.1, 1.0, 0xDEFA_0017);
+        check_moments(0.5, 3.0, 0x1234_5678_9ABC);
+        check_moments(0.9, 0.2, 42);
+    }
+
+    #[test]
+    fn moments_large_shape() {
+        check_moments(1.0, 1.0, 0xDEFA_0017);
+        check_moments(2.5, 0.5, 0x1234_5678_9ABC);
+        check_moments(100.0, 4.0, 42);
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert!(Gamma::new(0.0, 1.0).is_none());
+        assert!(Gamma::new(1.0, -1.0).is_none());
+        assert!(Gamma::new(f64::NAN, 1.0).is_none());
+        assert!(Gamma::new(1.0, f64::INFINITY).is_none());
+        assert!(ChiSquared::new(0.0).is_none());
+        assert!(ChiSquared::new(f64::NAN).is_none());
+        assert!(StudentT::new(-1.0).is_none());
+        assert!(StudentT::new(f64::INFINITY).is_none());
+    }
+
+    #[test]
+    fn chi_squared_moments() {
+        for (k, seed) in [(1.0, 0xDEFA_0017), (3.5, 0x1234_5678_9ABC), (200.0, 42)] {
+            let chi = ChiSquared::new(k).unwrap();
+            let mut rng = XorShift64::new(seed).unwrap();
+            let sample = || {
+                let x = chi.sample(&mut rng);
+                assert!(x >= 0.0);
+                x
+            };
+            assert_moments(sample, k, 2.0 * k, 12.0 / k, format_args!("χ²({k})"));
+        }
+    }
+
+    #[test]
+    fn student_t_moments() {
+        // the excess kurtosis is only finite for ν > 4
+        for (nu, seed) in [(10.0, 0xDEFA_0017), (30.0, 0x1234_5678_9ABC), (1e4, 42)] {
+            let t = StudentT::new(nu).unwrap();
+            let mut rng = XorShift64::new(seed).unwrap();
+            let sample = || t.sample(&mut rng);
+            let (variance, kurtosis) = (nu / (nu - 2.0), 6.0 / (nu - 4.0));
+            assert_moments(sample, 0.0, variance, kurtosis, format_args!("t({nu})"));
+        }
+    }
+
+    #[test]
+    fn student_t_cauchy() {
+        // t(1) is the standard Cauchy distribution, with its quartiles at ±1
+        const SAMPLES: usize = 100_000;
+        let t = StudentT::new(1.0).unwrap();
+        let mut rng = XorShift64::new(7).unwrap();
+        let mut counts = [0; 2];
+        for _ in 0..SAMPLES {
+            counts[usize::from(abs(t.sample(&mut rng)) < 1.0)] += 1;
+        }
+        assert_uniform(&counts, "t(1) quartiles");
+    }
+}
@@ -0,0 +1,37 @@
+// alazar::distributions
+//
+//! Sampling from probability distributions.
+//!
+//! Every distribution is driven by any [`Prng`] and works without `std`.
+//! The floating-point functions they need are implemented in software,
+//! so a sample only depends on the generator state, on every platform.
+//
+
+use crate::Prng;
+
+mod beta;
+mod binomial;
+mod dirichlet;
+mod exponential;
+mod gamma;
+mod normal;
+mod poisson;
+mod weighted;
+
+pub use beta::Beta;
+pub use binomial::Binomial;
+pub use dirichlet::Dirichlet;
+pub use exponential::Exponential;
+pub use gamma::{ChiSquared, Gamma, StudentT};
+pub use normal::Normal;
+pub use poisson::Poisson;
+pub use weighted::{AliasTable, DynamicWeightedIndex, Weight};
+#[cfg(feature = "alloc")]
+pub use weighted::{AliasTableVec, DynamicWeightedIndexVec};
+
+/// A probability distribution that can be sampled with any [`Prng`].
+pub trait Distribution<T> {
+    /// Returns a random value sampled from the distribution.
+    #[must_use]
+    fn sample<P: Prng>(&self, prng: &mut P) -> T;
+}
@@ -0,0 +1,277 @@
+// alazar::distributions::normal
+//
+//! The normal distribution.
+//
+
+use super::Distribution;
+use crate::{
+    math::{abs, exp, ln, sqrt},
+    Prng,
+};
+
+/// The normal (Gaussian) distribution `N(mean, std_dev²)`.
+///
+/// It's sampled by default with the ziggurat method, with 128 layers, as
+/// described by Jurgen A. Doornik in [*An Improved Ziggurat Method to Generate
+/// Normal Random Samples*][doornik] (2005). Its tables are computed at compile
+/// time. Each sample usually consumes a single [`next_u64`][Prng::next_u64].
+///
+/// The Marsaglia polar method is also available as a fallback, with
+/// [`sample_standard_polar`][Self::sample_standard_polar].
+///
+/// [doornik]: https://www.doornik.com/research/ziggurat.pdf
+///
+/// # Examples
+/// ```
+/// use alazar::{distributions::{Distribution, Normal}, xorshift::XorShift128p};
+///
+/// let mut rng = XorShift128p::default();
+/// let normal = Normal::new(10.0, 2.0).unwrap();
+/// let value: f64 = normal.sample(&mut rng);
+/// # assert!(value.is_finite());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Default for Normal {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+impl Normal {
+    /// The standard normal distribution `N(0, 1)`.
+    pub const STANDARD: Self = Self {
+        mean: 0.0,
+        std_dev: 1.0,
+    };
+
+    /// Returns a normal distribution with the given `mean` and `std_dev`.
+    ///
+    /// Returns `None` if `mean` is not finite,
+    /// or if `std_dev` is negative or not finite.
+    #[inline]
+    #[must_use]
+    pub const fn new(mean: f64, std_dev: f64) -> Option<Self> {
+        if mean.is_finite() && std_dev.is_finite() && std_dev >= 0.0 {
+            Some(Self { mean, std_dev })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the mean of the distribution.
+    #[inline]
+    #[must_use]
+    pub const fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the standard deviation of the distribution.
+    #[inline]
+    #[must_use]
+    pub const fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+
+    /// Returns a sample from the standard normal distribution, using the ziggurat.
+    #[must_use]
+    pub fn sample_standard<P: Prng>(prng: &mut P) -> f64 {
+        let (x, ratio) = (&ZIGGURAT.x, &ZIGGURAT.ratio);
+        loop {
+            let bits = prng.next_u64();
+            let i = (bits & ZIG_INDEX_MASK) as usize;
+            // uniform in [-1, 1), from the upper 53 bits
+            let u = (bits >> 11) as f64 * ZIG_SCALE - 1.0;
+
+            // the rectangular part of a layer
+            if abs(u) < ratio[i] {
+                return u * x[i];
+            }
+            // the base layer overflows into the tail
+            if i == 0 {
+                return sample_tail(prng, u < 0.0);
+            }
+            // the wedge between the rectangle and the curve
+            let z = u * x[i];
+            let f0 = exp(-0.5 * (x[i] * x[i] - z * z));
+            let f1 = exp(-0.5 * (x[i + 1] * x[i + 1] - z * z));
+            if f1 + prng.next_f64() * (f0 - f1) < 1.0 {
+                return z;
+            }
+        }
+    }
+
+    /// Returns 2 independent samples from the standard normal distribution,
+    /// using the Marsaglia polar method.
+    ///
+    /// This method is slower than the ziggurat but doesn't need any tables.
+    #[must_use]
+    pub fn sample_standard_polar<P: Prng>(prng: &mut P) -> [f64; 2] {
+        loop {
+            let u = 2.0 * prng.next_f64() - 1.0;
+            let v = 2.0 * prng.next_f64() - 1.0;
+            let s = u * u + v * v;
+            if s < 1.0 && s > 0.0 {
+                let m = sqrt(-2.0 * ln(s) / s);
+                return [u * m, v * m];
+            }
+        }
+    }
+}
+
+impl Distribution<f64> for Normal {
+    #[inline]
+    fn sample<P: Prng>(&self, prng: &mut P) -> f64 {
+        self.mean + self.std_dev * Self::sample_standard(prng)
+    }
+}
+
+/// Returns a sample from the tail of the distribution, beyond `ZIG_R`.
+//
+// Marsaglia, "Generating a variable from the tail of the normal distribution" (1964).
+#[cold]
+fn sample_tail<P: Prng>(prng: &mut P, negative: bool) -> f64 {
+    loop {
+        let x = ln(prng.next_f64_open()) / ZIG_R;
+        let y = ln(prng.next_f64_open());
+        if -2.0 * y >= x * x {
+            return if negative { x - ZIG_R } else { ZIG_R - x };
+        }
+    }
+}
+
+/* ziggurat */
+
+const ZIG_LAYERS: usize = 128;
+const ZIG_INDEX_MASK: u64 = ZIG_LAYERS as u64 - 1;
+const ZIG_SCALE: f64 = 1.0 / (1_u64 << 52) as f64;
+/// The start of the tail.
+const ZIG_R: f64 = 3.442_619_855_899;
+/// The area of each layer.
+const ZIG_V: f64 = 9.912_563_035_262_17e-3;
+
+/// The ziggurat tables of the standard normal distribution.
+static ZIGGURAT: Ziggurat = Ziggurat::new();
+
+struct Ziggurat {
+    /// The right edge of each layer, decreasing, where `x[0]` is the virtual
+    /// width of the base layer, `x[1]` is `ZIG_R` and `x[ZIG_LAYERS]` is `0`.
+    x: [f64; ZIG_LAYERS + 1],
+    /// The ratio between the width of the layer above and of each layer.
+    ratio: [f64; ZIG_LAYERS],
+}
+
+impl Ziggurat {
+    const fn new() -> Self {
+        let mut x = [0.0; ZIG_LAYERS + 1];
+        let mut f = exp(-0.5 * ZIG_R * ZIG_R);
+        x[0] = ZIG_V / f;
+        x[1] = ZIG_R;
+        let mut i = 2;
+        while i < ZIG_LAYERS {
+            x[i] = sqrt(-2.0 * ln(ZIG_V / x[i - 1] + f));
+            f = exp(-0.5 * x[i] * x[i]);
+            i += 1;
+        }
+        let mut ratio = [0.0; ZIG_LAYERS];
+        let mut i = 0;
+        while i < ZIG_LAYERS {
+            ratio[i] = x[i + 1] / x[i];
+            i += 1;
+        }
+        Self { x, ratio }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_frequencies, assert_moments},
+        xorshift::XorShift64,
+    };
+
+    #[test]
+    fn moments() {
+        for (mean, std_dev, seed) in [(0.0, 1.0, 0xDEFA_0017), (10.0, 2.0, 0x1234_5678_9ABC)] {
+            let normal = Normal::new(mean, std_dev).unwrap();
+            let mut rng = XorShift64::new(seed).unwrap();
+            let sample = || normal.sample(&mut rng);
+            let what = format_args!("N({mean}, {std_dev}²)");
+            assert_moments(sample, mean, std_dev * std_dev, 0.0, what);
+        }
+    }
+
+    #[test]
+    fn moments_polar() {
+        let mut rng = XorShift64::new(42).unwrap();
+        let (mut pair, mut next) = ([0.0; 2], 2);
+        let sample = || {
+            if next == 2 {
+                (pair, next) = (Normal::sample_standard_polar(&mut rng), 0);
+            }
+            next += 1;
+            pair[next - 1]
+        };
+        assert_moments(sample, 0.0, 1.0, 0.0, format_args!("polar"));
+    }
+
+    #[test]
+    fn tail_mass() {
+        const SAMPLES: usize = 1_000_000;
+        // the mass of each tail beyond `ZIG_R` and beyond 4
+        const BEYOND_R: f64 = 0.5 * 5.761_085_123_916_4e-4;
+        const BEYOND_4: f64 = 0.5 * 6.334_248_366_624e-5;
+        let mut rng = XorShift64::new(7).unwrap();
+        // the core, then (R, 4] and beyond 4 on each side
+        let mut counts = [0; 5];
+        for _ in 0..SAMPLES {
+            let x = Normal::sample_standard(&mut rng);
+            let i = match abs(x) {
+                a if a <= ZIG_R => 0,
+                a if a <= 4.0 => 1,
+                _ => 2,
+            };
+            counts[if x < 0.0 && i > 0 { i + 2 } else { i }] += 1;
+        }
+        let tail = [BEYOND_R - BEYOND_4, BEYOND_4];
+        let weights = [1.0 - 2.0 * BEYOND_R, tail[0], tail[1], tail[0], tail[1]];
+        assert_frequencies(&counts, &weights, "normal tails");
+    }
+
+    #[test]
+    fn deterministic() {
+        // pins the output for a fixed seed, to catch unintended changes
+        let mut rng = XorShift64::new(0xDEFA_0017).unwrap();
+        let samples: [f64; 4] = core::array::from_fn(|_| Normal::sample_standard(&mut rng));
+        assert_eq!(
+            samples,
+            [
+                -0.6890169449814071,
+                -0.0450609473373135,
+                -1.603658133296508,
+                -0.0013630237563890943
+            ]
+        );
+        let polar = Normal::sample_standard_polar(&mut rng);
+        assert_eq!(polar, [-0.01213355688034374, 0.9417941083171728]);
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert!(Normal::new(f64::NAN, 1.0).is_none());
+        assert!(Normal::new(f64::INFINITY, 1.0).is_none());
+        assert!(Normal::new(0.0, -1.0).is_none());
+        assert!(Normal::new(0.0, f64::INFINITY).is_none());
+        assert_eq!(
+            Normal::new(3.0, 0.0)
+                .unwrap()
+                .sample(&mut XorShift64::default()),
+            3.0
+        );
+    }
+}
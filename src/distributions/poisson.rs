@@ -0,0 +1,172 @@
+// alazar::distributions::poisson
+//
+//! The Poisson distribution.
+//
+
+use super::Distribution;
+use crate::{
+    math::{abs, exp, floor, ln, ln_factorial, sqrt},
+    Prng,
+};
+
+/// The Poisson distribution `Poisson(λ)`.
+///
+/// It's sampled exactly by one of two methods, depending on `λ`:
+/// - below 10, by inversion with a sequential search from `0`,
+///   with a single uniform sample.
+/// - from 10, by the transformed rejection with squeeze (PTRS) method,
+///   described by Wolfgang Hörmann in [*The transformed rejection method for
+///   generating Poisson random variables*][ptrs] (1993), which takes about
+///   1.15 pairs of uniform samples regardless of `λ`.
+///
+/// [ptrs]: https://doi.org/10.1016/0167-6687(93)90997-4
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Poisson {
+    lambda: f64,
+    method: Method,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Method {
+    Inversion {
+        exp_neg_lambda: f64,
+    },
+    Ptrs {
+        ln_lambda: f64,
+        a: f64,
+        b: f64,
+        ln_inv_alpha: f64,
+        v_r: f64,
+    },
+}
+
+impl Poisson {
+    /// The maximum supported `λ`.
+    ///
+    /// Above it the samples couldn't be represented exactly by an `f64`.
+    pub const MAX_LAMBDA: f64 = (1_u64 << 53) as f64;
+
+    /// The threshold of `λ` from which the PTRS method is used.
+    const PTRS_THRESHOLD: f64 = 10.0;
+
+    /// Returns a Poisson distribution with the given mean `lambda`.
+    ///
+    /// Returns `None` if `lambda` is not positive, or greater than [`MAX_LAMBDA`].
+    ///
+    /// [`MAX_LAMBDA`]: Self::MAX_LAMBDA
+    #[must_use]
+    pub const fn new(lambda: f64) -> Option<Self> {
+        if !(lambda > 0.0 && lambda <= Self::MAX_LAMBDA) {
+            return None;
+        }
+        let method = if lambda < Self::PTRS_THRESHOLD {
+            Method::Inversion {
+                exp_neg_lambda: exp(-lambda),
+            }
+        } else {
+            let b = 0.931 + 2.53 * sqrt(lambda);
+            Method::Ptrs {
+                ln_lambda: ln(lambda),
+                a: -0.059 + 0.02483 * b,
+                b,
+                ln_inv_alpha: ln(1.1239 + 1.1328 / (b - 3.4)),
+                v_r: 0.9277 - 3.6224 / (b - 2.0),
+            }
+        };
+        Some(Self { lambda, method })
+    }
+
+    /// Returns the mean of the distribution.
+    #[inline]
+    #[must_use]
+    pub const fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl Distribution<u64> for Poisson {
+    fn sample<P: Prng>(&self, prng: &mut P) -> u64 {
+        let lambda = self.lambda;
+        match self.method {
+            Method::Inversion { exp_neg_lambda } => 'retry: loop {
+                let mut u = prng.next_f64();
+                let (mut k, mut p) = (0, exp_neg_lambda);
+                while u >= p {
+                    u -= p;
+                    k += 1;
+                    p *= lambda / k as f64;
+                    // the accumulated rounding errors can leave u above 0
+                    if p == 0.0 {
+                        continue 'retry;
+                    }
+                }
+                return k;
+            },
+            Method::Ptrs {
+                ln_lambda,
+                a,
+                b,
+                ln_inv_alpha,
+                v_r,
+            } => loop {
+                let u = prng.next_f64() - 0.5;
+                let v = prng.next_f64_open();
+                let us = 0.5 - abs(u);
+                let k = floor((2.0 * a / us + b) * u + lambda + 0.43);
+                // the fast acceptance
+                if us >= 0.07 && v <= v_r {
+                    return k as u64;
+                }
+                if k < 0.0 || (us < 0.013 && v > us) {
+                    continue;
+                }
+                let lhs = ln(v) + ln_inv_alpha - ln(a / (us * us) + b);
+                if lhs <= -lambda + k * ln_lambda - ln_factorial(k) {
+                    return k as u64;
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::assert_moments, xorshift::XorShift64};
+
+    fn check_moments(lambda: f64, seed: u64) {
+        let poisson = Poisson::new(lambda).unwrap();
+        let mut rng = XorShift64::new(seed).unwrap();
+        let sample = || poisson.sample(&mut rng) as f64;
+        assert_moments(
+            sample,
+            lambda,
+            lambda,
+            1.0 / lambda,
+            format_args!("λ {lambda}"),
+        );
+    }
+
+    #[test]
+    fn moments_inversion() {
+        check_moments(0.1, 0xDEFA_0017);
+        check_moments(1.0, 0x1234_5678_9ABC);
+        check_moments(9.5, 42);
+    }
+
+    #[test]
+    fn moments_ptrs() {
+        check_moments(10.0, 0xDEFA_0017);
+        check_moments(123.4, 0x1234_5678_9ABC);
+        check_moments(1e6, 42);
+        check_moments(1e12, 7);
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert!(Poisson::new(0.0).is_none());
+        assert!(Poisson::new(-1.0).is_none());
+        assert!(Poisson::new(f64::NAN).is_none());
+        assert!(Poisson::new(f64::INFINITY).is_none());
+    }
+}
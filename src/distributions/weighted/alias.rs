@@ -0,0 +1,276 @@
+// alazar::distributions::weighted::alias
+//
+//! Alias tables.
+//
+
+use super::{checked_sum, Weight};
+use crate::{distributions::Distribution, prng::uniform_usize, Prng};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// A table for sampling weighted indices in *O(1)*, with capacity for `N` weights.
+///
+/// It's built in *O(n)* with Vose's version of Walker's alias method, described in
+/// [*A linear algorithm for generating random numbers with a given
+/// distribution*][vose] (1991), and doesn't allocate.
+///
+/// Each sample takes a bounded index and an `f64` in `[0, 1)`.
+///
+/// See also [`AliasTableVec`], for a table allocated on the heap.
+///
+/// [vose]: https://doi.org/10.1109/32.92917
+///
+/// # Examples
+/// ```
+/// use alazar::{distributions::{AliasTable, Distribution}, misc::Xabc};
+///
+/// let mut rng = Xabc::default();
+/// let loot = AliasTable::<8>::new(&[60_u8, 30, 9, 1]).unwrap();
+/// let index = loot.sample(&mut rng);
+/// assert!(index < 4);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AliasTable<const N: usize> {
+    len: usize,
+    prob: [f64; N],
+    alias: [usize; N],
+}
+
+impl<const N: usize> AliasTable<N> {
+    /// Returns a new alias table for the given `weights`.
+    ///
+    /// Returns `None` if there are no weights or more than `N`, if any weight
+    /// is negative or not finite, or if their sum is not positive and finite.
+    #[must_use]
+    pub fn new<W: Weight>(weights: &[W]) -> Option<Self> {
+        if weights.len() > N {
+            return None;
+        }
+        let mut table = Self {
+            len: weights.len(),
+            prob: [0.0; N],
+            alias: [0; N],
+        };
+        let mut work = [0; N];
+        build(
+            weights,
+            &mut table.prob[..weights.len()],
+            &mut table.alias,
+            &mut work,
+        )?;
+        Some(table)
+    }
+
+    /// Returns the number of weights.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no weights.
+    ///
+    /// This is never the case for a successfully built table.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of weights.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> Distribution<usize> for AliasTable<N> {
+    #[inline]
+    fn sample<P: Prng>(&self, prng: &mut P) -> usize {
+        sample(prng, &self.prob[..self.len], &self.alias)
+    }
+}
+
+/// A table for sampling weighted indices in *O(1)*, allocated on the heap.
+///
+/// This is the heap allocated version of [`AliasTable`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AliasTableVec {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl AliasTableVec {
+    /// Returns a new alias table for the given `weights`.
+    ///
+    /// Returns `None` if there are no weights, if any weight is negative
+    /// or not finite, or if their sum is not positive and finite.
+    #[must_use]
+    pub fn new<W: Weight>(weights: &[W]) -> Option<Self> {
+        let mut table = Self {
+            prob: vec![0.0; weights.len()],
+            alias: vec![0; weights.len()],
+        };
+        let mut work = vec![0; weights.len()];
+        build(weights, &mut table.prob, &mut table.alias, &mut work)?;
+        Some(table)
+    }
+
+    /// Returns the number of weights.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.prob.len()
+    }
+
+    /// Returns `true` if there are no weights.
+    ///
+    /// This is never the case for a successfully built table.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.prob.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Distribution<usize> for AliasTableVec {
+    #[inline]
+    fn sample<P: Prng>(&self, prng: &mut P) -> usize {
+        sample(prng, &self.prob, &self.alias)
+    }
+}
+
+/// Fills the `prob` and `alias` columns for the given `weights`.
+///
+/// The `alias` and `work` slices must be at least as long as `prob`,
+/// which must be as long as `weights`.
+fn build<W: Weight>(
+    weights: &[W],
+    prob: &mut [f64],
+    alias: &mut [usize],
+    work: &mut [usize],
+) -> Option<()> {
+    let n = prob.len();
+    let scale = n as f64 / checked_sum(weights)?;
+
+    // the indices of the small columns are stacked from the front of `work`,
+    // and the ones of the large columns from the back
+    let (mut small, mut large) = (0, n);
+    for (i, (p, w)) in prob.iter_mut().zip(weights).enumerate() {
+        *p = w.to_f64() * scale;
+        if *p < 1.0 {
+            work[small] = i;
+            small += 1;
+        } else {
+            large -= 1;
+            work[large] = i;
+        }
+    }
+    while small > 0 && large < n {
+        small -= 1;
+        let s = work[small];
+        let l = work[large];
+        large += 1;
+
+        alias[s] = l;
+        prob[l] = (prob[l] + prob[s]) - 1.0;
+        if prob[l] < 1.0 {
+            work[small] = l;
+            small += 1;
+        } else {
+            large -= 1;
+            work[large] = l;
+        }
+    }
+    // the remaining columns are full, except for rounding errors
+    for &i in work[..small].iter().chain(&work[large..n]) {
+        prob[i] = 1.0;
+        alias[i] = i;
+    }
+    Some(())
+}
+
+/// Returns a weighted index from the `prob` and `alias` columns.
+#[inline]
+fn sample<P: Prng>(prng: &mut P, prob: &[f64], alias: &[usize]) -> usize {
+    let i = uniform_usize(prng, prob.len());
+    if prng.next_f64() < prob[i] {
+        i
+    } else {
+        alias[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::assert_frequencies, xorshift::XorShift64};
+
+    const WEIGHTS: [f64; 8] = [1.0, 0.0, 2.5, 3.0, 0.0, 10.0, 0.5, 3.0];
+
+    /// Returns the counts of 100000 samples of `dist`.
+    fn sample_counts<D: Distribution<usize>>(dist: &D, seed: u64) -> [usize; 8] {
+        let mut rng = XorShift64::new(seed).unwrap();
+        let mut counts = [0; 8];
+        for _ in 0..100_000 {
+            counts[dist.sample(&mut rng)] += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn frequencies() {
+        let table = AliasTable::<8>::new(&WEIGHTS).unwrap();
+        let counts = sample_counts(&table, 0xDEFA_0017);
+        assert_eq![(counts[1], counts[4]), (0, 0)];
+        assert_frequencies(&counts, &WEIGHTS, "AliasTable");
+
+        let table = AliasTable::<16>::new(&[0_u8, 0, 7]).unwrap();
+        assert_eq![sample_counts(&table, 42)[2], 100_000];
+    }
+
+    #[test]
+    fn single_entry() {
+        let table = AliasTable::<1>::new(&[0.25]).unwrap();
+        assert_eq![table.len(), 1];
+        assert_eq![sample_counts(&table, 42)[0], 100_000];
+    }
+
+    #[test]
+    fn invalid_weights() {
+        assert![AliasTable::<4>::new::<f64>(&[]).is_none()];
+        assert![AliasTable::<4>::new(&[1.0, -1.0]).is_none()];
+        assert![AliasTable::<4>::new(&[1.0, f64::NAN]).is_none()];
+        assert![AliasTable::<4>::new(&[1.0, f64::INFINITY]).is_none()];
+        assert![AliasTable::<4>::new(&[0.0, 0.0]).is_none()];
+        assert![AliasTable::<4>::new(&[1, 2, 3, 4, 5]).is_none()];
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn frequencies_vec() {
+        let table = AliasTableVec::new(&WEIGHTS).unwrap();
+        let counts = sample_counts(&table, 0xDEFA_0017);
+        assert_eq![(counts[1], counts[4]), (0, 0)];
+        assert_frequencies(&counts, &WEIGHTS, "AliasTableVec");
+
+        let table = AliasTableVec::new(&[0.25]).unwrap();
+        assert_eq![table.len(), 1];
+        assert_eq![sample_counts(&table, 42)[0], 100_000];
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn invalid_weights_vec() {
+        assert![AliasTableVec::new::<f64>(&[]).is_none()];
+        assert![AliasTableVec::new(&[1.0, -1.0]).is_none()];
+        assert![AliasTableVec::new(&[1.0, f64::NAN]).is_none()];
+        assert![AliasTableVec::new(&[1.0, f64::INFINITY]).is_none()];
+        assert![AliasTableVec::new(&[0.0, 0.0]).is_none()];
+    }
+}
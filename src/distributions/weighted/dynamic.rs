@@ -0,0 +1,467 @@
+// alazar::distributions::weighted::dynamic
+//
+//! Weighted indices with dynamic weights.
+//
+
+use crate::{distributions::Distribution, prng::uniform_u64, Prng};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A sampler of weighted indices with dynamic integer weights,
+/// with capacity for `N` weights.
+///
+/// The weights are stored in a Fenwick tree (binary indexed tree), so that
+/// setting, pushing and removing a weight, and sampling an index,
+/// are all *O(log n)*. It doesn't allocate.
+///
+/// Each sample takes a single unbiased bounded `u64` in `[0, total)`,
+/// so the probability of each index is exactly its weight over the total.
+///
+/// See also [`DynamicWeightedIndexVec`], for a sampler allocated on the heap.
+///
+/// # Examples
+/// ```
+/// use alazar::{distributions::{Distribution, DynamicWeightedIndex}, xorshift::XorShift64};
+///
+/// let mut rng = XorShift64::default();
+/// let mut tasks = DynamicWeightedIndex::<16>::new();
+/// tasks.push(10).unwrap();
+/// tasks.push(5).unwrap();
+/// tasks.set(0, 0).unwrap();
+/// assert_eq![tasks.sample(&mut rng), 1];
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DynamicWeightedIndex<const N: usize> {
+    len: usize,
+    total: u64,
+    tree: [u64; N],
+}
+
+impl<const N: usize> Default for DynamicWeightedIndex<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> DynamicWeightedIndex<N> {
+    /// Returns a new sampler without weights.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            total: 0,
+            tree: [0; N],
+        }
+    }
+
+    /// Returns a new sampler with the given `weights`.
+    ///
+    /// Returns `None` if there are more than `N` weights,
+    /// or if their sum overflows a `u64`.
+    #[must_use]
+    pub fn from_weights(weights: &[u64]) -> Option<Self> {
+        let mut new = Self::new();
+        for &weight in weights {
+            new.push(weight)?;
+        }
+        Some(new)
+    }
+
+    /// Returns the number of weights.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no weights.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of weights.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the sum of the weights.
+    #[inline]
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the weight at `index`, or `None` if it's out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn weight(&self, index: usize) -> Option<u64> {
+        (index < self.len).then(|| weight(&self.tree[..self.len], index))
+    }
+
+    /// Appends a new `weight`, and returns its index.
+    ///
+    /// Returns `None` if the sampler is full, or if the total overflows.
+    pub fn push(&mut self, weight: u64) -> Option<usize> {
+        if self.len == N {
+            return None;
+        }
+        self.total = self.total.checked_add(weight)?;
+        self.len += 1;
+        push(&mut self.tree[..self.len], weight);
+        Some(self.len - 1)
+    }
+
+    /// Removes the last weight and returns it, or `None` if there are no weights.
+    pub fn pop(&mut self) -> Option<u64> {
+        let weight = self.weight(self.len.checked_sub(1)?)?;
+        self.len -= 1;
+        self.total -= weight;
+        Some(weight)
+    }
+
+    /// Sets the weight at `index`, and returns the previous one.
+    ///
+    /// Returns `None` if `index` is out of bounds, or if the total overflows.
+    pub fn set(&mut self, index: usize, weight: u64) -> Option<u64> {
+        let old = self.weight(index)?;
+        self.total = (self.total - old).checked_add(weight)?;
+        set(&mut self.tree[..self.len], index, old, weight);
+        Some(old)
+    }
+
+    /// Sets the weight at `index` to zero, and returns the previous one.
+    ///
+    /// The index remains valid, but it's never sampled again unless its weight
+    /// is set. Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> Option<u64> {
+        self.set(index, 0)
+    }
+
+    /// Returns a weighted index, or `None` if the total is zero.
+    #[inline]
+    #[must_use]
+    pub fn try_sample<P: Prng>(&self, prng: &mut P) -> Option<usize> {
+        (self.total > 0).then(|| sample(prng, &self.tree[..self.len], self.total))
+    }
+}
+
+impl<const N: usize> Distribution<usize> for DynamicWeightedIndex<N> {
+    /// Returns a weighted index.
+    ///
+    /// # Panics
+    /// Panics if the total is zero.
+    #[inline]
+    fn sample<P: Prng>(&self, prng: &mut P) -> usize {
+        self.try_sample(prng)
+            .expect("the total weight must be positive")
+    }
+}
+
+/// A sampler of weighted indices with dynamic integer weights,
+/// allocated on the heap.
+///
+/// This is the heap allocated version of [`DynamicWeightedIndex`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DynamicWeightedIndexVec {
+    total: u64,
+    tree: Vec<u64>,
+}
+
+#[cfg(feature = "alloc")]
+impl DynamicWeightedIndexVec {
+    /// Returns a new sampler without weights.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            total: 0,
+            tree: Vec::new(),
+        }
+    }
+
+    /// Returns a new sampler with the given `weights`.
+    ///
+    /// Returns `None` if their sum overflows a `u64`.
+    #[must_use]
+    pub fn from_weights(weights: &[u64]) -> Option<Self> {
+        let mut new = Self {
+            total: 0,
+            tree: Vec::with_capacity(weights.len()),
+        };
+        for &weight in weights {
+            new.push(weight)?;
+        }
+        Some(new)
+    }
+
+    /// Returns the number of weights.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    /// Returns `true` if there are no weights.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    /// Returns the sum of the weights.
+    #[inline]
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the weight at `index`, or `None` if it's out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn weight(&self, index: usize) -> Option<u64> {
+        (index < self.tree.len()).then(|| weight(&self.tree, index))
+    }
+
+    /// Appends a new `weight`, and returns its index.
+    ///
+    /// Returns `None` if the total overflows.
+    pub fn push(&mut self, weight: u64) -> Option<usize> {
+        self.total = self.total.checked_add(weight)?;
+        self.tree.push(0);
+        push(&mut self.tree, weight);
+        Some(self.tree.len() - 1)
+    }
+
+    /// Removes the last weight and returns it, or `None` if there are no weights.
+    pub fn pop(&mut self) -> Option<u64> {
+        let weight = self.weight(self.tree.len().checked_sub(1)?)?;
+        self.tree.pop();
+        self.total -= weight;
+        Some(weight)
+    }
+
+    /// Sets the weight at `index`, and returns the previous one.
+    ///
+    /// Returns `None` if `index` is out of bounds, or if the total overflows.
+    pub fn set(&mut self, index: usize, weight: u64) -> Option<u64> {
+        let old = self.weight(index)?;
+        self.total = (self.total - old).checked_add(weight)?;
+        set(&mut self.tree, index, old, weight);
+        Some(old)
+    }
+
+    /// Sets the weight at `index` to zero, and returns the previous one.
+    ///
+    /// The index remains valid, but it's never sampled again unless its weight
+    /// is set. Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> Option<u64> {
+        self.set(index, 0)
+    }
+
+    /// Returns a weighted index, or `None` if the total is zero.
+    #[inline]
+    #[must_use]
+    pub fn try_sample<P: Prng>(&self, prng: &mut P) -> Option<usize> {
+        (self.total > 0).then(|| sample(prng, &self.tree, self.total))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Distribution<usize> for DynamicWeightedIndexVec {
+    /// Returns a weighted index.
+    ///
+    /// # Panics
+    /// Panics if the total is zero.
+    #[inline]
+    fn sample<P: Prng>(&self, prng: &mut P) -> usize {
+        self.try_sample(prng)
+            .expect("the total weight must be positive")
+    }
+}
+
+/* Fenwick tree operations, where tree[i] is the sum of the weights of the
+ * indices in (i + 1 - lsb(i + 1), i], and lsb(x) is the lowest set bit of x */
+
+/// Returns the lowest set bit of `x`.
+#[inline(always)]
+const fn lsb(x: usize) -> usize {
+    x & x.wrapping_neg()
+}
+
+/// Returns the sum of the weights of the indices in `[0, end)`.
+#[inline]
+fn prefix_sum(tree: &[u64], mut end: usize) -> u64 {
+    let mut sum = 0;
+    while end > 0 {
+        sum += tree[end - 1];
+        end -= lsb(end);
+    }
+    sum
+}
+
+/// Returns the weight at `index`.
+#[inline]
+fn weight(tree: &[u64], index: usize) -> u64 {
+    // the node minus its children
+    let mut weight = tree[index];
+    let (end, mut child) = (index + 1 - lsb(index + 1), index);
+    while child > end {
+        weight -= tree[child - 1];
+        child -= lsb(child);
+    }
+    weight
+}
+
+/// Initializes the last node of the `tree` with its `weight`.
+#[inline]
+fn push(tree: &mut [u64], weight: u64) {
+    let index = tree.len() - 1;
+    let start = index + 1 - lsb(index + 1);
+    tree[index] = weight + prefix_sum(tree, index) - prefix_sum(tree, start);
+}
+
+/// Replaces the `old` weight at `index` with the `new` one.
+#[inline]
+fn set(tree: &mut [u64], index: usize, old: u64, new: u64) {
+    let mut i = index + 1;
+    while i <= tree.len() {
+        tree[i - 1] = tree[i - 1] - old + new;
+        i += lsb(i);
+    }
+}
+
+/// Returns the index whose cumulative weight range contains
+/// a uniform value in `[0, total)`.
+#[inline]
+fn sample<P: Prng>(prng: &mut P, tree: &[u64], total: u64) -> usize {
+    let mut remaining = uniform_u64(prng, total);
+    let mut index = 0;
+    let mut step = if tree.is_empty() {
+        0
+    } else {
+        1 << tree.len().ilog2()
+    };
+    while step > 0 {
+        let next = index + step;
+        if next <= tree.len() && tree[next - 1] <= remaining {
+            index = next;
+            remaining -= tree[next - 1];
+        }
+        step >>= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::assert_frequencies, xorshift::XorShift64};
+
+    /// Checks the weights and the total against the expected `weights`.
+    fn check_weights<const N: usize>(sampler: &DynamicWeightedIndex<N>, weights: &[u64]) {
+        assert_eq![sampler.len(), weights.len()];
+        for (i, &w) in weights.iter().enumerate() {
+            assert_eq![sampler.weight(i), Some(w)];
+        }
+        let sum = weights.iter().sum();
+        assert_eq![sampler.total(), sum];
+        assert_eq![prefix_sum(&sampler.tree[..sampler.len], sampler.len), sum];
+    }
+
+    #[test]
+    fn total() {
+        const N: usize = 37;
+        let mut rng = XorShift64::new(0xDEFA_0017).unwrap();
+        let mut sampler = DynamicWeightedIndex::<N>::new();
+        let (mut weights, mut len) = ([0; N], 0);
+        for _ in 0..10_000 {
+            let index = rng.gen_range(0..len.max(1));
+            let weight = rng.gen_range(0..1000);
+            match rng.gen_range(0..4_u8) {
+                0 if len < N => {
+                    assert_eq![sampler.push(weight), Some(len)];
+                    weights[len] = weight;
+                    len += 1;
+                }
+                1 if len > 0 => {
+                    len -= 1;
+                    assert_eq![sampler.pop(), Some(weights[len])];
+                }
+                2 if len > 0 => {
+                    assert_eq![sampler.set(index, weight), Some(weights[index])];
+                    weights[index] = weight;
+                }
+                3 if len > 0 => {
+                    assert_eq![sampler.remove(index), Some(weights[index])];
+                    weights[index] = 0;
+                }
+                _ => continue,
+            }
+            check_weights(&sampler, &weights[..len]);
+        }
+        assert_eq![sampler.set(len, 1), None];
+    }
+
+    #[test]
+    fn zero_weights() {
+        let mut rng = XorShift64::new(0xDEFA_0017).unwrap();
+        let mut sampler =
+            DynamicWeightedIndex::<8>::from_weights(&[3, 0, 5, 0, 0, 1, 0, 2]).unwrap();
+        sampler.remove(2).unwrap();
+        for _ in 0..100_000 {
+            assert![matches![sampler.sample(&mut rng), 0 | 5 | 7]];
+        }
+        for i in 0..8 {
+            sampler.remove(i).unwrap();
+        }
+        assert_eq![sampler.try_sample(&mut rng), None];
+    }
+
+    #[test]
+    fn frequencies() {
+        const SAMPLES: usize = 200_000;
+        let weights = [1, 2, 3, 4, 0, 10, 20];
+        let mut sampler = DynamicWeightedIndex::<8>::from_weights(&weights).unwrap();
+        sampler.push(60).unwrap();
+        let mut rng = XorShift64::new(0x1234_5678_9ABC).unwrap();
+        let mut counts = [0; 8];
+        for _ in 0..SAMPLES {
+            counts[sampler.sample(&mut rng)] += 1;
+        }
+        let weights: [f64; 8] = core::array::from_fn(|i| sampler.weight(i).unwrap() as f64);
+        assert_frequencies(&counts, &weights, "DynamicWeightedIndex");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn total_vec() {
+        let mut rng = XorShift64::new(42).unwrap();
+        let mut sampler = DynamicWeightedIndexVec::from_weights(&[7, 0, 3]).unwrap();
+        let mut weights = alloc::vec![7, 0, 3];
+        for _ in 0..1000 {
+            let weight = rng.gen_range(0..1000);
+            if rng.gen_range(0..3_u8) == 0 {
+                assert_eq![sampler.push(weight), Some(weights.len())];
+                weights.push(weight);
+            } else {
+                let index = rng.gen_range(0..weights.len());
+                assert_eq![sampler.set(index, weight), Some(weights[index])];
+                weights[index] = weight;
+            }
+            assert_eq![sampler.total(), weights.iter().sum::<u64>()];
+            assert_eq![prefix_sum(&sampler.tree, sampler.len()), sampler.total()];
+            for (i, &w) in weights.iter().enumerate() {
+                assert_eq![sampler.weight(i), Some(w)];
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// alazar::distributions::weighted
+//
+//! Weighted sampling of indices.
+//
+
+mod alias;
+mod dynamic;
+
+pub use alias::AliasTable;
+pub use dynamic::DynamicWeightedIndex;
+#[cfg(feature = "alloc")]
+pub use {alias::AliasTableVec, dynamic::DynamicWeightedIndexVec};
+
+/// A non-negative weight of a weighted sampler.
+///
+/// It's implemented for the primitive integers and floats,
+/// which are converted to `f64` for building the samplers.
+pub trait Weight: Copy {
+    /// Returns the weight as an `f64`.
+    #[must_use]
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_weight {
+    ($($t:ty),+) => { $(
+        impl Weight for $t {
+            #[inline(always)]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )+ };
+}
+impl_weight![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64];
+
+/// Returns the sum of the `weights`, or `None` if any weight is negative or
+/// not finite, or if the sum is not positive and finite.
+fn checked_sum<W: Weight>(weights: &[W]) -> Option<f64> {
+    let mut sum = 0.0;
+    for w in weights {
+        let w = w.to_f64();
+        if !(w.is_finite() && w >= 0.0) {
+            return None;
+        }
+        sum += w;
+    }
+    if sum.is_finite() && sum > 0.0 {
+        Some(sum)
+    } else {
+        None
+    }
+}
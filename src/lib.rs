@@ -20,11 +20,28 @@ compile_error!("You can't enable the `safe` and `unsafe` features at the same ti
 // deprecated
 devela::deprecate_feature![old: "all", new: "full", since: "0.0.2"];
 
+mod math;
+mod prng;
+#[cfg(test)]
+mod test_utils;
+pub use prng::{Prng, PrngWord, SampleRange};
+
+pub mod chacha;
+pub mod chaotic;
+pub mod counter;
+pub mod distributions;
 pub mod misc;
+pub mod mt;
+pub mod pcg;
+pub mod seq;
 pub mod xorshift;
+pub mod xoshiro;
 
 /// All items are reexported here.
 pub mod all {
     #[doc(inline)]
-    pub use super::{misc::*, xorshift::*};
+    pub use super::{
+        chacha::*, chaotic::*, counter::*, distributions::*, misc::*, mt::*, pcg::*, prng::*,
+        seq::*, xorshift::*, xoshiro::*,
+    };
 }
@@ -0,0 +1,193 @@
+// alazar::math
+//
+//! Floating-point functions for `no_std`, usable in compile-time evaluation.
+//
+// They only use basic IEEE-754 arithmetic and bit manipulation, so their
+// results are identical on every platform, unlike the platform's libm.
+// They are accurate to about 1 ulp, which is enough for sampling.
+
+// ln(2) split in a high part with trailing zero bits, and the remainder
+const LN2_HI: f64 = f64::from_bits(0x3FE62E42_FEE00000);
+const LN2_LO: f64 = f64::from_bits(0x3DEA39EF_35793C76);
+const LOG2_E: f64 = core::f64::consts::LOG2_E;
+const SQRT_2: f64 = core::f64::consts::SQRT_2;
+const MANTISSA_MASK: u64 = (1 << 52) - 1;
+const TWO_POW_52: f64 = 4_503_599_627_370_496.0;
+const TWO_POW_54: f64 = 18_014_398_509_481_984.0;
+
+/// Returns `2^k`, for `k` in the normal range `[-1022, 1023]`.
+#[inline]
+const fn pow2(k: i64) -> f64 {
+    f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+/// Returns the absolute value of `x`.
+#[inline]
+#[must_use]
+pub(crate) const fn abs(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() & !(1 << 63))
+}
+
+/// Returns the square root of `x`.
+#[must_use]
+pub(crate) const fn sqrt(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    } else if x == 0.0 || x == f64::INFINITY {
+        return x;
+    } else if x < f64::MIN_POSITIVE {
+        return sqrt(x * TWO_POW_54) / (1 << 27) as f64;
+    }
+    // halve the exponent for the initial guess, then refine it with Newton
+    let mut y = f64::from_bits((x.to_bits() >> 1) + (1023 << 51));
+    let mut i = 0;
+    while i < 6 {
+        y = 0.5 * (y + x / y);
+        i += 1;
+    }
+    y
+}
+
+/// Returns `e^x`.
+#[must_use]
+pub(crate) const fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    } else if x > 709.782_712_893_384 {
+        return f64::INFINITY;
+    } else if x < -745.133_219_101_941_1 {
+        return 0.0;
+    }
+    // x = k·ln(2) + r, with |r| <= ln(2)/2
+    let k = (x * LOG2_E + if x < 0.0 { -0.5 } else { 0.5 }) as i64;
+    let r = (x - k as f64 * LN2_HI) - k as f64 * LN2_LO;
+
+    // Taylor series of e^r, in Horner form
+    let mut p = 1.0;
+    let mut n = 13;
+    while n > 0 {
+        p = 1.0 + p * r / n as f64;
+        n -= 1;
+    }
+
+    // p·2^k, in two steps when 2^k is not a normal number
+    if k > 1023 {
+        p * pow2(k - 1) * 2.0
+    } else if k < -1022 {
+        p * pow2(k + 54) / TWO_POW_54
+    } else {
+        p * pow2(k)
+    }
+}
+
+/// Returns the natural logarithm of `x`.
+#[must_use]
+pub(crate) const fn ln(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    } else if x == 0.0 {
+        return f64::NEG_INFINITY;
+    } else if x == f64::INFINITY {
+        return x;
+    }
+    // x = m·2^e, with m in [√2/2, √2)
+    let (mut bits, mut e) = (x.to_bits(), -1023);
+    if x < f64::MIN_POSITIVE {
+        bits = (x * TWO_POW_54).to_bits();
+        e -= 54;
+    }
+    e += (bits >> 52) as i64;
+    let mut m = f64::from_bits((bits & MANTISSA_MASK) | (1023 << 52));
+    if m > SQRT_2 {
+        m *= 0.5;
+        e += 1;
+    }
+
+    // ln(m) = 2·atanh(s), with s = (m - 1) / (m + 1), |s| < 0.172
+    let s = (m - 1.0) / (m + 1.0);
+    let s2 = s * s;
+    let mut series = 0.0;
+    let mut n = 23;
+    while n > 1 {
+        series = 2.0 / n as f64 + s2 * series;
+        n -= 2;
+    }
+    series = s * (2.0 + s2 * series);
+
+    e as f64 * LN2_HI + (series + e as f64 * LN2_LO)
+}
+
+/// Returns `ln(1 + x)`, accurately also for `x` near zero.
+#[must_use]
+pub(crate) const fn ln_1p(x: f64) -> f64 {
+    if abs(x) < 1e-4 {
+        // Taylor series, the next term is below 2^-53 relative to the sum
+        x * (1.0 - x * (0.5 - x * (1.0 / 3.0 - x * 0.25)))
+    } else {
+        ln(1.0 + x)
+    }
+}
+
+/// Returns the largest integer less than or equal to `x`.
+#[must_use]
+pub(crate) const fn floor(x: f64) -> f64 {
+    // also returns NaN, infinities and values without a fractional part
+    if x.is_nan() || abs(x) >= TWO_POW_52 {
+        return x;
+    }
+    let t = x as i64 as f64;
+    if t > x {
+        t - 1.0
+    } else {
+        t
+    }
+}
+
+/// Returns the natural logarithm of `k!`, for a non-negative integer `k`.
+#[must_use]
+pub(crate) const fn ln_factorial(k: f64) -> f64 {
+    if k < LN_FACTORIALS.len() as f64 {
+        LN_FACTORIALS[k as usize]
+    } else {
+        ln_gamma_stirling(k + 1.0)
+    }
+}
+
+/// The natural logarithms of the first factorials.
+const LN_FACTORIALS: [f64; 16] = {
+    let mut table = [0.0; 16];
+    let mut factorial = 1.0; // exact up to 15!
+    let mut k = 1;
+    while k < table.len() {
+        factorial *= k as f64;
+        table[k] = ln(factorial);
+        k += 1;
+    }
+    table
+};
+
+/// Returns the natural logarithm of `Γ(x)`, for `x >= 16`.
+///
+/// Uses the Stirling series, with an error below 1e-17 in that domain.
+const fn ln_gamma_stirling(x: f64) -> f64 {
+    const HALF_LN_2PI: f64 = 0.918_938_533_204_672_8;
+    // the coefficients B₂ₙ / (2n·(2n-1))
+    const COEFFS: [f64; 7] = [
+        1.0 / 12.0,
+        -1.0 / 360.0,
+        1.0 / 1260.0,
+        -1.0 / 1680.0,
+        1.0 / 1188.0,
+        -691.0 / 360_360.0,
+        1.0 / 156.0,
+    ];
+    let x_inv = 1.0 / x;
+    let x_inv2 = x_inv * x_inv;
+    let mut series = 0.0;
+    let mut i = COEFFS.len();
+    while i > 0 {
+        i -= 1;
+        series = COEFFS[i] + x_inv2 * series;
+    }
+    (x - 0.5) * ln(x) - x + HALF_LN_2PI + series * x_inv
+}
@@ -0,0 +1,204 @@
+// alazar::misc::lehmer64
+//
+//! The `Lehmer64` pseudo-random number generator.
+//
+
+use crate::{misc::SplitMix64, prng::impl_prng};
+use devela::convert::{u128_from_u16_le, u128_from_u32_le, u128_from_u64_le, u128_from_u8_le};
+
+/// The `Lehmer64` pseudo-random number generator.
+///
+/// It has a 128-bit state and generates 64-bit numbers.
+///
+/// It's a multiplicative congruential generator (MCG) modulo `2^128`, also
+/// known as *MCG128*: each step multiplies the state by a 64-bit constant,
+/// and the result is its high half. It's one of the fastest generators that
+/// passes the usual statistical test suites.
+///
+/// The state must be odd, and then its period is `2^126`.
+///
+/// This version follows the one benchmarked by [Daniel Lemire][blog],
+/// based on D. H. Lehmer's original construction.
+///
+/// [blog]: https://lemire.me/blog/2019/03/19/the-fastest-conventional-random-number-generator-that-can-pass-big-crush/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lehmer64(u128);
+
+impl Default for Lehmer64 {
+    fn default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Lehmer64 {
+    const DEFAULT_SEED: u128 = 0xDEFA0017_DEFA0017_DEFA0017_DEFA0017;
+
+    const MULTIPLIER: u128 = 0xDA94_2042_E4DD_58B5;
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+}
+
+impl Lehmer64 {
+    /// Returns a seeded `Lehmer64` generator from the given 128-bit seed.
+    ///
+    /// Returns `None` if the seed is even.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u128) -> Option<Self> {
+        if seed & 1 == 0 {
+            Self::cold_path_result()
+        } else {
+            Some(Self(seed))
+        }
+    }
+
+    /// Returns a seeded `Lehmer64` generator from the given 128-bit seed,
+    /// unchecked.
+    ///
+    /// The seed must be odd, otherwise the period will be shorter,
+    /// and every result will be `0` if the seed is `0`.
+    ///
+    /// # Panics
+    /// Panics in debug if the seed is even.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(seed: u128) -> Self {
+        debug_assert![seed & 1 == 1, "Seed must be odd"];
+        Self(seed)
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        (self.0 >> 64) as u64
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_mul(Self::MULTIPLIER);
+        (self.0 >> 64) as u64
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        Self(self.0.wrapping_mul(Self::MULTIPLIER))
+    }
+}
+
+/// # Extra constructors
+impl Lehmer64 {
+    /// Returns a seeded `Lehmer64` generator from the given 128-bit seed.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new1_u128(seed: u128) -> Option<Self> {
+        Self::new(seed)
+    }
+
+    /// Returns a seeded `Lehmer64` generator from the given 2 × 64-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new2_u64(seeds: [u64; 2]) -> Option<Self> {
+        Self::new(u128_from_u64_le(seeds))
+    }
+
+    /// Returns a seeded `Lehmer64` generator from the given 4 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u32(seeds: [u32; 4]) -> Option<Self> {
+        Self::new(u128_from_u32_le(seeds))
+    }
+
+    /// Returns a seeded `Lehmer64` generator from the given 8 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u16(seeds: [u16; 8]) -> Option<Self> {
+        Self::new(u128_from_u16_le(seeds))
+    }
+
+    /// Returns a seeded `Lehmer64` generator from the given 16 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new16_u8(seeds: [u8; 16]) -> Option<Self> {
+        Self::new(u128_from_u8_le(seeds))
+    }
+
+    /// Returns a seeded `Lehmer64` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states. The lowest bit of the
+    /// expanded state is set, to make it odd.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(u128_from_u64_le(SplitMix64::seed_u64s(seed)) | 1)
+    }
+}
+
+impl_prng![Lehmer64: u64, current_u64, next_u64];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::Lehmer64;
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![Lehmer64];
+
+    impl SeedableRng for Lehmer64 {
+        type Seed = [u8; 16];
+
+        /// The lowest bit of the seed is set, since the state must be odd.
+        fn from_seed(seed: Self::Seed) -> Self {
+            Self::new_unchecked(u128::from_le_bytes(seed) | 1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // from `lehmer64` in Lemire's benchmark at https://github.com/lemire/testingRNG,
+        // seeded with `lehmer64_seed(42)`, which joins the `splitmix64_stateless`
+        // outputs of 42 and 43 into the high and low halves of the state
+        let state = 0xBDD7_3226_2FEB_6E95_BA69_EC90_EB4F_EF88;
+        let high = SplitMix64::new(42).next_u64() as u128;
+        let low = SplitMix64::new(43).next_u64() as u128;
+        assert_eq![state, (high << 64) | low];
+        // that state is even, so it can't be given to the constructors
+        let mut rng = Lehmer64(state);
+        for expected in [
+            13248416701911671562,
+            6819505686226414128,
+            17312391784925465866,
+            3084725117068970398,
+            936363761953875614,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn even_seed() {
+        assert![Lehmer64::new(0).is_none()];
+        assert![Lehmer64::new(42 << 64).is_none()];
+        assert![Lehmer64::new(2).is_none()];
+    }
+}
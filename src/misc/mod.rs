@@ -3,8 +3,16 @@
 //! Miscellaneous pseudo-random number generators.
 //
 
+mod lehmer64;
+mod msws;
 mod mult13p1;
+mod splitmix64;
+mod wyrand;
 mod xabc;
 
+pub use lehmer64::Lehmer64;
+pub use msws::Msws;
 pub use mult13p1::Mult13P1;
+pub use splitmix64::SplitMix64;
+pub use wyrand::WyRand;
 pub use xabc::Xabc;
@@ -0,0 +1,209 @@
+// alazar::misc::msws
+//
+//! The `Msws` pseudo-random number generator.
+//
+
+use crate::{misc::SplitMix64, prng::impl_prng};
+use devela::convert::{u64_from_u16_le, u64_from_u32_le, u64_from_u8_le};
+
+/// The `MSWS` pseudo-random number generator (*Middle Square Weyl Sequence*).
+///
+/// It has a 128-bit state plus a 64-bit Weyl constant, and generates
+/// 32-bit numbers.
+///
+/// It revives John von Neumann's middle-square method from 1946: each step
+/// squares the state and keeps its middle bits. On its own that method soon
+/// falls into short cycles, so a Weyl sequence, the running sum of the
+/// constant, is added after each squaring. Its period is `2^64`.
+///
+/// The constant acts as the seed, and must be odd. It should also have an
+/// irregular bit pattern, with about as many ones as zeros, like the ones
+/// made by [`from_u64_seed`][Self::from_u64_seed].
+///
+/// It was published by Bernard Widynski in
+/// [*Middle Square Weyl Sequence RNG*][paper] (2017).
+///
+/// [paper]: https://arxiv.org/abs/1704.00358
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Msws {
+    /// The squared state.
+    x: u64,
+    /// The Weyl sequence.
+    w: u64,
+    /// The Weyl constant.
+    s: u64,
+}
+
+impl Default for Msws {
+    fn default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Msws {
+    const DEFAULT_SEED: u64 = 0xDEFA0017_DEFA0017;
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+}
+
+impl Msws {
+    /// Returns a seeded `Msws` generator from the given 64-bit Weyl constant.
+    ///
+    /// Returns `None` if the constant is even.
+    #[inline]
+    #[must_use]
+    pub const fn new(weyl: u64) -> Option<Self> {
+        if weyl & 1 == 0 {
+            Self::cold_path_result()
+        } else {
+            Some(Self {
+                x: 0,
+                w: 0,
+                s: weyl,
+            })
+        }
+    }
+
+    /// Returns a seeded `Msws` generator from the given 64-bit Weyl constant,
+    /// unchecked.
+    ///
+    /// The constant must be odd, otherwise the period will be shorter.
+    ///
+    /// # Panics
+    /// Panics in debug if the constant is even.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(weyl: u64) -> Self {
+        debug_assert![weyl & 1 == 1, "Weyl constant must be odd"];
+        Self {
+            x: 0,
+            w: 0,
+            s: weyl,
+        }
+    }
+
+    /// Returns the Weyl constant.
+    #[inline]
+    #[must_use]
+    pub const fn weyl(&self) -> u64 {
+        self.s
+    }
+
+    /// Returns the current random `u32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u32(&self) -> u32 {
+        self.x as u32
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        *self = self.next_new();
+        self.x as u32
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let w = self.w.wrapping_add(self.s);
+        let x = self.x.wrapping_mul(self.x).wrapping_add(w).rotate_left(32);
+        Self { x, w, s: self.s }
+    }
+}
+
+/// # Extra constructors
+impl Msws {
+    /// Returns a seeded `Msws` generator from the given 64-bit Weyl constant.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new1_u64(weyl: u64) -> Option<Self> {
+        Self::new(weyl)
+    }
+
+    /// Returns a seeded `Msws` generator from the given 2 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order into the Weyl constant.
+    #[inline]
+    pub const fn new2_u32(seeds: [u32; 2]) -> Option<Self> {
+        Self::new(u64_from_u32_le(seeds))
+    }
+
+    /// Returns a seeded `Msws` generator from the given 4 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order into the Weyl constant.
+    #[inline]
+    pub const fn new4_u16(seeds: [u16; 4]) -> Option<Self> {
+        Self::new(u64_from_u16_le(seeds))
+    }
+
+    /// Returns a seeded `Msws` generator from the given 8 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order into the Weyl constant.
+    #[inline]
+    pub const fn new8_u8(seeds: [u8; 8]) -> Option<Self> {
+        Self::new(u64_from_u8_le(seeds))
+    }
+
+    /// Returns a seeded `Msws` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`] into the Weyl constant.
+    ///
+    /// The constant is odd, its 16 hexadecimal digits are non-zero, and the
+    /// 8 digits of each 32-bit half are all different.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_weyl_key(seed))
+    }
+}
+
+impl_prng![Msws: u32, current_u32, next_u32];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::Msws;
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![Msws];
+
+    impl SeedableRng for Msws {
+        type Seed = [u8; 8];
+
+        /// Uses the seed as the Weyl constant, with its lowest bit set,
+        /// since it must be odd.
+        fn from_seed(seed: Self::Seed) -> Self {
+            Self::new_unchecked(u64::from_le_bytes(seed) | 1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // from the C implementation in the paper, with its example constant
+        let mut rng = Msws::new(0xB5AD_4ECE_DA1C_E2A9).unwrap();
+        for expected in [
+            0xB5AD_4ECE,
+            0xDF4E_E85C,
+            0x1889_155F,
+            0xC6DC_BCCF,
+            0x1106_E0C5,
+            0x4730_66AE,
+        ] {
+            assert_eq![rng.next_u32(), expected];
+        }
+        assert![Msws::new(0xB5AD_4ECE_DA1C_E2A8).is_none()];
+    }
+}
@@ -1,8 +1,10 @@
-// alazar::xorshift::mult13p1
+// alazar::misc::mult13p1
 //
-//!
+//! The `Mult13P1` pseudo-random number generator.
 //
 
+use crate::prng::impl_prng;
+
 /// A weak 8-bit pseudo-random number generator from 1977.
 ///
 /// It basically multiplies the previous number by 13, and adds 1.
@@ -64,11 +66,14 @@ impl Mult13P1 {
     #[inline]
     #[must_use]
     pub const fn next_new(&self) -> Self {
-        let mut state = self.state;
-        let n_times_2_pow_2 = state << 2;
-        let n_times_2_pow_3 = state << 3;
+        let n = self.state;
+        let n_times_2_pow_2 = n << 2;
+        let n_times_2_pow_3 = n << 3;
         // 13*n = n + n*2^2 + n*2^3
-        state += n_times_2_pow_2 + n_times_2_pow_3 + 1;
+        let state = n
+            .wrapping_add(n_times_2_pow_2)
+            .wrapping_add(n_times_2_pow_3)
+            .wrapping_add(1);
         Self { state }
     }
 }
@@ -84,48 +89,16 @@ impl Mult13P1 {
     }
 }
 
+impl_prng![Mult13P1: u8, current_u8, next_u8];
+
 #[cfg(feature = "rand_core")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
 mod impl_rand {
     use super::Mult13P1;
-    use rand_core::{Error, RngCore, SeedableRng};
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
 
-    impl RngCore for Mult13P1 {
-        /// Returns the next 4 × random `u8` combined as a single `u32`.
-        fn next_u32(&mut self) -> u32 {
-            u32::from_le_bytes([
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-            ])
-        }
-
-        /// Returns the next 8 × random `u8` combined as a single `u64`.
-        fn next_u64(&mut self) -> u64 {
-            u64::from_le_bytes([
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-            ])
-        }
-
-        fn fill_bytes(&mut self, dest: &mut [u8]) {
-            for byte in dest {
-                *byte = self.next_u8();
-            }
-        }
-
-        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-            self.fill_bytes(dest);
-            Ok(())
-        }
-    }
+    impl_rand_core![Mult13P1];
 
     impl SeedableRng for Mult13P1 {
         type Seed = [u8; 1];
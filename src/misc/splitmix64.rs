@@ -0,0 +1,270 @@
+// alazar::misc::splitmix64
+//
+//! The `SplitMix64` pseudo-random number generator.
+//
+
+use crate::prng::impl_prng;
+use devela::convert::{u64_from_u16_le, u64_from_u32_le, u64_from_u8_le};
+
+/// The `SplitMix64` pseudo-random number generator.
+///
+/// It has a 64-bit state and generates 64-bit numbers.
+///
+/// It adds a constant to its state on each step and returns a bijective
+/// mix of it, so every state is valid, including `0`, and it has a period
+/// of `2^64`. It's fast and statistically sound, but its main use is to
+/// expand a single 64-bit seed into the bigger state of other generators,
+/// which is how the `from_u64_seed` constructors work.
+///
+/// It was published by Guy L. Steele, Doug Lea and Christine H. Flood in
+/// [*Fast splittable pseudorandom number generators*][paper] (2014).
+///
+/// [paper]: https://doi.org/10.1145/2714064.2660195
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SplitMix64(u64);
+
+impl Default for SplitMix64 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl SplitMix64 {
+    const DEFAULT_SEED: u64 = 0xDEFA0017_DEFA0017;
+
+    /// The golden ratio increment of the state.
+    const GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    /// The output function, a variant of the MurmurHash3 finalizer.
+    #[inline(always)]
+    const fn mix(mut z: u64) -> u64 {
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+// seed expansion
+impl SplitMix64 {
+    /// Returns `N` 64-bit words expanded from the given `seed`,
+    /// which are never all `0`.
+    pub(crate) const fn seed_u64s<const N: usize>(seed: u64) -> [u64; N] {
+        let mut sm = Self::new(seed);
+        loop {
+            let (mut words, mut any) = ([0; N], 0);
+            let mut i = 0;
+            while i < N {
+                sm = sm.next_new();
+                words[i] = sm.current_u64();
+                any |= words[i];
+                i += 1;
+            }
+            if any != 0 {
+                return words;
+            }
+        }
+    }
+
+    /// Returns `N` 32-bit words expanded from the given `seed`,
+    /// which are never all `0`.
+    ///
+    /// Each 64-bit output is split in little endian order.
+    pub(crate) const fn seed_u32s<const N: usize>(seed: u64) -> [u32; N] {
+        let mut sm = Self::new(seed);
+        loop {
+            let (mut words, mut any) = ([0; N], 0);
+            let mut i = 0;
+            while i < N {
+                if i % 2 == 0 {
+                    sm = sm.next_new();
+                }
+                words[i] = (sm.current_u64() >> (32 * (i % 2))) as u32;
+                any |= words[i];
+                i += 1;
+            }
+            if any != 0 {
+                return words;
+            }
+        }
+    }
+
+    /// Returns `N` 8-bit words expanded from the given `seed`,
+    /// which are never all `0`.
+    ///
+    /// Each 64-bit output is split in little endian order.
+    pub(crate) const fn seed_u8s<const N: usize>(seed: u64) -> [u8; N] {
+        let mut sm = Self::new(seed);
+        loop {
+            let (mut words, mut any) = ([0; N], 0);
+            let mut i = 0;
+            while i < N {
+                if i % 8 == 0 {
+                    sm = sm.next_new();
+                }
+                words[i] = (sm.current_u64() >> (8 * (i % 8))) as u8;
+                any |= words[i];
+                i += 1;
+            }
+            if any != 0 {
+                return words;
+            }
+        }
+    }
+
+    /// Returns an odd 64-bit key with an irregular bit pattern,
+    /// expanded from the given `seed`.
+    ///
+    /// Its 16 hexadecimal digits are non-zero, and the 8 digits of each
+    /// 32-bit half are all different.
+    pub(crate) const fn seed_weyl_key(seed: u64) -> u64 {
+        let mut sm = Self::new(seed);
+        let mut key = 0;
+        let mut half = 0;
+        while half < 2 {
+            let mut digits = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+            let mut i = 0;
+            while i < 8 {
+                sm = sm.next_new();
+                let r = sm.current_u64() as usize;
+                // the lowest digit is one of the odd ones, at the even indices
+                let j = if half == 0 && i == 0 {
+                    2 * (r % 8)
+                } else {
+                    i + r % (15 - i)
+                };
+                let digit = digits[j];
+                digits[j] = digits[i];
+                digits[i] = digit;
+                key |= (digit as u64) << (4 * (half * 8 + i));
+                i += 1;
+            }
+            half += 1;
+        }
+        key
+    }
+}
+
+impl SplitMix64 {
+    /// Returns a seeded `SplitMix64` generator from the given 64-bit seed.
+    ///
+    /// Any seed is valid, including `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        Self::mix(self.0)
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(Self::GAMMA);
+        Self::mix(self.0)
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        Self(self.0.wrapping_add(Self::GAMMA))
+    }
+}
+
+/// # Extra constructors
+impl SplitMix64 {
+    /// Returns a seeded `SplitMix64` generator from the given 64-bit seed.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new1_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+
+    /// Returns a seeded `SplitMix64` generator from the given 2 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new2_u32(seeds: [u32; 2]) -> Self {
+        Self::new(u64_from_u32_le(seeds))
+    }
+
+    /// Returns a seeded `SplitMix64` generator from the given 4 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u16(seeds: [u16; 4]) -> Self {
+        Self::new(u64_from_u16_le(seeds))
+    }
+
+    /// Returns a seeded `SplitMix64` generator from the given 8 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u8(seeds: [u8; 8]) -> Self {
+        Self::new(u64_from_u8_le(seeds))
+    }
+}
+
+impl_prng![SplitMix64: u64, current_u64, next_u64];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::SplitMix64;
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![SplitMix64];
+
+    impl SeedableRng for SplitMix64 {
+        type Seed = [u8; 8];
+
+        fn from_seed(seed: Self::Seed) -> Self {
+            Self::new(u64::from_le_bytes(seed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // from the reference implementation at https://prng.di.unimi.it/
+        let mut rng = SplitMix64::new(1477776061723855037);
+        for expected in [
+            1985237415132408290,
+            2979275885539914483,
+            13511426838097143398,
+            8488337342461049707,
+            15141737807933549159,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn weyl_key() {
+        for seed in 0..1000 {
+            let key = SplitMix64::seed_weyl_key(seed);
+            assert![key & 1 == 1];
+            for half in [key as u32, (key >> 32) as u32] {
+                let mut seen = 0u16;
+                for i in 0..8 {
+                    let digit = (half >> (4 * i)) & 0xF;
+                    assert![digit != 0 && seen & (1 << digit) == 0];
+                    seen |= 1 << digit;
+                }
+            }
+        }
+    }
+}
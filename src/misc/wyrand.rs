@@ -0,0 +1,169 @@
+// alazar::misc::wyrand
+//
+//! The `WyRand` pseudo-random number generator.
+//
+
+use crate::prng::impl_prng;
+use devela::convert::{u64_from_u16_le, u64_from_u32_le, u64_from_u8_le};
+
+/// The `WyRand` pseudo-random number generator.
+///
+/// It has a 64-bit state and generates 64-bit numbers.
+///
+/// It adds a constant to its state on each step, like [`SplitMix64`], and
+/// mixes it with *wymix*, a single 64 × 64 → 128-bit multiplication whose
+/// halves are folded together with a xor. This makes it one of the fastest
+/// 64-bit generators on platforms with a wide multiplier.
+///
+/// Every state is valid, including `0`, and it has a period of `2^64`.
+///
+/// It was designed by Wang Yi as the generator of the [wyhash] hash function.
+///
+/// [`SplitMix64`]: super::SplitMix64
+/// [wyhash]: https://github.com/wangyi-fudan/wyhash
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WyRand(u64);
+
+impl Default for WyRand {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl WyRand {
+    const DEFAULT_SEED: u64 = 0xDEFA0017_DEFA0017;
+
+    /// The increment of the state.
+    const P0: u64 = 0xA076_1D64_78BD_642F;
+
+    /// The value xored with the state before mixing.
+    const P1: u64 = 0xE703_7ED1_A0B4_28DB;
+
+    /// The output function, the wide multiplication of the state and a
+    /// scrambled copy of it, with the high and low halves folded together.
+    #[inline(always)]
+    const fn wymix(state: u64) -> u64 {
+        let t = (state as u128) * ((state ^ Self::P1) as u128);
+        ((t >> 64) ^ t) as u64
+    }
+}
+
+impl WyRand {
+    /// Returns a seeded `WyRand` generator from the given 64-bit seed.
+    ///
+    /// Any seed is valid, including `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Returns a seeded `WyRand` generator from the given 64-bit seed.
+    ///
+    /// This is an alias of [`new`][Self#method.new], since every seed is
+    /// valid, for parity with the generators that have invalid seeds.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(seed: u64) -> Self {
+        Self::new(seed)
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        Self::wymix(self.0)
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(Self::P0);
+        Self::wymix(self.0)
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        Self(self.0.wrapping_add(Self::P0))
+    }
+}
+
+/// # Extra constructors
+impl WyRand {
+    /// Returns a seeded `WyRand` generator from the given 64-bit seed.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new1_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+
+    /// Returns a seeded `WyRand` generator from the given 2 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new2_u32(seeds: [u32; 2]) -> Self {
+        Self::new(u64_from_u32_le(seeds))
+    }
+
+    /// Returns a seeded `WyRand` generator from the given 4 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u16(seeds: [u16; 4]) -> Self {
+        Self::new(u64_from_u16_le(seeds))
+    }
+
+    /// Returns a seeded `WyRand` generator from the given 8 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u8(seeds: [u8; 8]) -> Self {
+        Self::new(u64_from_u8_le(seeds))
+    }
+}
+
+impl_prng![WyRand: u64, current_u64, next_u64];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::WyRand;
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![WyRand];
+
+    impl SeedableRng for WyRand {
+        type Seed = [u8; 8];
+
+        fn from_seed(seed: Self::Seed) -> Self {
+            Self::new(u64::from_le_bytes(seed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // from `wyrand` in the final version 4 of the reference implementation
+        // at https://github.com/wangyi-fudan/wyhash, before version 4.2
+        // changed its constants
+        let mut rng = WyRand::new(42);
+        for expected in [
+            12558987674375533620,
+            16846851108956068306,
+            14652274819296609082,
+            16945271478357465713,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+}
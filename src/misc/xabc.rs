@@ -1,8 +1,10 @@
 // alazar::misc::xabc
 //
-//!
+//! The `Xabc` pseudo-random number generator.
 //
 
+use crate::{misc::SplitMix64, prng::impl_prng};
+
 /// X ABC Algorithm Random Number Generator for 8-bit Devices.
 ///
 /// It has a 32-bit state and generates 8-bit numbers.
@@ -80,7 +82,7 @@ impl Xabc {
         self.b ^= seeds[1];
         self.c ^= seeds[2];
 
-        self.x += 1;
+        self.x = self.x.wrapping_add(1);
         self.a = self.a ^ self.c ^ self.x;
         self.b = self.b.wrapping_add(self.a);
         self.c = self.c.wrapping_add(self.b >> 1) ^ self.a;
@@ -114,7 +116,7 @@ impl Xabc {
     #[must_use]
     pub const fn next_new(&self) -> Self {
         let [mut a, mut b, mut c, mut x] = [self.a, self.b, self.c, self.x];
-        x += 1;
+        x = x.wrapping_add(1);
         a = a ^ c ^ x;
         b = b.wrapping_add(a);
         c = c.wrapping_add(b >> 1) ^ a;
@@ -131,50 +133,27 @@ impl Xabc {
     pub const fn new3_u8(seeds: [u8; 3]) -> Self {
         Self::new(seeds)
     }
+
+    /// Returns a seeded `Xabc` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new(SplitMix64::seed_u8s(seed))
+    }
 }
 
+impl_prng![Xabc: u8, current_u8, next_u8];
+
 #[cfg(feature = "rand_core")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
 mod impl_rand {
     use super::Xabc;
-    use rand_core::{Error, RngCore, SeedableRng};
-
-    impl RngCore for Xabc {
-        /// Returns the next 4 × random `u8` combined as a single `u32`.
-        fn next_u32(&mut self) -> u32 {
-            u32::from_le_bytes([
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-            ])
-        }
-
-        /// Returns the next 8 × random `u8` combined as a single `u64`.
-        fn next_u64(&mut self) -> u64 {
-            u64::from_le_bytes([
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-            ])
-        }
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
 
-        fn fill_bytes(&mut self, dest: &mut [u8]) {
-            for byte in dest {
-                *byte = self.next_u8();
-            }
-        }
-
-        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-            self.fill_bytes(dest);
-            Ok(())
-        }
-    }
+    impl_rand_core![Xabc];
 
     impl SeedableRng for Xabc {
         type Seed = [u8; 3];
@@ -0,0 +1,641 @@
+// alazar::mt::jump
+//
+//! Jump polynomials of the Mersenne Twister generators.
+//!
+//! The coefficient of `x^i` is the bit `i % 64` of the word `i / 64`.
+//!
+//! They were computed by finding each characteristic polynomial with the
+//! Berlekamp-Massey algorithm, and raising `x` to `2^128` by squaring.
+//
+
+/// The polynomial `x^(2^128)` modulo the characteristic polynomial of `MT19937`.
+pub(super) const MT19937_JUMP: [u64; 312] = [
+    0xB570_9EC4_72DE_3963,
+    0xA823_F8E5_8827_9BB6,
+    0x041F_2259_26D8_3E59,
+    0x8B52_1777_E7FD_BB15,
+    0xBF28_12D5_48B5_E756,
+    0x0B48_49AA_E4B0_ADB9,
+    0xE96D_39CE_3E92_8B83,
+    0x09EA_F2E8_AF61_31D3,
+    0xC181_4C7B_3354_8456,
+    0xFEBD_07BC_893A_7C83,
+    0x5147_DCBF_01BD_8267,
+    0x9AFE_F574_E2A6_7DE6,
+    0xF0D3_DECA_B833_4D09,
+    0xD884_703B_5561_FD58,
+    0xB39B_8F42_EF5C_803B,
+    0xD61C_FED3_20DF_B761,
+    0x4741_6177_CF5F_3E5B,
+    0x8EA9_CFAB_8E84_42E9,
+    0x60DD_F78D_585D_0EC0,
+    0xF0F7_D60E_2C9B_8528,
+    0xCA3E_E37D_B2BB_3BFC,
+    0x870E_D969_81C9_E659,
+    0xCE52_4851_9573_A0DE,
+    0x73CD_A5ED_7768_3B94,
+    0xF43B_956C_56BC_FCBC,
+    0xBF04_B400_1F91_DE14,
+    0x1D85_9831_9438_C481,
+    0x9D97_AED5_CA6A_E0A2,
+    0xE75C_9519_9E46_4218,
+    0xCD43_455C_253C_5486,
+    0x7F82_82D4_73B5_CCD8,
+    0x192D_DF99_C8CA_CD44,
+    0x5288_B589_D6BE_8546,
+    0x9819_557F_B4F2_6CA7,
+    0x03E7_3D28_2005_70EB,
+    0x78A1_14C9_264A_CC04,
+    0x42EE_E897_95F0_FB7B,
+    0x67E7_51E8_ABCC_80C2,
+    0x140E_87EF_1330_CC85,
+    0xD3F8_525E_913B_9A96,
+    0x1BA1_158F_3EE3_D205,
+    0x1F6A_A87D_2C4C_DB89,
+    0x878B_3223_9B5E_9A3A,
+    0xA48C_7778_A498_C3ED,
+    0x1D08_F055_974A_C066,
+    0xD6DE_80E9_C8A0_8242,
+    0x2892_CE4C_A1CF_0B40,
+    0x6041_68AE_8427_31C7,
+    0xBECF_F8B2_DD23_EE6D,
+    0xA436_9751_DFAC_7287,
+    0x4A58_40D9_BA8B_C89D,
+    0xF53B_DBED_A7A5_8582,
+    0xA414_9D1C_CFBA_4997,
+    0xF2C7_2905_D5C6_6FC3,
+    0xAE4D_8E96_CE68_AD39,
+    0xC588_F396_F213_A9B5,
+    0x2C61_8D4E_9D61_16BB,
+    0xEBFB_61F3_B344_20D1,
+    0xCBDC_A6F2_3B70_2ED7,
+    0xBE28_3395_7CB7_8166,
+    0x20C0_D096_03A2_436A,
+    0xBF49_B815_E190_AA6F,
+    0x9B45_B903_49D7_8DC3,
+    0x67EB_90E3_0AA4_C4C8,
+    0x7F5C_EAB1_F32B_13F0,
+    0x641E_AEDB_CCC4_8294,
+    0x80B5_5358_6D6A_AFB6,
+    0xF1FA_779A_72B5_5832,
+    0x8992_AEFD_3B60_AF74,
+    0x2835_9472_4FA6_09F2,
+    0x527D_C1A9_61E7_AAF1,
+    0xBCAD_693F_834E_8087,
+    0x9517_1796_C9CA_3BF6,
+    0xB7D3_6775_9F41_164A,
+    0x5C77_677B_CF20_CF3B,
+    0x47DF_D69F_F476_5B01,
+    0xD708_247F_D90D_6E15,
+    0xAD79_9628_5FE9_5113,
+    0xFCFB_0CE2_C627_F9F2,
+    0x4B00_3380_0F24_41CE,
+    0x50FA_780B_7216_1100,
+    0xB71C_A8B7_1F72_B11A,
+    0x5475_BACE_FFAB_42FD,
+    0x356E_EF78_91C2_8B39,
+    0xDC80_086D_1441_C9C3,
+    0xB5C3_0EC9_96C4_7491,
+    0xA932_1ADD_A254_E42D,
+    0xC30B_EE5B_963A_3612,
+    0xDF14_1323_635C_75C7,
+    0x8926_E38F_3830_8F58,
+    0x8977_54D8_71B6_9592,
+    0x5BC0_6174_3CDD_DE5E,
+    0xBEBB_80A7_AD52_0904,
+    0xD91D_5D33_5CC2_84D4,
+    0x1109_0E41_8C6B_A748,
+    0x462C_FFBC_33BB_9929,
+    0xEFC6_8605_C42A_508E,
+    0x230E_6CD9_602A_3A14,
+    0x49B8_EB31_26C6_F9F4,
+    0x7C49_E7A4_51BD_358F,
+    0x1910_BB39_47B5_92CB,
+    0xAD0C_A518_3CED_6A5B,
+    0xD98C_A579_9346_1DCB,
+    0xECC5_CB65_9526_948E,
+    0x0BDD_C87D_FD1A_431B,
+    0x7D98_20AC_5D69_4024,
+    0x716C_1AE1_FFEB_5538,
+    0x04F8_ED86_13CF_FB2F,
+    0x1B32_EB97_D777_F039,
+    0x893D_A4EE_87C1_A95F,
+    0x9651_18D4_C235_F16C,
+    0xF990_23E2_E879_94BA,
+    0x8912_68A5_BB8C_4545,
+    0x4D16_3861_E7CF_46B4,
+    0xCA68_8C0E_0B2C_5681,
+    0xB863_46B5_3670_2E5F,
+    0x72A6_0137_55E3_11BB,
+    0x47D1_0E13_142F_DC5C,
+    0xAC08_8C30_A34C_E0CB,
+    0x4D79_A2E8_8F95_03FE,
+    0x02B4_C095_9376_70C7,
+    0x0805_33C0_20F8_F5E0,
+    0xAB1D_0C25_81FE_8F32,
+    0xB601_BB28_048F_776D,
+    0xF8B8_E16E_9600_4A47,
+    0x4A9F_A042_6862_AF7B,
+    0x5438_4AD4_B0B6_F662,
+    0x8167_0A57_A350_C0EE,
+    0x3A2C_2820_2606_1DC1,
+    0xB974_9667_B575_F899,
+    0xAA85_3838_738D_FC2A,
+    0xA53A_92A4_00CC_C442,
+    0xBDC8_CFA2_CFAF_5A3E,
+    0x529F_EE9D_0988_4265,
+    0x966C_709E_A4D7_F84F,
+    0xD142_65D4_4C80_BC42,
+    0xB23C_2AED_F5EB_E7F3,
+    0xB7D4_7C42_8045_23F1,
+    0x7337_0568_A7CB_0AA9,
+    0x6615_8A1E_06D9_0AC5,
+    0xC4A3_898C_9805_C7AD,
+    0x7FC5_3690_7890_ADDE,
+    0xC542_7E08_85C3_9B20,
+    0x2FBA_05ED_C0C8_64F8,
+    0x210A_D2BF_C365_017A,
+    0x609C_A003_8FFB_95EA,
+    0x84E6_63C4_8E6C_4F72,
+    0x753C_1CA8_3C11_0562,
+    0x4864_2AFC_8700_B723,
+    0xCEF1_123E_14AC_952C,
+    0xF075_B8B8_ED84_973C,
+    0xF00A_255A_0CEA_C5C9,
+    0x7E77_E0DA_DFCD_487C,
+    0x0071_CB97_8BE5_750C,
+    0x28C4_386F_5608_27FE,
+    0xBF6B_3AD6_AF40_49F0,
+    0x2E30_06D1_A911_AADD,
+    0x2E84_89F9_5EB5_BB74,
+    0x8427_8164_C36F_B83D,
+    0x61E0_E6BE_8230_2B47,
+    0x11B5_9C56_0422_260E,
+    0x9CD5_ECAA_E4F2_0C9C,
+    0x9BC7_2523_F866_E2DA,
+    0x816F_533C_52C4_1667,
+    0xA0DB_FF9E_47A3_235E,
+    0xEA9C_A5A3_0C62_A756,
+    0xC512_67E9_DE07_61A6,
+    0xF28B_8866_3EED_2AF6,
+    0xFD76_9663_695E_D01F,
+    0xBC47_FCDF_9065_AF4E,
+    0x424E_389C_DFCA_6259,
+    0xBB03_335E_166C_2C1B,
+    0xC4BE_33DD_2A73_A1A1,
+    0x4574_6BC2_E690_D058,
+    0x07D3_8D7F_94B4_3407,
+    0x74B8_51E4_6085_4FB3,
+    0xD99D_F507_DB3D_2AC2,
+    0x5D6C_254C_86D3_323B,
+    0xB4DD_3032_82BF_AC22,
+    0xB726_1A5F_B27E_023B,
+    0x40F3_61BF_34FE_8179,
+    0xE716_500E_6C9E_7858,
+    0x35C6_EE0B_6587_3B06,
+    0xE4C5_D4FC_FB28_64E7,
+    0x858E_E284_2819_01C6,
+    0x4480_3A65_E5FC_A3CD,
+    0xF9F4_1E41_F850_F7F6,
+    0x87CB_F3C9_65EB_5539,
+    0xAE05_6412_BE2F_8074,
+    0xD8FE_916F_3C5C_B955,
+    0xD18C_CB5E_AEC2_89DF,
+    0x4461_57F2_0EEF_81BF,
+    0xDE98_2175_4690_364A,
+    0xD094_591B_C159_7EA0,
+    0x7967_6E7A_B1ED_3E17,
+    0xA283_BDF6_C495_EBC1,
+    0x6A06_B25C_648C_3570,
+    0x0DEB_138C_398B_0580,
+    0x4E3D_096A_E511_08ED,
+    0xAFDE_012B_1DDA_7416,
+    0xCB00_1892_722F_0317,
+    0x82D7_56D2_2387_5CF7,
+    0x2091_CE44_C991_14DE,
+    0x8A94_4EF9_D247_57B4,
+    0xEDF8_F12B_8594_145A,
+    0xF30C_0CE9_998C_4AFF,
+    0xBA65_7A58_9CE6_01A0,
+    0x94E6_EC8D_36A8_51DD,
+    0x86AD_A470_ED46_B938,
+    0x46C7_14B9_409B_507D,
+    0xB628_043E_05C8_62A8,
+    0x8D76_3A8C_7AC4_A188,
+    0x7F5B_A797_0ADC_18B6,
+    0x5DB4_BC6B_6907_3599,
+    0x3D08_7E22_444D_59D3,
+    0x6146_6F51_E9C0_4E89,
+    0x151F_D405_548A_A4E6,
+    0x6090_5661_9155_5389,
+    0x3E3C_8561_5E8D_5619,
+    0x2491_156C_39C6_B81C,
+    0x17B4_D42C_FC2F_D4A6,
+    0x2BD7_04CF_82C9_BCF9,
+    0x0540_3240_7B25_68EC,
+    0x7E03_7B6B_5D22_68D9,
+    0x231F_10E7_D86B_EC7A,
+    0x964F_8501_BA01_6830,
+    0x9873_C321_A3B7_321F,
+    0xA5A2_50E1_350A_C2DD,
+    0xC738_D247_2657_8385,
+    0xCD33_873C_0125_41CA,
+    0xD0CD_C82C_C590_7F19,
+    0x5656_CCA4_5C2B_540A,
+    0xA3D9_87B8_1F88_7DD1,
+    0x06A2_8478_83E7_FE48,
+    0x465F_2DF8_9456_82DB,
+    0xFAC8_FFBC_9B49_4CE1,
+    0xB12A_C825_598F_39CD,
+    0x3E5C_217E_FA99_231B,
+    0xE550_FDBA_3B2D_8BA2,
+    0x846A_6733_8E51_0006,
+    0xEE48_A926_3E57_3194,
+    0x41C3_94C8_5CCD_36BD,
+    0xA19B_67F2_10A7_9620,
+    0x8A28_5C06_8B3F_D2A6,
+    0x3637_050A_3A17_97D9,
+    0x7295_647E_63DF_CA07,
+    0xBE8E_7601_7A7B_3BBA,
+    0x3C1E_511A_EA66_0549,
+    0x06C4_0C25_C7A1_931A,
+    0x7D18_8664_3796_CF70,
+    0xB9F7_0031_CCD9_FA38,
+    0x87FE_9735_601E_2C75,
+    0xEF64_5DD6_F8CD_68B0,
+    0x535D_7138_7D05_B323,
+    0x9032_7A26_5C02_F47F,
+    0xABD5_EA25_63EC_D3B2,
+    0x302C_1641_0162_4325,
+    0x1CDF_A6BC_DBFB_EB93,
+    0xB159_87ED_8665_19A2,
+    0x0C31_EC84_1132_96F1,
+    0xB413_2090_232A_35B2,
+    0x5351_72E3_92D0_C3C5,
+    0xFC24_A0A9_095F_FCCB,
+    0x2546_326E_932C_038E,
+    0x1BBA_FC54_CCC1_5E47,
+    0xA848_6630_3CF2_A838,
+    0x8405_B4AE_1057_E025,
+    0x1EEC_4C73_DA36_738D,
+    0x4F9F_F104_88B3_0F90,
+    0x6EAB_7DA8_85EE_A780,
+    0x6FE9_593D_40D9_FDBE,
+    0x6560_6C0C_3C85_0D3C,
+    0x7030_8A34_B078_A231,
+    0x6D9A_7CBE_635A_F9BD,
+    0x6366_0519_ED73_EE32,
+    0x0E62_955F_1701_DD8D,
+    0x9CB6_6A13_180D_B0E9,
+    0x78FB_88AA_D3C2_CD3E,
+    0xA285_9C52_85FD_BE48,
+    0x902F_FD41_9579_F8F8,
+    0x1F5E_048A_4B7C_6A7B,
+    0x706D_2495_8E26_2D89,
+    0x816D_7F42_EBBB_D878,
+    0x3E6C_C58A_88CD_FBF1,
+    0xAA7D_FAFD_754A_64AB,
+    0xB63C_D2F7_E98D_0A02,
+    0x72C5_B57F_38C8_C85C,
+    0xE479_DA34_B97F_2B0A,
+    0x7C86_232A_553E_33F7,
+    0xEDC6_266D_B35C_C8F8,
+    0x14B7_F688_CA67_E7FE,
+    0xB3D3_D66F_072D_997B,
+    0x1210_05B9_528C_6A42,
+    0x87D3_1F39_0DF2_B622,
+    0xEDAE_DB37_12CE_5FD4,
+    0x8E53_FF25_49DE_C2F4,
+    0x7640_41AA_E79E_435A,
+    0xB359_BD5E_29A3_EE70,
+    0x303A_CD04_5AA2_B047,
+    0x1657_95C2_B82A_2D07,
+    0x950F_AAC1_A64A_B733,
+    0xFF19_5E03_DFA2_861F,
+    0x5EB3_60EC_8CD6_E865,
+    0x19E1_A74D_639C_B063,
+    0x775C_20D6_7EC1_2528,
+    0x0872_2D7F_A44C_4DDF,
+    0x83D1_45BC_B0C9_2D32,
+    0x73DA_60E4_3B22_07E8,
+    0x9628_13B9_A13D_0929,
+    0xEB65_72D6_738F_420B,
+    0x80A4_A0EF_151A_52CA,
+    0x0000_0000_23EE_E457,
+];
+
+/// The polynomial `x^(2^128)` modulo the characteristic polynomial of `MT19937-64`.
+pub(super) const MT19937_64_JUMP: [u64; 312] = [
+    0x153F_BC23_409B_1E30,
+    0xB8D5_8A2E_FC1C_C7BE,
+    0x04CC_8DF6_BD55_73E1,
+    0x8E1B_99D6_EA32_2754,
+    0x7FA5_C8AB_11A7_8ECF,
+    0xA3F0_1992_F879_DC26,
+    0x7750_0E62_929D_74D1,
+    0x4C65_EF43_9F2D_CB2A,
+    0x731B_3BD3_538E_EC46,
+    0x14CD_564C_40C9_E3AE,
+    0x6FF6_5677_7522_68B7,
+    0xBBEA_104C_48EC_8B8D,
+    0x08D3_5659_7256_8EA4,
+    0x5CB7_9DB1_F773_95F2,
+    0x94F5_C348_A32C_ECAC,
+    0x4B58_CC38_B612_3ED7,
+    0x64D1_91A0_0B3E_362C,
+    0x7B05_1615_BC10_5659,
+    0x2AD1_1E2D_812E_15D2,
+    0xD255_1D15_C944_F218,
+    0x6837_4254_D1F4_6885,
+    0x72A5_FD77_00E8_C34F,
+    0xE40B_4AC6_1E14_376C,
+    0xBB10_7CD0_A915_8CC0,
+    0x5028_A2A3_D4CE_28E6,
+    0xD081_5EEB_2E91_AA05,
+    0x29BA_386F_6309_E7DD,
+    0xA19B_F128_091D_F643,
+    0xA4DD_A3EA_5AF2_47F8,
+    0x950F_F2C8_BC8D_9F30,
+    0xC415_A087_1EF1_AF4E,
+    0xE885_9D7A_5AC3_264C,
+    0x4D58_E6BE_D073_9FE2,
+    0xB072_D474_E3F9_602C,
+    0x93B1_1203_5CF0_E33D,
+    0x90D4_AF56_420A_0A3D,
+    0xCB93_0CDF_FD09_BA87,
+    0x8230_5413_C76B_A04A,
+    0x88ED_61BA_7DFC_9075,
+    0xDEFC_75A7_869C_145C,
+    0x0C16_9166_9677_5659,
+    0x94A4_7BF0_B5D3_869B,
+    0x026C_4476_E255_1799,
+    0x2B22_D900_27FD_D747,
+    0xE447_AF77_1864_4777,
+    0xBB83_F1C0_3190_E0FA,
+    0x932F_ABC7_17B3_114C,
+    0xE038_4041_DBD5_EAFD,
+    0x698C_A9A2_304F_A895,
+    0xBBB2_6EFF_4E2F_6627,
+    0x453C_AB96_7A47_0645,
+    0x2A6A_EFAB_CD19_D4E9,
+    0x808F_8D33_240F_6B90,
+    0x91BF_46C9_3A4B_852B,
+    0x74B6_A859_7100_E697,
+    0xBD2A_4EF2_3956_4089,
+    0x9917_718E_08EC_24FA,
+    0xAC9C_E650_DCCC_5D61,
+    0x52DB_4D76_A2C5_546C,
+    0x0123_E0FC_3CB9_0AEA,
+    0xFE78_F1E8_3BB9_3635,
+    0x4F5B_739D_5BA0_4851,
+    0xA4BF_7F96_E968_4A89,
+    0x5464_BB37_7A97_F62E,
+    0x3289_33F0_06CE_14BE,
+    0x43E5_58B7_D62A_E5D7,
+    0xDDB0_F33F_21E7_D8DC,
+    0x52D2_779D_E933_20D2,
+    0x5719_1C72_ACFC_5093,
+    0x1779_3848_19CA_00E9,
+    0x7AFC_FBBE_2ACA_A684,
+    0x9023_1D57_884A_7544,
+    0xDD3F_FEAD_4FEE_C6E3,
+    0x2735_84A4_2F1A_795D,
+    0x6916_0133_8D2C_7449,
+    0x8C8E_419C_A052_9FC3,
+    0x373E_37DD_051F_8B86,
+    0x27A2_D716_1F6D_06BD,
+    0x9542_4007_0472_311A,
+    0x4715_65B6_0A93_D2E4,
+    0x4FB4_AD96_2C32_8135,
+    0x7B1A_3A92_C401_E93B,
+    0xF261_C3FC_C82A_F141,
+    0x5724_1AF0_8978_F3EC,
+    0x2C79_AAA3_70D1_BD4F,
+    0xF357_90A0_9781_37D6,
+    0x38C7_263C_9623_4239,
+    0xE0A1_3A1D_D5F8_52B5,
+    0x0734_F6C9_62F8_6802,
+    0xCA52_564F_72F1_3F11,
+    0xA4BD_2A9D_C69A_1248,
+    0x6F41_8A04_EDB4_5E98,
+    0x764B_57A0_059A_A71A,
+    0x926F_6F5F_3542_66DF,
+    0x60C4_1500_13CC_9412,
+    0x3A14_980C_9D4C_CD96,
+    0x4E5D_A339_4423_9D8B,
+    0x23F3_EF6E_843C_729C,
+    0x389B_1022_DE0A_C7C9,
+    0x369B_29D7_D285_823E,
+    0xF556_214A_D63E_2CD9,
+    0x90E4_3B95_36BC_15AB,
+    0xA436_0400_7E23_FD84,
+    0x70EE_2BD8_D9E6_C2AF,
+    0x0E8B_6C7A_77FD_426A,
+    0xED09_417C_E0D7_3CDF,
+    0xA3E9_35E2_C81A_4021,
+    0x7CF2_E08B_2883_98FA,
+    0x1E93_3CDE_96A3_1115,
+    0xDB60_14C3_A780_C561,
+    0x2BF1_5950_B466_0F9D,
+    0x50CF_62EF_C80A_3C55,
+    0x448E_DE02_EA07_83C5,
+    0x97DF_0D14_F64C_01C7,
+    0x1353_357D_5433_68D0,
+    0x9BD1_4496_52CD_CA9C,
+    0x66D1_5AEF_A7A2_4321,
+    0x25DD_75FC_7492_BA9D,
+    0x468C_E9A1_A387_4E13,
+    0x40AB_9E8E_D67A_4AD1,
+    0x0BAF_B4D3_23D0_2677,
+    0xF9F3_D01C_1F43_5B69,
+    0x0C4A_0FA4_6FAC_656A,
+    0xBDAC_3ABD_D37E_4DFC,
+    0xDF9B_06EF_05DB_31DF,
+    0xED00_5F00_F37D_AA7B,
+    0x924B_E2E4_65B0_9410,
+    0x9909_9376_EA87_BE57,
+    0x302D_8A7C_49C4_BE6A,
+    0xE8EF_FC70_541C_07A5,
+    0x6E46_11AD_196A_6EE3,
+    0xBD42_CB15_A52C_B228,
+    0xCE34_3EE4_93CD_EC20,
+    0x7F42_31E3_D20E_8E72,
+    0xA212_7D2E_D81E_4F89,
+    0x27BB_32AF_A1C6_EF4C,
+    0x9D37_D9F4_CB87_C492,
+    0xA6B7_E94B_15E2_287C,
+    0x098B_4D30_2E16_D6E9,
+    0x12D1_DA8F_FBF3_ADB2,
+    0xD5BE_155B_C2FC_01DE,
+    0x90F6_30B9_E309_715B,
+    0xBDB1_08B0_F8DA_213C,
+    0x98ED_520D_71F4_9D1A,
+    0x8249_5AAC_D19E_B9DC,
+    0x124D_7478_A150_25B2,
+    0xA0EB_607E_C408_7775,
+    0xCB47_955E_EABE_0890,
+    0x7360_A3D0_E0B6_8B89,
+    0x25F5_BEE6_5615_9D92,
+    0xEAE8_434E_13F9_85ED,
+    0x04FF_3872_2AD1_0A86,
+    0xAC70_9721_5B43_4280,
+    0x3640_AE9D_D068_7B1A,
+    0xB242_09A4_CE9F_603B,
+    0xF03E_6FD6_F7A4_16DD,
+    0xD31E_5BCD_E486_72AF,
+    0x2704_CE60_EB84_29A7,
+    0xF7AE_B81F_8FCD_00C3,
+    0x5424_DBAA_0B63_6A3C,
+    0xF352_FE25_0D62_5A64,
+    0x9CC1_2556_C222_8F86,
+    0xEDAC_0DBB_94E9_4F51,
+    0xDD8F_2B1F_2676_2FD1,
+    0x5EF4_8807_6C7E_957F,
+    0x2B73_4DC8_A46C_3C61,
+    0x5211_1589_EB2A_22E3,
+    0xFA11_C9BB_843D_F4BC,
+    0x5896_AC2E_CF36_F9D2,
+    0x66C1_97A7_E49D_BA0A,
+    0xE1ED_A2CD_47AE_FD0F,
+    0x4CAE_0ACF_5D5F_A62D,
+    0xCB3E_21E3_F8D7_C943,
+    0x3515_80D2_7B75_FE44,
+    0x6CBD_4B56_18CB_AB9B,
+    0x8E47_EF05_42E8_A51D,
+    0x125A_DF6B_4B59_B2EF,
+    0x2729_DC33_4CAC_FD5B,
+    0x8834_32A7_3793_7820,
+    0x60F0_02C1_DCED_A4AB,
+    0xAFED_1BE4_6E7F_D2BC,
+    0xF2A3_D1CC_BF87_1115,
+    0xF85E_5C50_50AE_7160,
+    0x777C_DC44_554E_6D74,
+    0x0BCF_7521_3E25_9946,
+    0x9D07_14B4_DB9C_A29A,
+    0x370F_DC40_6732_6A6D,
+    0xFFEB_7138_07A1_CEA8,
+    0x7FB0_A967_4A53_E792,
+    0x62B0_4000_5F9C_E7BB,
+    0x8903_F6B2_82B6_7CAB,
+    0x3544_FF15_8026_EB52,
+    0xD665_9024_8ADF_92F1,
+    0x55DE_1C87_A2EB_DF48,
+    0x40B0_3822_8726_7ABA,
+    0x7DFA_56A6_FB26_180E,
+    0x45C3_2D7D_C66B_19CE,
+    0xF5ED_0EDF_6650_34C7,
+    0xF4C7_ADBE_75E1_5DA0,
+    0x95DB_8535_E0BD_9122,
+    0xC571_B096_20D8_2713,
+    0x9C21_ED0E_78F0_21F9,
+    0xD0CB_50A9_F9AA_8DEF,
+    0xBCB3_368C_4E9F_F5B6,
+    0x06D8_F649_7049_39A3,
+    0x5EAA_9EE1_86D1_4A54,
+    0x86D1_F972_FD48_83D0,
+    0x63B1_522F_4D50_D887,
+    0x982B_2FBA_1A98_75A7,
+    0x7258_BFD6_2359_30EA,
+    0xE4CC_C8E3_C2F0_F70E,
+    0x9BF3_90D1_1976_9362,
+    0x1BCE_A29D_BD2C_02BE,
+    0xD9C1_89DB_4133_98C0,
+    0x988A_A445_64F8_5434,
+    0x007E_D1EA_EEF5_E20A,
+    0xA068_5FED_E0EE_C596,
+    0xFEF1_77E0_B35A_7F0E,
+    0x5006_596F_191E_BC61,
+    0xCBA8_7C3E_61BD_BC8A,
+    0xFF21_7404_9069_BFCB,
+    0xD7A5_36DD_B2C4_F33F,
+    0xF7AE_CDE2_1FC2_D977,
+    0xC121_DCA3_FEEF_7800,
+    0xA90A_D927_D025_C16B,
+    0x3EA6_FEE5_3205_8E96,
+    0x9F52_10DF_30AC_DEB9,
+    0x520E_9488_9837_BCFF,
+    0x8C6C_6A10_0DAB_DB5B,
+    0x6D21_01F3_FC53_0774,
+    0x51D5_35E6_DC64_5E49,
+    0xE5E7_620E_D6A4_941B,
+    0xAF80_23C1_0704_6243,
+    0x62E6_E40F_4EA1_9600,
+    0x4663_96CE_1AB8_E939,
+    0x470F_C344_D01A_2A69,
+    0x2230_11F8_1654_9F0E,
+    0x9B0A_4017_3329_9C57,
+    0x6E21_4523_AE60_B334,
+    0x84C4_CBE4_5A9B_66A6,
+    0x630D_39F9_22B4_C0B4,
+    0xFBFA_79EC_2C0E_1012,
+    0xE994_0485_EC80_D5C0,
+    0x1DC1_C6FB_5A01_F32A,
+    0x9CD0_B7F3_A578_E57F,
+    0x40B6_CE9D_50E9_2C04,
+    0x588B_8AF3_9AB9_1D81,
+    0x8058_DC27_83B0_2DE3,
+    0xBB21_03C5_0439_2C9D,
+    0x7264_6922_2071_6211,
+    0xDB80_4FCD_EB98_7BBA,
+    0xABAB_D32A_4939_8687,
+    0xE3DE_E375_5B4D_A875,
+    0x16DE_733A_DB8B_B721,
+    0x9947_6D13_103F_FE32,
+    0x86D2_D629_666C_B05B,
+    0x9C4E_62AB_740C_E645,
+    0xB596_8226_5B75_19FF,
+    0x54DF_6930_E9ED_43FB,
+    0x33F8_2188_61F9_8B68,
+    0x21BC_7495_42F0_6516,
+    0xD5E9_662B_4586_DF7F,
+    0x4655_69EA_0EB5_CCE4,
+    0x36A4_84C9_38F0_AE75,
+    0xC088_CC51_89F8_0399,
+    0x4BEC_D1A8_A228_0CDE,
+    0x192F_20A7_4DAC_06F0,
+    0xAE76_6A8B_287A_1565,
+    0x036C_05BA_6ABF_F5F3,
+    0x5FE4_4849_3D8F_AF69,
+    0xA880_A8FF_94B9_0EA8,
+    0xD0EC_7C63_42D2_B77B,
+    0xD187_D706_8A2C_F90F,
+    0x3252_3F9A_D82E_6693,
+    0x0F87_420E_87B9_0726,
+    0x3A74_5F95_3D8E_0C35,
+    0x0199_993C_5A3D_1DB4,
+    0x33E4_5B57_66CC_B1A0,
+    0xD2AB_AAC1_626E_0B0C,
+    0xAD5C_3023_B061_FDFB,
+    0xF67C_F654_1CB6_6E52,
+    0xE9D9_083C_635A_2190,
+    0x29A1_03E0_C3B4_DAC8,
+    0x75F7_2ADB_5E7A_7E46,
+    0xDCC9_43AB_2EC2_96DA,
+    0x396A_079F_137F_F14B,
+    0x6785_3F3D_2918_2EC1,
+    0x35DD_3E7A_7A71_C780,
+    0xFBF8_2A6F_A275_A546,
+    0x39CC_58A7_583F_7227,
+    0x8B1B_1AED_EFEA_9FED,
+    0x909F_457D_ADA7_1450,
+    0xC02A_BFCB_FE3E_387A,
+    0xD687_1E18_B79A_E3C1,
+    0x9F6B_AC46_344F_1A0F,
+    0x3366_CD78_201A_BCED,
+    0xA9DA_4A52_0717_5299,
+    0x0306_42BA_F1AD_5022,
+    0x5AE1_2066_9A84_4AB0,
+    0xD8FC_12C8_76B5_DBB7,
+    0x2F92_B413_A6FC_6E34,
+    0x2F2B_5A6B_0F30_AFF4,
+    0x8963_3B16_1FAC_757A,
+    0x5E4B_F21C_A2B3_99C2,
+    0x5ED8_34F9_55DC_F6AB,
+    0xD5FD_C80D_6FA8_E6CD,
+    0xCDF0_9ED9_9544_069F,
+    0xFA9A_DC85_5E53_297C,
+    0x38FA_314D_5C46_AB53,
+    0x9450_8C05_DDA2_6A06,
+    0x7DE2_DAE2_AA41_5D2C,
+    0x0000_0001_43ED_6F2E,
+];
@@ -0,0 +1,22 @@
+// alazar::mt
+//
+//! Pseudo-random number generators of the [Mersenne Twister] family.
+//!
+//! This module defines two types:
+//! - a generator of 32-bit numbers: ([`Mt19937`]).
+//! - a generator of 64-bit numbers: ([`Mt19937_64`]).
+//!
+//! Their outputs match those of the reference implementations, for
+//! reproducing the results of other software. Both have `discard` and
+//! `jump` methods, for skipping ahead in their sequence and splitting it
+//! into non-overlapping parallel streams.
+//!
+//! [Mersenne Twister]: http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/emt.html
+//
+
+mod jump;
+mod u32;
+mod u64;
+
+pub use u32::Mt19937;
+pub use u64::Mt19937_64;
@@ -0,0 +1,395 @@
+// alazar::mt::u32
+//
+//! The 32-bit Mersenne Twister.
+//
+
+use super::jump::MT19937_JUMP;
+use crate::prng::impl_prng;
+use core::fmt;
+use devela::convert::{u32_from_u16_le, u32_from_u8_le, u64_into_u32_le};
+
+/// The `MT19937` pseudo-random number generator (*Mersenne Twister*).
+///
+/// It has a 19968-bit state, of which 19937 bits are significant,
+/// and generates 32-bit numbers. Its period is `2^19937 - 1`.
+///
+/// Its output is the same as that of the reference implementation for the
+/// same seed or key array, and as that of `std::mt19937` in C++ for the same
+/// seed.
+///
+/// It's the most widely used generator in scientific software, but it's slow
+/// to seed, its state is big, and it fails some statistical tests.
+///
+/// It was published by Makoto Matsumoto and Takuji Nishimura in
+/// [*Mersenne twister: a 623-dimensionally equidistributed uniform
+/// pseudo-random number generator*][paper] (1998).
+///
+/// Its [`Debug`] implementation doesn't show the state.
+///
+/// [paper]: https://doi.org/10.1145/272991.272995
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mt19937 {
+    mt: [u32; 624],
+    /// The index of the next word.
+    index: usize,
+}
+
+impl fmt::Debug for Mt19937 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Mt19937")
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Mt19937 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Mt19937 {
+    const DEFAULT_SEED: u32 = 0xDEFA0017;
+
+    const N: usize = 624;
+    const M: usize = 397;
+    const MATRIX_A: u32 = 0x9908_B0DF;
+    const UPPER_MASK: u32 = 0x8000_0000;
+    const LOWER_MASK: u32 = 0x7FFF_FFFF;
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+
+    /// Returns a field by field copy, since the type is not `Copy`.
+    #[inline]
+    const fn copy(&self) -> Self {
+        Self {
+            mt: self.mt,
+            index: self.index,
+        }
+    }
+
+    /// Returns the initial state array of the given `seed`.
+    const fn init_genrand(seed: u32) -> [u32; 624] {
+        let mut mt = [0; 624];
+        mt[0] = seed;
+        let mut i = 1;
+        while i < Self::N {
+            let prev = mt[i - 1] ^ (mt[i - 1] >> 30);
+            mt[i] = 1_812_433_253_u32.wrapping_mul(prev).wrapping_add(i as u32);
+            i += 1;
+        }
+        mt
+    }
+
+    /// Returns a new generator from the initial state array,
+    /// ready to return its first word.
+    #[inline]
+    const fn from_init(mt: [u32; 624]) -> Self {
+        let mut new = Self { mt, index: 0 };
+        new.twist();
+        new
+    }
+
+    /// Returns the next word of the linear recurrence,
+    /// from the words `N`, `N - 1` and `N - M` positions before it.
+    #[inline(always)]
+    const fn recurrence(x0: u32, x1: u32, xm: u32) -> u32 {
+        let y = (x0 & Self::UPPER_MASK) | (x1 & Self::LOWER_MASK);
+        let mag = if y & 1 == 0 { 0 } else { Self::MATRIX_A };
+        xm ^ (y >> 1) ^ mag
+    }
+
+    /// Generates the next `N` words of the state.
+    const fn twist(&mut self) {
+        let mut k = 0;
+        while k < Self::N {
+            let (k1, km) = ((k + 1) % Self::N, (k + Self::M) % Self::N);
+            self.mt[k] = Self::recurrence(self.mt[k], self.mt[k1], self.mt[km]);
+            k += 1;
+        }
+    }
+
+    /// The tempering transformation of each output word.
+    #[inline(always)]
+    const fn temper(mut y: u32) -> u32 {
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9D2C_5680;
+        y ^= (y << 15) & 0xEFC6_0000;
+        y ^ (y >> 18)
+    }
+
+    /// Advances the generator with the given jump polynomial.
+    ///
+    /// The state array always holds a whole block of the sequence, so the
+    /// polynomial is applied to it, and the index stays the same.
+    const fn jump_with(&mut self, poly: &[u64; 312]) {
+        let (mut state, mut ring, mut pos) = ([0; 624], self.mt, 0);
+        let mut i = 0;
+        while i < 19937 {
+            if poly[i / 64] & (1 << (i % 64)) != 0 {
+                let mut k = 0;
+                while k < Self::N {
+                    state[k] ^= ring[(pos + k) % Self::N];
+                    k += 1;
+                }
+            }
+            let (k1, km) = ((pos + 1) % Self::N, (pos + Self::M) % Self::N);
+            ring[pos] = Self::recurrence(ring[pos], ring[k1], ring[km]);
+            pos = k1;
+            i += 1;
+        }
+        self.mt = state;
+    }
+}
+
+impl Mt19937 {
+    /// Returns a seeded `MT19937` generator from the given 32-bit seed.
+    ///
+    /// This is the `init_genrand` function of the reference implementation.
+    #[must_use]
+    pub const fn new(seed: u32) -> Self {
+        Self::from_init(Self::init_genrand(seed))
+    }
+
+    /// Returns a seeded `MT19937` generator from the given array of 32-bit
+    /// words.
+    ///
+    /// This is the `init_by_array` function of the reference implementation.
+    ///
+    /// Returns `None` if the array is empty.
+    #[must_use]
+    pub const fn new_by_array(key: &[u32]) -> Option<Self> {
+        let len = key.len();
+        if len == 0 {
+            return Self::cold_path_result();
+        }
+        let mut mt = Self::init_genrand(19_650_218);
+        let (mut i, mut j) = (1, 0);
+        let mut k = if Self::N > len { Self::N } else { len };
+        while k > 0 {
+            let prev = mt[i - 1] ^ (mt[i - 1] >> 30);
+            mt[i] = (mt[i] ^ prev.wrapping_mul(1_664_525))
+                .wrapping_add(key[j])
+                .wrapping_add(j as u32);
+            i += 1;
+            j += 1;
+            if i >= Self::N {
+                mt[0] = mt[Self::N - 1];
+                i = 1;
+            }
+            if j >= len {
+                j = 0;
+            }
+            k -= 1;
+        }
+        k = Self::N - 1;
+        while k > 0 {
+            let prev = mt[i - 1] ^ (mt[i - 1] >> 30);
+            mt[i] = (mt[i] ^ prev.wrapping_mul(1_566_083_941)).wrapping_sub(i as u32);
+            i += 1;
+            if i >= Self::N {
+                mt[0] = mt[Self::N - 1];
+                i = 1;
+            }
+            k -= 1;
+        }
+        // the most significant bit is set, so that the state is never zero
+        mt[0] = 1 << 31;
+        Some(Self::from_init(mt))
+    }
+
+    /// Returns the current random `u32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u32(&self) -> u32 {
+        Self::temper(self.mt[self.index])
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        let result = self.current_u32();
+        self.index += 1;
+        if self.index == Self::N {
+            self.twist();
+            self.index = 0;
+        }
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut new = self.copy();
+        new.discard(1);
+        new
+    }
+
+    /// Advances the generator `n` steps, as if calling
+    /// [`next_u32`][Self::next_u32] that many times.
+    ///
+    /// This is the `discard` method of `std::mt19937` in C++.
+    pub const fn discard(&mut self, mut n: u64) {
+        while n > 0 {
+            let left = (Self::N - self.index) as u64;
+            if n < left {
+                self.index += n as usize;
+                return;
+            }
+            n -= left;
+            self.twist();
+            self.index = 0;
+        }
+    }
+
+    /// Advances the generator `2^128` steps, as if calling
+    /// [`next_u32`][Self::next_u32] that many times.
+    ///
+    /// It can be used to generate `2^19809` non-overlapping
+    /// subsequences for parallel computations.
+    ///
+    /// It's much slower than the jumps of smaller generators, since it steps
+    /// the generator 19937 times and adds up about half of those states.
+    pub const fn jump(&mut self) {
+        self.jump_with(&MT19937_JUMP);
+    }
+}
+
+/// # Extra constructors
+impl Mt19937 {
+    /// Returns a seeded `MT19937` generator from the given 32-bit seed.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new1_u32(seed: u32) -> Self {
+        Self::new(seed)
+    }
+
+    /// Returns a seeded `MT19937` generator from the given 2 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new2_u16(seeds: [u16; 2]) -> Self {
+        Self::new(u32_from_u16_le(seeds))
+    }
+
+    /// Returns a seeded `MT19937` generator from the given 4 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u8(seeds: [u8; 4]) -> Self {
+        Self::new(u32_from_u8_le(seeds))
+    }
+
+    /// Returns a seeded `MT19937` generator from the given 64-bit seed.
+    ///
+    /// The seed is split in little endian order into a key array of
+    /// 2 words for [`new_by_array`][Self::new_by_array].
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        match Self::new_by_array(&u64_into_u32_le(seed)) {
+            Some(new) => new,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl_prng![Mt19937: u32, current_u32, next_u32];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::Mt19937;
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![Mt19937];
+
+    impl SeedableRng for Mt19937 {
+        type Seed = [u8; 4];
+
+        fn from_seed(seed: Self::Seed) -> Self {
+            Self::new(u32::from_le_bytes(seed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The expected values are from the reference implementation at
+    // http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/emt.html
+
+    #[test]
+    fn init_genrand() {
+        let mut rng = Mt19937::new(5489);
+        for expected in [3499211612, 581869302, 3890346734, 3586334585, 545404204] {
+            assert_eq![rng.next_u32(), expected];
+        }
+        // the 10000th output of the C++ default-constructed `std::mt19937`
+        rng.discard(10000 - 5 - 1);
+        assert_eq![rng.next_u32(), 4123659995];
+    }
+
+    #[test]
+    fn init_by_array() {
+        let mut rng = Mt19937::new_by_array(&[0x123, 0x234, 0x345, 0x456]).unwrap();
+        for expected in [1067595299, 955945823, 477289528, 4107218783, 4228976476] {
+            assert_eq![rng.next_u32(), expected];
+        }
+        assert![Mt19937::new_by_array(&[]).is_none()];
+    }
+
+    #[test]
+    fn discard() {
+        let mut a = Mt19937::new(7);
+        let mut b = a.clone();
+        for n in [0, 1, 623, 624, 1000, 5000] {
+            for _ in 0..n {
+                let _ = a.next_u32();
+            }
+            b.discard(n);
+            assert_eq![a, b];
+        }
+    }
+
+    #[test]
+    fn jump_with() {
+        // the jump polynomial of a distance under 19937 is just `x^n`
+        let mut a = Mt19937::new(7);
+        a.discard(100);
+        let mut b = a.clone();
+        let mut poly = [0; 312];
+        poly[3000 / 64] = 1 << (3000 % 64);
+        a.jump_with(&poly);
+        b.discard(3000);
+        for _ in 0..1000 {
+            assert_eq![a.next_u32(), b.next_u32()];
+        }
+    }
+
+    #[test]
+    fn jump() {
+        // computed independently, by summing the states of the reference
+        // implementation selected by the coefficients of `x^(2^128)` modulo
+        // the minimal polynomial of its sequence, found by Berlekamp-Massey
+        let expected = [1297186950, 2930575927, 3015810866, 1451871318, 498222669];
+        let mut rng = Mt19937::new(5489);
+        rng.jump();
+        for expected in expected {
+            assert_eq![rng.next_u32(), expected];
+        }
+        // the position within the block is kept
+        let mut rng = Mt19937::new(5489);
+        rng.discard(2);
+        rng.jump();
+        assert_eq![rng.next_u32(), expected[2]];
+    }
+}
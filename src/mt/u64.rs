@@ -0,0 +1,410 @@
+// alazar::mt::u64
+//
+//! The 64-bit Mersenne Twister.
+//
+
+use super::jump::MT19937_64_JUMP;
+use crate::prng::impl_prng;
+use core::fmt;
+use devela::convert::{u64_from_u16_le, u64_from_u32_le, u64_from_u8_le};
+
+/// The `MT19937-64` pseudo-random number generator (*Mersenne Twister*).
+///
+/// It has a 19968-bit state, of which 19937 bits are significant,
+/// and generates 64-bit numbers. Its period is `2^19937 - 1`.
+///
+/// This is the 64-bit version of [`Mt19937`], with a different recurrence
+/// and tempering, so their outputs are unrelated.
+///
+/// Its output is the same as that of the reference implementation for the
+/// same seed or key array, and as that of `std::mt19937_64` in C++ for the
+/// same seed.
+///
+/// It was published by Takuji Nishimura in [*Tables of 64-bit Mersenne
+/// twisters*][paper] (2000).
+///
+/// Its [`Debug`] implementation doesn't show the state.
+///
+/// [`Mt19937`]: super::Mt19937
+/// [paper]: https://doi.org/10.1145/369534.369540
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mt19937_64 {
+    mt: [u64; 312],
+    /// The index of the next word.
+    index: usize,
+}
+
+impl fmt::Debug for Mt19937_64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Mt19937_64")
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Mt19937_64 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Mt19937_64 {
+    const DEFAULT_SEED: u64 = 0xDEFA0017_DEFA0017;
+
+    const N: usize = 312;
+    const M: usize = 156;
+    const MATRIX_A: u64 = 0xB502_6F5A_A966_19E9;
+    const UPPER_MASK: u64 = 0xFFFF_FFFF_8000_0000;
+    const LOWER_MASK: u64 = 0x7FFF_FFFF;
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+
+    /// Returns a field by field copy, since the type is not `Copy`.
+    #[inline]
+    const fn copy(&self) -> Self {
+        Self {
+            mt: self.mt,
+            index: self.index,
+        }
+    }
+
+    /// Returns the initial state array of the given `seed`.
+    const fn init_genrand(seed: u64) -> [u64; 312] {
+        let mut mt = [0; 312];
+        mt[0] = seed;
+        let mut i = 1;
+        while i < Self::N {
+            let prev = mt[i - 1] ^ (mt[i - 1] >> 62);
+            mt[i] = 6_364_136_223_846_793_005_u64
+                .wrapping_mul(prev)
+                .wrapping_add(i as u64);
+            i += 1;
+        }
+        mt
+    }
+
+    /// Returns a new generator from the initial state array,
+    /// ready to return its first word.
+    #[inline]
+    const fn from_init(mt: [u64; 312]) -> Self {
+        let mut new = Self { mt, index: 0 };
+        new.twist();
+        new
+    }
+
+    /// Returns the next word of the linear recurrence,
+    /// from the words `N`, `N - 1` and `N - M` positions before it.
+    #[inline(always)]
+    const fn recurrence(x0: u64, x1: u64, xm: u64) -> u64 {
+        let y = (x0 & Self::UPPER_MASK) | (x1 & Self::LOWER_MASK);
+        let mag = if y & 1 == 0 { 0 } else { Self::MATRIX_A };
+        xm ^ (y >> 1) ^ mag
+    }
+
+    /// Generates the next `N` words of the state.
+    const fn twist(&mut self) {
+        let mut k = 0;
+        while k < Self::N {
+            let (k1, km) = ((k + 1) % Self::N, (k + Self::M) % Self::N);
+            self.mt[k] = Self::recurrence(self.mt[k], self.mt[k1], self.mt[km]);
+            k += 1;
+        }
+    }
+
+    /// The tempering transformation of each output word.
+    #[inline(always)]
+    const fn temper(mut y: u64) -> u64 {
+        y ^= (y >> 29) & 0x5555_5555_5555_5555;
+        y ^= (y << 17) & 0x71D6_7FFF_EDA6_0000;
+        y ^= (y << 37) & 0xFFF7_EEE0_0000_0000;
+        y ^ (y >> 43)
+    }
+
+    /// Advances the generator with the given jump polynomial.
+    ///
+    /// The state array always holds a whole block of the sequence, so the
+    /// polynomial is applied to it, and the index stays the same.
+    const fn jump_with(&mut self, poly: &[u64; 312]) {
+        let (mut state, mut ring, mut pos) = ([0; 312], self.mt, 0);
+        let mut i = 0;
+        while i < 19937 {
+            if poly[i / 64] & (1 << (i % 64)) != 0 {
+                let mut k = 0;
+                while k < Self::N {
+                    state[k] ^= ring[(pos + k) % Self::N];
+                    k += 1;
+                }
+            }
+            let (k1, km) = ((pos + 1) % Self::N, (pos + Self::M) % Self::N);
+            ring[pos] = Self::recurrence(ring[pos], ring[k1], ring[km]);
+            pos = k1;
+            i += 1;
+        }
+        self.mt = state;
+    }
+}
+
+impl Mt19937_64 {
+    /// Returns a seeded `MT19937-64` generator from the given 64-bit seed.
+    ///
+    /// This is the `init_genrand64` function of the reference implementation.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self::from_init(Self::init_genrand(seed))
+    }
+
+    /// Returns a seeded `MT19937-64` generator from the given array of 64-bit
+    /// words.
+    ///
+    /// This is the `init_by_array64` function of the reference implementation.
+    ///
+    /// Returns `None` if the array is empty.
+    #[must_use]
+    pub const fn new_by_array(key: &[u64]) -> Option<Self> {
+        let len = key.len();
+        if len == 0 {
+            return Self::cold_path_result();
+        }
+        let mut mt = Self::init_genrand(19_650_218);
+        let (mut i, mut j) = (1, 0);
+        let mut k = if Self::N > len { Self::N } else { len };
+        while k > 0 {
+            let prev = mt[i - 1] ^ (mt[i - 1] >> 62);
+            mt[i] = (mt[i] ^ prev.wrapping_mul(3_935_559_000_370_003_845))
+                .wrapping_add(key[j])
+                .wrapping_add(j as u64);
+            i += 1;
+            j += 1;
+            if i >= Self::N {
+                mt[0] = mt[Self::N - 1];
+                i = 1;
+            }
+            if j >= len {
+                j = 0;
+            }
+            k -= 1;
+        }
+        k = Self::N - 1;
+        while k > 0 {
+            let prev = mt[i - 1] ^ (mt[i - 1] >> 62);
+            mt[i] = (mt[i] ^ prev.wrapping_mul(2_862_933_555_777_941_757)).wrapping_sub(i as u64);
+            i += 1;
+            if i >= Self::N {
+                mt[0] = mt[Self::N - 1];
+                i = 1;
+            }
+            k -= 1;
+        }
+        // the most significant bit is set, so that the state is never zero
+        mt[0] = 1 << 63;
+        Some(Self::from_init(mt))
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        Self::temper(self.mt[self.index])
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.current_u64();
+        self.index += 1;
+        if self.index == Self::N {
+            self.twist();
+            self.index = 0;
+        }
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut new = self.copy();
+        new.discard(1);
+        new
+    }
+
+    /// Advances the generator `n` steps, as if calling
+    /// [`next_u64`][Self::next_u64] that many times.
+    ///
+    /// This is the `discard` method of `std::mt19937_64` in C++.
+    pub const fn discard(&mut self, mut n: u64) {
+        while n > 0 {
+            let left = (Self::N - self.index) as u64;
+            if n < left {
+                self.index += n as usize;
+                return;
+            }
+            n -= left;
+            self.twist();
+            self.index = 0;
+        }
+    }
+
+    /// Advances the generator `2^128` steps, as if calling
+    /// [`next_u64`][Self::next_u64] that many times.
+    ///
+    /// It can be used to generate `2^19809` non-overlapping
+    /// subsequences for parallel computations.
+    ///
+    /// It's much slower than the jumps of smaller generators, since it steps
+    /// the generator 19937 times and adds up about half of those states.
+    pub const fn jump(&mut self) {
+        self.jump_with(&MT19937_64_JUMP);
+    }
+}
+
+/// # Extra constructors
+impl Mt19937_64 {
+    /// Returns a seeded `MT19937-64` generator from the given 64-bit seed.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new1_u64(seed: u64) -> Self {
+        Self::new(seed)
+    }
+
+    /// Returns a seeded `MT19937-64` generator from the given 2 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new2_u32(seeds: [u32; 2]) -> Self {
+        Self::new(u64_from_u32_le(seeds))
+    }
+
+    /// Returns a seeded `MT19937-64` generator from the given 4 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u16(seeds: [u16; 4]) -> Self {
+        Self::new(u64_from_u16_le(seeds))
+    }
+
+    /// Returns a seeded `MT19937-64` generator from the given 8 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u8(seeds: [u8; 8]) -> Self {
+        Self::new(u64_from_u8_le(seeds))
+    }
+
+    /// Returns a seeded `MT19937-64` generator from the given 64-bit seed.
+    ///
+    /// The seed is used as a key array of 1 word for
+    /// [`new_by_array`][Self::new_by_array].
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        match Self::new_by_array(&[seed]) {
+            Some(new) => new,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl_prng![Mt19937_64: u64, current_u64, next_u64];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::Mt19937_64;
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![Mt19937_64];
+
+    impl SeedableRng for Mt19937_64 {
+        type Seed = [u8; 8];
+
+        fn from_seed(seed: Self::Seed) -> Self {
+            Self::new(u64::from_le_bytes(seed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The expected values are from the reference implementation at
+    // http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/emt64.html
+
+    #[test]
+    fn init_genrand64() {
+        let mut rng = Mt19937_64::new(5489);
+        for expected in [
+            14514284786278117030,
+            4620546740167642908,
+            13109570281517897720,
+            17462938647148434322,
+            355488278567739596,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+        // the 10000th output of the C++ default-constructed `std::mt19937_64`
+        rng.discard(10000 - 5 - 1);
+        assert_eq![rng.next_u64(), 9981545732273789042];
+    }
+
+    #[test]
+    fn init_by_array64() {
+        let mut rng = Mt19937_64::new_by_array(&[0x12345, 0x23456, 0x34567, 0x45678]).unwrap();
+        for expected in [
+            7266447313870364031,
+            4946485549665804864,
+            16945909448695747420,
+            16394063075524226720,
+            4873882236456199058,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+        assert![Mt19937_64::new_by_array(&[]).is_none()];
+    }
+
+    #[test]
+    fn jump_with() {
+        // the jump polynomial of a distance under 19937 is just `x^n`
+        let mut a = Mt19937_64::new(7);
+        a.discard(100);
+        let mut b = a.clone();
+        let mut poly = [0; 312];
+        poly[3000 / 64] = 1 << (3000 % 64);
+        a.jump_with(&poly);
+        b.discard(3000);
+        for _ in 0..1000 {
+            assert_eq![a.next_u64(), b.next_u64()];
+        }
+    }
+
+    #[test]
+    fn jump() {
+        // computed independently, by summing the states of the reference
+        // implementation selected by the coefficients of `x^(2^128)` modulo
+        // the minimal polynomial of its sequence, found by Berlekamp-Massey
+        let expected = [
+            16532021385579938789,
+            10463566405617668023,
+            8335637642163205872,
+            7799536607934257481,
+            2077261891999231362,
+        ];
+        let mut rng = Mt19937_64::new(5489);
+        rng.jump();
+        for expected in expected {
+            assert_eq![rng.next_u64(), expected];
+        }
+        // the position within the block is kept
+        let mut rng = Mt19937_64::new(5489);
+        rng.discard(2);
+        rng.jump();
+        assert_eq![rng.next_u64(), expected[2]];
+    }
+}
@@ -0,0 +1,72 @@
+// alazar::pcg
+//
+//! Pseudo-random number generators of the [PCG] family.
+//!
+//! This module defines several types:
+//! - a 64-bit state generator of 32-bit numbers: ([`Pcg32`]).
+//! - 128-bit state generators of 64-bit numbers: ([`Pcg64`], [`Pcg64Dxsm`]).
+//!
+//! They're linear congruential generators with a permuted output, and can be
+//! seeded in one of 2^63 or 2^127 different streams. They can jump ahead or
+//! back any number of steps in logarithmic time, and compute the distance
+//! between two states of the same stream.
+//!
+//! Their outputs match the reference implementations.
+//!
+//! [PCG]: https://www.pcg-random.org/
+//
+
+mod u128;
+mod u64;
+
+pub use u128::{Pcg64, Pcg64Dxsm};
+pub use u64::Pcg32;
+
+macro_rules! impl_lcg_jump {
+    ($($t:ty: $advance:ident, $distance:ident);+ $(;)?) => { $(
+        /// Returns the state of an LCG with the given multiplier and increment
+        /// after `delta` steps, in *O(log delta)*.
+        //
+        // Forrest B. Brown, "Random Number Generation with Arbitrary Strides" (1994).
+        #[must_use]
+        pub(crate) const fn $advance(state: $t, mult: $t, inc: $t, mut delta: $t) -> $t {
+            let (mut acc_mult, mut acc_plus): ($t, $t) = (1, 0);
+            let (mut cur_mult, mut cur_plus) = (mult, inc);
+            while delta > 0 {
+                if delta & 1 != 0 {
+                    acc_mult = acc_mult.wrapping_mul(cur_mult);
+                    acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+                }
+                cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+                cur_mult = cur_mult.wrapping_mul(cur_mult);
+                delta >>= 1;
+            }
+            acc_mult.wrapping_mul(state).wrapping_add(acc_plus)
+        }
+
+        /// Returns the number of steps of an LCG with the given multiplier and
+        /// odd increment from the state `from` to the state `to`, in *O(bits)*.
+        ///
+        /// It finds the bits of the distance from the lowest one, which
+        /// only depends on the lowest bits of the states.
+        #[must_use]
+        pub(crate) const fn $distance(mut from: $t, to: $t, mult: $t, inc: $t) -> $t {
+            let (mut cur_mult, mut cur_plus) = (mult, inc);
+            let (mut bit, mut distance): ($t, $t) = (1, 0);
+            while from != to {
+                if (from & bit) != (to & bit) {
+                    from = from.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+                    distance |= bit;
+                }
+                bit <<= 1;
+                cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+                cur_mult = cur_mult.wrapping_mul(cur_mult);
+            }
+            distance
+        }
+    )+ };
+}
+impl_lcg_jump![
+    u64: lcg_advance_u64, lcg_distance_u64;
+    u128: lcg_advance_u128, lcg_distance_u128;
+];
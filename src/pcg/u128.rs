@@ -0,0 +1,581 @@
+// alazar::pcg::u128
+//
+//! PCG generators with a 128-bit state.
+//
+
+use super::{lcg_advance_u128, lcg_distance_u128};
+use crate::{misc::SplitMix64, prng::impl_prng};
+use devela::convert::{u128_from_u16_le, u128_from_u32_le, u128_from_u64_le, u128_from_u8_le};
+
+/// The `PCG64` pseudo-random number generator (PCG XSL RR 128/64).
+///
+/// It has a 128-bit state and a 128-bit stream increment,
+/// and generates 64-bit numbers.
+///
+/// It's a linear congruential generator whose 128-bit state goes through a
+/// xorshift of the high half into the low half and a random rotation to
+/// produce each output. Its period is `2^128` in each of the `2^127` streams.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pcg64 {
+    state: u128,
+    increment: u128,
+}
+
+impl Default for Pcg64 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED[0], Self::DEFAULT_SEED[1])
+    }
+}
+
+// private associated items
+impl Pcg64 {
+    const DEFAULT_SEED: [u128; 2] = [
+        0xCAFE_F00D_D15E_A5E5,
+        0x0A02_BDBF_7BB3_C0A7_AC28_FA16_A64A_BF96,
+    ];
+    const MULTIPLIER: u128 = 0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645;
+
+    /// Returns a generator from an initial state and odd increment,
+    /// mixing the state as the reference implementation does.
+    #[inline]
+    const fn from_state_incr(state: u128, increment: u128) -> Self {
+        Self::new_unchecked(state.wrapping_add(increment), increment).next_new()
+    }
+
+    /// The XSL RR output function.
+    #[inline(always)]
+    const fn output(state: u128) -> u64 {
+        let rot = (state >> 122) as u32;
+        let xsl = ((state >> 64) as u64) ^ (state as u64);
+        xsl.rotate_right(rot)
+    }
+}
+
+impl Pcg64 {
+    /// Returns a seeded `Pcg64` generator from the given 128-bit `state`
+    /// and the 128-bit `stream` selector.
+    ///
+    /// The highest bit of `stream` is discarded, since the increment
+    /// of the stream must be odd.
+    #[inline]
+    #[must_use]
+    pub const fn new(state: u128, stream: u128) -> Self {
+        Self::from_state_incr(state, (stream << 1) | 1)
+    }
+
+    /// Returns a `Pcg64` generator with the given exact internal `state`
+    /// and `increment`, unchecked.
+    ///
+    /// The increment must be odd, otherwise the period will be shorter.
+    ///
+    /// # Panics
+    /// Panics in debug if the increment is even.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(state: u128, increment: u128) -> Self {
+        debug_assert![increment & 1 == 1, "Increment must be odd"];
+        Self { state, increment }
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        Self::output(self.state)
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+        Self::output(self.state)
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+        Self {
+            state,
+            increment: self.increment,
+        }
+    }
+
+    /// Advances the generator `delta` steps in *O(log delta)*,
+    /// as if calling [`next_u64`][Self::next_u64] `delta` times.
+    ///
+    /// Since the period is `2^128`, advancing by `delta.wrapping_neg()`
+    /// goes back `delta` steps.
+    #[inline]
+    pub const fn advance(&mut self, delta: u128) {
+        self.state = lcg_advance_u128(self.state, Self::MULTIPLIER, self.increment, delta);
+    }
+
+    /// Returns the number of steps from this generator to `other`,
+    /// or `None` if they're in different streams.
+    ///
+    /// Advancing this generator by the distance makes it equal to `other`.
+    #[inline]
+    #[must_use]
+    pub const fn distance(&self, other: &Self) -> Option<u128> {
+        if self.increment == other.increment {
+            Some(lcg_distance_u128(
+                self.state,
+                other.state,
+                Self::MULTIPLIER,
+                self.increment,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// # Extra constructors
+impl Pcg64 {
+    /// Returns a seeded `Pcg64` generator from the given 2 × 128-bit seeds,
+    /// the state and stream.
+    #[inline]
+    pub const fn new2_u128(seeds: [u128; 2]) -> Self {
+        Self::new(seeds[0], seeds[1])
+    }
+
+    /// Returns a seeded `Pcg64` generator from the given 4 × 64-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order, into the state and stream.
+    #[inline]
+    pub const fn new4_u64(seeds: [u64; 4]) -> Self {
+        Self::new(
+            u128_from_u64_le([seeds[0], seeds[1]]),
+            u128_from_u64_le([seeds[2], seeds[3]]),
+        )
+    }
+
+    /// Returns a seeded `Pcg64` generator from the given 8 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order, into the state and stream.
+    #[inline]
+    pub const fn new8_u32(seeds: [u32; 8]) -> Self {
+        let s = seeds;
+        Self::new(
+            u128_from_u32_le([s[0], s[1], s[2], s[3]]),
+            u128_from_u32_le([s[4], s[5], s[6], s[7]]),
+        )
+    }
+
+    /// Returns a seeded `Pcg64` generator from the given 16 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order, into the state and stream.
+    #[inline]
+    pub const fn new16_u16(seeds: [u16; 16]) -> Self {
+        let s = seeds;
+        Self::new(
+            u128_from_u16_le([s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]]),
+            u128_from_u16_le([s[8], s[9], s[10], s[11], s[12], s[13], s[14], s[15]]),
+        )
+    }
+
+    /// Returns a seeded `Pcg64` generator from the given 32 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order, into the state and stream.
+    #[inline]
+    pub const fn new32_u8(seeds: [u8; 32]) -> Self {
+        let s = seeds;
+        Self::new(
+            u128_from_u8_le([
+                s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7], s[8], s[9], s[10], s[11], s[12],
+                s[13], s[14], s[15],
+            ]),
+            u128_from_u8_le([
+                s[16], s[17], s[18], s[19], s[20], s[21], s[22], s[23], s[24], s[25], s[26], s[27],
+                s[28], s[29], s[30], s[31],
+            ]),
+        )
+    }
+
+    /// Returns a seeded `Pcg64` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new4_u64(SplitMix64::seed_u64s(seed))
+    }
+}
+
+/// The `PCG64-DXSM` pseudo-random number generator (PCG CM DXSM 128/64).
+///
+/// It has a 128-bit state and a 128-bit stream increment,
+/// and generates 64-bit numbers.
+///
+/// It's a linear congruential generator with a cheap 64-bit multiplier,
+/// whose 128-bit state goes through a double xorshift multiply to produce
+/// each output. It has better statistical quality than [`Pcg64`], and its
+/// streams are less correlated. It's the default generator of *NumPy*.
+/// Its period is `2^128` in each of the `2^127` streams.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pcg64Dxsm {
+    state: u128,
+    increment: u128,
+}
+
+impl Default for Pcg64Dxsm {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED[0], Self::DEFAULT_SEED[1])
+    }
+}
+
+// private associated items
+impl Pcg64Dxsm {
+    const DEFAULT_SEED: [u128; 2] = [
+        0xCAFE_F00D_D15E_A5E5,
+        0x0A02_BDBF_7BB3_C0A7_AC28_FA16_A64A_BF96,
+    ];
+    // the 64-bit multiplier is used both by the LCG and the output function
+    const CHEAP_MULTIPLIER: u64 = 0xDA94_2042_E4DD_58B5;
+    const MULTIPLIER: u128 = Self::CHEAP_MULTIPLIER as u128;
+
+    /// Returns a generator from an initial state and odd increment,
+    /// mixing the state as the reference implementation does.
+    ///
+    /// The state is kept one step behind the reference's,
+    /// since each output is that of the state after a step.
+    #[inline]
+    const fn from_state_incr(state: u128, increment: u128) -> Self {
+        Self::new_unchecked(state.wrapping_add(increment), increment)
+    }
+
+    /// The DXSM output function.
+    #[inline(always)]
+    const fn output(state: u128) -> u64 {
+        let mut hi = (state >> 64) as u64;
+        let lo = state as u64 | 1;
+        hi ^= hi >> 32;
+        hi = hi.wrapping_mul(Self::CHEAP_MULTIPLIER);
+        hi ^= hi >> 48;
+        hi.wrapping_mul(lo)
+    }
+}
+
+impl Pcg64Dxsm {
+    /// Returns a seeded `Pcg64Dxsm` generator from the given 128-bit `state`
+    /// and the 128-bit `stream` selector.
+    ///
+    /// The highest bit of `stream` is discarded, since the increment
+    /// of the stream must be odd.
+    #[inline]
+    #[must_use]
+    pub const fn new(state: u128, stream: u128) -> Self {
+        Self::from_state_incr(state, (stream << 1) | 1)
+    }
+
+    /// Returns a `Pcg64Dxsm` generator with the given exact internal `state`
+    /// and `increment`, unchecked.
+    ///
+    /// The increment must be odd, otherwise the period will be shorter.
+    ///
+    /// # Panics
+    /// Panics in debug if the increment is even.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(state: u128, increment: u128) -> Self {
+        debug_assert![increment & 1 == 1, "Increment must be odd"];
+        Self { state, increment }
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        Self::output(self.state)
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+        Self::output(self.state)
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+        Self {
+            state,
+            increment: self.increment,
+        }
+    }
+
+    /// Advances the generator `delta` steps in *O(log delta)*,
+    /// as if calling [`next_u64`][Self::next_u64] `delta` times.
+    ///
+    /// Since the period is `2^128`, advancing by `delta.wrapping_neg()`
+    /// goes back `delta` steps.
+    #[inline]
+    pub const fn advance(&mut self, delta: u128) {
+        self.state = lcg_advance_u128(self.state, Self::MULTIPLIER, self.increment, delta);
+    }
+
+    /// Returns the number of steps from this generator to `other`,
+    /// or `None` if they're in different streams.
+    ///
+    /// Advancing this generator by the distance makes it equal to `other`.
+    #[inline]
+    #[must_use]
+    pub const fn distance(&self, other: &Self) -> Option<u128> {
+        if self.increment == other.increment {
+            Some(lcg_distance_u128(
+                self.state,
+                other.state,
+                Self::MULTIPLIER,
+                self.increment,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// # Extra constructors
+impl Pcg64Dxsm {
+    /// Returns a seeded `Pcg64Dxsm` generator from the given 2 × 128-bit seeds,
+    /// the state and stream.
+    #[inline]
+    pub const fn new2_u128(seeds: [u128; 2]) -> Self {
+        Self::new(seeds[0], seeds[1])
+    }
+
+    /// Returns a seeded `Pcg64Dxsm` generator from the given 4 × 64-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order, into the state and stream.
+    #[inline]
+    pub const fn new4_u64(seeds: [u64; 4]) -> Self {
+        Self::new(
+            u128_from_u64_le([seeds[0], seeds[1]]),
+            u128_from_u64_le([seeds[2], seeds[3]]),
+        )
+    }
+
+    /// Returns a seeded `Pcg64Dxsm` generator from the given 8 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order, into the state and stream.
+    #[inline]
+    pub const fn new8_u32(seeds: [u32; 8]) -> Self {
+        let s = seeds;
+        Self::new(
+            u128_from_u32_le([s[0], s[1], s[2], s[3]]),
+            u128_from_u32_le([s[4], s[5], s[6], s[7]]),
+        )
+    }
+
+    /// Returns a seeded `Pcg64Dxsm` generator from the given 16 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order, into the state and stream.
+    #[inline]
+    pub const fn new16_u16(seeds: [u16; 16]) -> Self {
+        let s = seeds;
+        Self::new(
+            u128_from_u16_le([s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]]),
+            u128_from_u16_le([s[8], s[9], s[10], s[11], s[12], s[13], s[14], s[15]]),
+        )
+    }
+
+    /// Returns a seeded `Pcg64Dxsm` generator from the given 32 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order, into the state and stream.
+    #[inline]
+    pub const fn new32_u8(seeds: [u8; 32]) -> Self {
+        let s = seeds;
+        Self::new(
+            u128_from_u8_le([
+                s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7], s[8], s[9], s[10], s[11], s[12],
+                s[13], s[14], s[15],
+            ]),
+            u128_from_u8_le([
+                s[16], s[17], s[18], s[19], s[20], s[21], s[22], s[23], s[24], s[25], s[26], s[27],
+                s[28], s[29], s[30], s[31],
+            ]),
+        )
+    }
+
+    /// Returns a seeded `Pcg64Dxsm` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new4_u64(SplitMix64::seed_u64s(seed))
+    }
+}
+
+impl_prng![Pcg64: u64, current_u64, next_u64];
+impl_prng![Pcg64Dxsm: u64, current_u64, next_u64];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::{Pcg64, Pcg64Dxsm};
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    /// Returns the state and increment from a seed, in little endian order.
+    fn state_incr_from_seed(seed: [u8; 32]) -> [u128; 2] {
+        let mut seed_u128s = [0u128; 2];
+        for i in 0..2 {
+            let mut bytes = [0; 16];
+            bytes.copy_from_slice(&seed[i * 16..(i + 1) * 16]);
+            seed_u128s[i] = u128::from_le_bytes(bytes);
+        }
+        seed_u128s
+    }
+
+    impl_rand_core![Pcg64];
+
+    impl SeedableRng for Pcg64 {
+        type Seed = [u8; 32];
+
+        /// The seed is read as the state and the increment in little endian
+        /// order, discarding the lowest bit of the increment, like `rand_pcg`.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let [state, increment] = state_incr_from_seed(seed);
+            Self::from_state_incr(state, increment | 1)
+        }
+    }
+
+    impl_rand_core![Pcg64Dxsm];
+
+    impl SeedableRng for Pcg64Dxsm {
+        type Seed = [u8; 32];
+
+        /// The seed is read as the state and the increment in little endian
+        /// order, discarding the lowest bit of the increment, like `rand_pcg`.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let [state, increment] = state_incr_from_seed(seed);
+            Self::from_state_incr(state, increment | 1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The state and stream that numpy's `SeedSequence(42)` produces,
+    // for the `PCG64(42)` and `PCG64DXSM(42)` bit generators.
+    const NUMPY_STATE: u128 = 0x9F1E_2E6D_CD54_0AB7_D578_73DC_79FB_94B6;
+    const NUMPY_STREAM: u128 = 0x7D28_2A1B_64D4_20B7_3365_7971_4692_D5FF;
+
+    #[test]
+    fn pcg64_reference() {
+        // from the `pcg64-global-demo` of the reference implementation at
+        // https://github.com/imneme/pcg-c
+        let mut rng = Pcg64::new(42, 54);
+        for expected in [
+            0x86B1_DA1D_7206_2B68,
+            0x1304_AA46_C985_3D39,
+            0xA367_0E9E_0DD5_0358,
+            0xF909_0E52_9A7D_AE00,
+            0xC85B_9FD8_3799_6F2C,
+            0x6061_21F8_E391_9196,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+        // from numpy, whose first `random()` double is 0.7739560485559633
+        let mut rng = Pcg64::new(NUMPY_STATE, NUMPY_STREAM);
+        for expected in [
+            0xC621_FBCD_16D9_2688,
+            0x705A_5661_A791_FFC1,
+            0xDBCD_12C2_6EDA_1624,
+            0xB286_B60E_1600_888D,
+            0x181C_01B5_3393_81EB,
+            0xF9C2_62ED_86C7_538C,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn pcg64dxsm_reference() {
+        // from the `pcg64_c_dxsm` engine (`cm_setseq_dxsm_128_64`)
+        // of the reference implementation at https://github.com/imneme/pcg-cpp
+        let mut rng = Pcg64Dxsm::new(42, 54);
+        for expected in [
+            17331114245835578256,
+            10267467544499227306,
+            9726600296081716989,
+            10165951391103677450,
+            12131334649314727261,
+            10134094537930450875,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+        // from numpy
+        let mut rng = Pcg64Dxsm::new(NUMPY_STATE, NUMPY_STREAM);
+        for expected in [
+            0xEBC6_3CFB_40B9_24F9,
+            0xDE91_FD3B_D2F5_A33C,
+            0x5B27_488E_83EC_7248,
+            0x97DF_D399_DE82_8D2D,
+            0xA5E2_81AD_058F_15E3,
+            0x3375_ED3E_1796_204B,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn current() {
+        let (mut a, mut b) = (Pcg64::new(42, 54), Pcg64Dxsm::new(42, 54));
+        for _ in 0..4 {
+            let next = a.next_u64();
+            assert_eq![a.current_u64(), next];
+            let next = b.next_u64();
+            assert_eq![b.current_u64(), next];
+        }
+    }
+
+    #[test]
+    fn advance_distance() {
+        let (rng64, dxsm) = (Pcg64::from_u64_seed(7), Pcg64Dxsm::from_u64_seed(7));
+        for n in [0, 1, 2, 127, 1000] {
+            let (mut a, mut b) = (rng64, rng64);
+            for _ in 0..n {
+                let _ = a.next_u64();
+            }
+            b.advance(n);
+            assert_eq![a, b];
+            assert_eq![rng64.distance(&b), Some(n)];
+            b.advance(n.wrapping_neg());
+            assert_eq![b, rng64];
+
+            let (mut a, mut b) = (dxsm, dxsm);
+            for _ in 0..n {
+                let _ = a.next_u64();
+            }
+            b.advance(n);
+            assert_eq![a, b];
+            assert_eq![dxsm.distance(&b), Some(n)];
+            b.advance(n.wrapping_neg());
+            assert_eq![b, dxsm];
+        }
+        assert_eq![rng64.distance(&Pcg64::from_u64_seed(8)), None];
+        assert_eq![dxsm.distance(&Pcg64Dxsm::from_u64_seed(8)), None];
+    }
+}
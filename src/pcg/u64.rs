@@ -0,0 +1,285 @@
+// alazar::pcg::u64
+//
+//! PCG generators with a 64-bit state.
+//
+
+use super::{lcg_advance_u64, lcg_distance_u64};
+use crate::{misc::SplitMix64, prng::impl_prng};
+use devela::convert::{u128_into_u64_le, u64_from_u16_le, u64_from_u32_le, u64_from_u8_le};
+
+/// The `PCG32` pseudo-random number generator (PCG XSH RR 64/32).
+///
+/// It has a 64-bit state and a 64-bit stream increment,
+/// and generates 32-bit numbers.
+///
+/// It's a linear congruential generator whose 64-bit state goes through a
+/// xorshift of the high bits and a random rotation to produce each output.
+/// Its period is `2^64` in each of the `2^63` streams.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+impl Default for Pcg32 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED[0], Self::DEFAULT_SEED[1])
+    }
+}
+
+// private associated items
+impl Pcg32 {
+    const DEFAULT_SEED: [u64; 2] = [0xCAFE_F00D_D15E_A5E5, 0x0A02_BDBF_7BB3_C0A7];
+    const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+    /// Returns a generator from an initial state and odd increment,
+    /// mixing the state as the reference implementation does.
+    ///
+    /// The state is kept one step behind the reference's,
+    /// since each output is that of the state after a step.
+    #[inline]
+    const fn from_state_incr(state: u64, increment: u64) -> Self {
+        Self::new_unchecked(state.wrapping_add(increment), increment)
+    }
+
+    /// The XSH RR output function.
+    #[inline(always)]
+    const fn output(state: u64) -> u32 {
+        let rot = (state >> 59) as u32;
+        let xsh = (((state >> 18) ^ state) >> 27) as u32;
+        xsh.rotate_right(rot)
+    }
+}
+
+impl Pcg32 {
+    /// Returns a seeded `Pcg32` generator from the given 64-bit `state`
+    /// and the 64-bit `stream` selector.
+    ///
+    /// The highest bit of `stream` is discarded, since the increment
+    /// of the stream must be odd.
+    #[inline]
+    #[must_use]
+    pub const fn new(state: u64, stream: u64) -> Self {
+        Self::from_state_incr(state, (stream << 1) | 1)
+    }
+
+    /// Returns a `Pcg32` generator with the given exact internal `state`
+    /// and `increment`, unchecked.
+    ///
+    /// The increment must be odd, otherwise the period will be shorter.
+    ///
+    /// # Panics
+    /// Panics in debug if the increment is even.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(state: u64, increment: u64) -> Self {
+        debug_assert![increment & 1 == 1, "Increment must be odd"];
+        Self { state, increment }
+    }
+
+    /// Returns the current random `u32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u32(&self) -> u32 {
+        Self::output(self.state)
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        self.state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+        Self::output(self.state)
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+        Self {
+            state,
+            increment: self.increment,
+        }
+    }
+
+    /// Advances the generator `delta` steps in *O(log delta)*,
+    /// as if calling [`next_u32`][Self::next_u32] `delta` times.
+    ///
+    /// Since the period is `2^64`, advancing by `delta.wrapping_neg()`
+    /// goes back `delta` steps.
+    #[inline]
+    pub const fn advance(&mut self, delta: u64) {
+        self.state = lcg_advance_u64(self.state, Self::MULTIPLIER, self.increment, delta);
+    }
+
+    /// Returns the number of steps from this generator to `other`,
+    /// or `None` if they're in different streams.
+    ///
+    /// Advancing this generator by the distance makes it equal to `other`.
+    #[inline]
+    #[must_use]
+    pub const fn distance(&self, other: &Self) -> Option<u64> {
+        if self.increment == other.increment {
+            Some(lcg_distance_u64(
+                self.state,
+                other.state,
+                Self::MULTIPLIER,
+                self.increment,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// # Extra constructors
+impl Pcg32 {
+    /// Returns a seeded `Pcg32` generator from the given 128-bit seed.
+    ///
+    /// The seed will be split in little endian order, into the state and stream.
+    #[inline]
+    pub const fn new1_u128(seed: u128) -> Self {
+        let [state, stream] = u128_into_u64_le(seed);
+        Self::new(state, stream)
+    }
+
+    /// Returns a seeded `Pcg32` generator from the given 2 × 64-bit seeds,
+    /// the state and stream.
+    #[inline]
+    pub const fn new2_u64(seeds: [u64; 2]) -> Self {
+        Self::new(seeds[0], seeds[1])
+    }
+
+    /// Returns a seeded `Pcg32` generator from the given 4 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order, into the state and stream.
+    #[inline]
+    pub const fn new4_u32(seeds: [u32; 4]) -> Self {
+        Self::new(
+            u64_from_u32_le([seeds[0], seeds[1]]),
+            u64_from_u32_le([seeds[2], seeds[3]]),
+        )
+    }
+
+    /// Returns a seeded `Pcg32` generator from the given 8 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order, into the state and stream.
+    #[inline]
+    pub const fn new8_u16(seeds: [u16; 8]) -> Self {
+        Self::new(
+            u64_from_u16_le([seeds[0], seeds[1], seeds[2], seeds[3]]),
+            u64_from_u16_le([seeds[4], seeds[5], seeds[6], seeds[7]]),
+        )
+    }
+
+    /// Returns a seeded `Pcg32` generator from the given 16 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order, into the state and stream.
+    #[inline]
+    pub const fn new16_u8(seeds: [u8; 16]) -> Self {
+        let s = seeds;
+        Self::new(
+            u64_from_u8_le([s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]]),
+            u64_from_u8_le([s[8], s[9], s[10], s[11], s[12], s[13], s[14], s[15]]),
+        )
+    }
+
+    /// Returns a seeded `Pcg32` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        let [state, stream] = SplitMix64::seed_u64s(seed);
+        Self::new(state, stream)
+    }
+}
+
+impl_prng![Pcg32: u32, current_u32, next_u32];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::Pcg32;
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    /// Returns the state and increment from a seed, in little endian order.
+    fn state_incr_from_seed(seed: [u8; 16]) -> [u64; 2] {
+        let mut seed_u64s = [0u64; 2];
+        for i in 0..2 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&seed[i * 8..(i + 1) * 8]);
+            seed_u64s[i] = u64::from_le_bytes(bytes);
+        }
+        seed_u64s
+    }
+
+    impl_rand_core![Pcg32];
+
+    impl SeedableRng for Pcg32 {
+        type Seed = [u8; 16];
+
+        /// The seed is read as the state and the increment in little endian
+        /// order, discarding the lowest bit of the increment, like `rand_pcg`.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let [state, increment] = state_incr_from_seed(seed);
+            Self::from_state_incr(state, increment | 1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        // from the `pcg32-demo` of the reference implementation at
+        // https://github.com/imneme/pcg-c-basic
+        let mut rng = Pcg32::new(42, 54);
+        for expected in [
+            0xA15C_02B7,
+            0x7B47_F409,
+            0xBA1D_3330,
+            0x83D2_F293,
+            0xBFA4_784B,
+            0xCBED_606E,
+        ] {
+            assert_eq![rng.next_u32(), expected];
+        }
+    }
+
+    #[test]
+    fn current() {
+        let mut rng = Pcg32::new(42, 54);
+        for _ in 0..4 {
+            let next = rng.next_u32();
+            assert_eq![rng.current_u32(), next];
+        }
+    }
+
+    #[test]
+    fn advance_distance() {
+        let rng = Pcg32::from_u64_seed(7);
+        for n in [0, 1, 2, 63, 1000] {
+            let (mut a, mut b) = (rng, rng);
+            for _ in 0..n {
+                let _ = a.next_u32();
+            }
+            b.advance(n);
+            assert_eq![a, b];
+            assert_eq![rng.distance(&b), Some(n)];
+            b.advance(n.wrapping_neg());
+            assert_eq![b, rng];
+        }
+        assert_eq![rng.distance(&Pcg32::from_u64_seed(8)), None];
+    }
+}
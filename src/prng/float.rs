@@ -0,0 +1,191 @@
+// alazar::prng::float
+//
+//! Floating-point generation in the unit interval.
+//
+// Every value is built with integer operations, exact integer to float
+// conversions and multiplications by exact powers of two, so the result
+// is bit-for-bit identical on every platform.
+
+use super::{range::uniform_u32, range::uniform_u64, Prng};
+
+macro_rules! impl_float {
+    (
+        $f:ty, $u:ty, $next:ident, $uniform:ident,
+        mantissa: $mbits:literal, bias: $bias:literal,
+        fns: $co:ident, $oc:ident, $oo:ident, $cc:ident, $full:ident
+    ) => {
+        /// Returns a value in `[0, 1)`, a multiple of `2^-(mantissa+1)`.
+        #[inline]
+        pub(crate) fn $co<P: Prng>(prng: &mut P) -> $f {
+            const SHIFT: u32 = <$u>::BITS - ($mbits + 1);
+            const SCALE: $f = 1.0 / (1_u64 << ($mbits + 1)) as $f;
+            (prng.$next() >> SHIFT) as $f * SCALE
+        }
+
+        /// Returns a value in `(0, 1]`, a multiple of `2^-(mantissa+1)`.
+        #[inline]
+        pub(crate) fn $oc<P: Prng>(prng: &mut P) -> $f {
+            const SHIFT: u32 = <$u>::BITS - ($mbits + 1);
+            const SCALE: $f = 1.0 / (1_u64 << ($mbits + 1)) as $f;
+            ((prng.$next() >> SHIFT) + 1) as $f * SCALE
+        }
+
+        /// Returns a value in `(0, 1)`, an odd multiple of `2^-(mantissa+1)`.
+        #[inline]
+        pub(crate) fn $oo<P: Prng>(prng: &mut P) -> $f {
+            const SHIFT: u32 = <$u>::BITS - ($mbits + 1);
+            const SCALE: $f = 1.0 / (1_u64 << ($mbits + 1)) as $f;
+            ((prng.$next() >> SHIFT) | 1) as $f * SCALE
+        }
+
+        /// Returns a value in `[0, 1]`, a multiple of `2^-(mantissa+1)`.
+        #[inline]
+        pub(crate) fn $cc<P: Prng>(prng: &mut P) -> $f {
+            const SPAN: $u = (1 << ($mbits + 1)) + 1;
+            const SCALE: $f = 1.0 / (1_u64 << ($mbits + 1)) as $f;
+            $uniform(prng, SPAN) as $f * SCALE
+        }
+
+        /// Returns any representable value in `[0, 1)`, with a probability
+        /// proportional to the width of the real interval that rounds to it.
+        //
+        // Allen B. Downey, "Generating Pseudo-random Floating-Point Values" (2007).
+        #[inline]
+        pub(crate) fn $full<P: Prng>(prng: &mut P) -> $f {
+            const MIN_EXP: i32 = 1 - $bias;
+            const SHIFT: u32 = <$u>::BITS - $mbits;
+            'retry: loop {
+                // the exponent of the binade [2^-1, 2^0), lowered by one
+                // for every leading zero of a geometric random draw
+                let mut exp: i32 = -1;
+                let mut bits = prng.$next();
+                while bits == 0 {
+                    exp -= <$u>::BITS as i32;
+                    if exp < MIN_EXP {
+                        break;
+                    }
+                    bits = prng.$next();
+                }
+                exp -= bits.leading_zeros() as i32;
+                if exp < MIN_EXP {
+                    // the subnormal values are evenly spaced
+                    return <$f>::from_bits(prng.$next() >> SHIFT);
+                }
+                let mantissa = prng.$next() >> SHIFT;
+                // a zero significand is shared with the binade above
+                if mantissa == 0 && prng.$next() & 1 == 1 {
+                    exp += 1;
+                    if exp == 0 {
+                        continue 'retry; // 1.0 is excluded
+                    }
+                }
+                return <$f>::from_bits((((exp + $bias) as $u) << $mbits) | mantissa);
+            }
+        }
+    };
+}
+impl_float![f32, u32, next_u32, uniform_u32, mantissa: 23, bias: 127,
+    fns: f32_co, f32_oc, f32_oo, f32_cc, f32_full];
+impl_float![f64, u64, next_u64, uniform_u64, mantissa: 52, bias: 1023,
+    fns: f64_co, f64_oc, f64_oo, f64_cc, f64_full];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A generator that returns `zeros` zero words, and then `word` forever.
+    #[derive(Clone, Copy)]
+    struct Stub {
+        zeros: usize,
+        word: u64,
+    }
+
+    impl Stub {
+        const fn constant(word: u64) -> Self {
+            Self { zeros: 0, word }
+        }
+    }
+
+    impl Prng for Stub {
+        type Native = u64;
+
+        fn next_native(&mut self) -> u64 {
+            if self.zeros > 0 {
+                self.zeros -= 1;
+                0
+            } else {
+                self.word
+            }
+        }
+        fn current(&self) -> u64 {
+            self.word
+        }
+        fn next_new(&self) -> Self {
+            *self
+        }
+    }
+
+    #[test]
+    fn extremes_f32() {
+        let (mut zero, mut max) = (Stub::constant(0), Stub::constant(u64::MAX));
+        assert_eq![zero.next_f32(), 0.0];
+        assert![max.next_f32() < 1.0];
+        assert![zero.next_f32_open_closed() > 0.0];
+        assert_eq![max.next_f32_open_closed(), 1.0];
+        assert![zero.next_f32_open() > 0.0];
+        assert![max.next_f32_open() < 1.0];
+        // a zero word is rejected by the unbiased sampling, but a one isn't
+        assert_eq![Stub::constant(1).next_f32_closed(), 0.0];
+        assert_eq![max.next_f32_closed(), 1.0];
+        assert_eq![zero.next_f32_full(), 0.0];
+        assert![max.next_f32_full() < 1.0];
+    }
+
+    #[test]
+    fn extremes_f64() {
+        let (mut zero, mut max) = (Stub::constant(0), Stub::constant(u64::MAX));
+        assert_eq![zero.next_f64(), 0.0];
+        assert![max.next_f64() < 1.0];
+        assert![zero.next_f64_open_closed() > 0.0];
+        assert_eq![max.next_f64_open_closed(), 1.0];
+        assert![zero.next_f64_open() > 0.0];
+        assert![max.next_f64_open() < 1.0];
+        // a zero word is rejected by the unbiased sampling, but a one isn't
+        assert_eq![Stub::constant(1).next_f64_closed(), 0.0];
+        assert_eq![max.next_f64_closed(), 1.0];
+        assert_eq![zero.next_f64_full(), 0.0];
+        assert![max.next_f64_full() < 1.0];
+    }
+
+    #[test]
+    fn full_subnormals() {
+        // each zero word lowers the exponent by the word width, until it's
+        // below the minimum, and the next word is the subnormal significand
+        let mut rng = Stub {
+            zeros: 4,
+            word: u64::MAX,
+        };
+        let x = rng.next_f32_full();
+        assert![x > 0.0 && x < f32::MIN_POSITIVE];
+        assert_eq![x, f32::from_bits(0x007F_FFFF)];
+        let mut rng = Stub {
+            zeros: 16,
+            word: u64::MAX,
+        };
+        let x = rng.next_f64_full();
+        assert![x > 0.0 && x < f64::MIN_POSITIVE];
+        assert_eq![x, f64::from_bits(0x000F_FFFF_FFFF_FFFF)];
+
+        // a zero word fewer keeps the exponent in the normal range
+        let mut rng = Stub {
+            zeros: 3,
+            word: u64::MAX,
+        };
+        assert![rng.next_f32_full() >= f32::MIN_POSITIVE];
+        let mut rng = Stub {
+            zeros: 15,
+            word: u64::MAX,
+        };
+        assert![rng.next_f64_full() >= f64::MIN_POSITIVE];
+    }
+}
@@ -0,0 +1,333 @@
+// alazar::prng
+//
+//! The common pseudo-random number generator trait.
+//
+
+mod float;
+mod range;
+mod word;
+
+pub(crate) use range::{uniform_u128, uniform_u64, uniform_usize};
+pub use {range::SampleRange, word::PrngWord};
+
+/// The common interface shared by every pseudo-random number generator.
+///
+/// Each generator produces values of a single native word type ([`Native`]).
+/// Every other output is derived from it in a defined way:
+/// - narrower outputs keep the least significant bits of one native word.
+/// - wider outputs join as many native words as needed in little endian order.
+/// - [`fill_bytes`] writes the little endian bytes of successive native words,
+///   discarding the unused bytes of the last one.
+///
+/// This makes the output of a given generator state identical on every platform.
+///
+/// [`Native`]: Prng::Native
+/// [`fill_bytes`]: Prng::fill_bytes
+pub trait Prng: Sized {
+    /// The native output word type.
+    type Native: PrngWord;
+
+    /// Returns the next random native word.
+    #[must_use]
+    fn next_native(&mut self) -> Self::Native;
+
+    /// Returns the current random native word.
+    #[must_use]
+    fn current(&self) -> Self::Native;
+
+    /// Returns a copy of the next new random state.
+    #[must_use]
+    fn next_new(&self) -> Self;
+
+    /// Returns the next random `u8`.
+    #[inline]
+    #[must_use]
+    fn next_u8(&mut self) -> u8 {
+        next_bits(self, 8) as u8
+    }
+
+    /// Returns the next random `u16`.
+    #[inline]
+    #[must_use]
+    fn next_u16(&mut self) -> u16 {
+        next_bits(self, 16) as u16
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    fn next_u32(&mut self) -> u32 {
+        next_bits(self, 32) as u32
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    fn next_u64(&mut self) -> u64 {
+        next_bits(self, 64)
+    }
+
+    /// Returns the next 2 × random `u64` combined as a single `u128`.
+    #[inline]
+    #[must_use]
+    fn next_u128(&mut self) -> u128 {
+        let lo = self.next_u64() as u128;
+        let hi = self.next_u64() as u128;
+        (hi << 64) | lo
+    }
+
+    /// Fills `dest` with the little endian bytes of successive native words.
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(Self::Native::BYTES) {
+            let bytes = self.next_native().to_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    /// Returns a uniformly sampled integer from the given `range`, without bias.
+    ///
+    /// See [`SampleRange`] for the supported types and ranges.
+    ///
+    /// # Panics
+    /// Panics if the range is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use alazar::{Prng, xorshift::XorShift8};
+    ///
+    /// let mut rng = XorShift8::default();
+    /// let die = rng.gen_range(1..=6_u8);
+    /// assert!((1..=6).contains(&die));
+    /// ```
+    #[inline]
+    #[must_use]
+    fn gen_range<T, R: SampleRange<T>>(&mut self, range: R) -> T {
+        range.sample(self)
+    }
+
+    /// Returns a random `f32` in the interval `[0, 1)`.
+    ///
+    /// The value is a multiple of `2^-24`, from the high bits of
+    /// [`next_u32`][Self::next_u32].
+    #[inline]
+    #[must_use]
+    fn next_f32(&mut self) -> f32 {
+        float::f32_co(self)
+    }
+
+    /// Returns a random `f32` in the interval `(0, 1]`.
+    ///
+    /// The value is a multiple of `2^-24`, from the high bits of
+    /// [`next_u32`][Self::next_u32].
+    #[inline]
+    #[must_use]
+    fn next_f32_open_closed(&mut self) -> f32 {
+        float::f32_oc(self)
+    }
+
+    /// Returns a random `f32` in the open interval `(0, 1)`.
+    ///
+    /// The value is an odd multiple of `2^-24`, from the high bits of
+    /// [`next_u32`][Self::next_u32]. It's never `0`, which makes it
+    /// suitable as the argument of a logarithm.
+    #[inline]
+    #[must_use]
+    fn next_f32_open(&mut self) -> f32 {
+        float::f32_oo(self)
+    }
+
+    /// Returns a random `f32` in the closed interval `[0, 1]`.
+    ///
+    /// The value is a multiple of `2^-24`, sampled without bias
+    /// from the `2^24 + 1` possible values.
+    #[inline]
+    #[must_use]
+    fn next_f32_closed(&mut self) -> f32 {
+        float::f32_cc(self)
+    }
+
+    /// Returns a random `f32` in the interval `[0, 1)`, with full precision.
+    ///
+    /// Every representable value in the interval can be returned, including
+    /// the subnormals, with a probability proportional to the width of the
+    /// real interval that rounds to it, following Downey's method.
+    ///
+    /// It consumes a variable number of [`next_u32`][Self::next_u32] values,
+    /// 2, rarely more.
+    #[inline]
+    #[must_use]
+    fn next_f32_full(&mut self) -> f32 {
+        float::f32_full(self)
+    }
+
+    /// Returns a random `f64` in the interval `[0, 1)`.
+    ///
+    /// The value is a multiple of `2^-53`, from the high bits of
+    /// [`next_u64`][Self::next_u64].
+    #[inline]
+    #[must_use]
+    fn next_f64(&mut self) -> f64 {
+        float::f64_co(self)
+    }
+
+    /// Returns a random `f64` in the interval `(0, 1]`.
+    ///
+    /// The value is a multiple of `2^-53`, from the high bits of
+    /// [`next_u64`][Self::next_u64].
+    #[inline]
+    #[must_use]
+    fn next_f64_open_closed(&mut self) -> f64 {
+        float::f64_oc(self)
+    }
+
+    /// Returns a random `f64` in the open interval `(0, 1)`.
+    ///
+    /// The value is an odd multiple of `2^-53`, from the high bits of
+    /// [`next_u64`][Self::next_u64]. It's never `0`, which makes it
+    /// suitable as the argument of a logarithm.
+    #[inline]
+    #[must_use]
+    fn next_f64_open(&mut self) -> f64 {
+        float::f64_oo(self)
+    }
+
+    /// Returns a random `f64` in the closed interval `[0, 1]`.
+    ///
+    /// The value is a multiple of `2^-53`, sampled without bias
+    /// from the `2^53 + 1` possible values.
+    #[inline]
+    #[must_use]
+    fn next_f64_closed(&mut self) -> f64 {
+        float::f64_cc(self)
+    }
+
+    /// Returns a random `f64` in the interval `[0, 1)`, with full precision.
+    ///
+    /// Every representable value in the interval can be returned, including
+    /// the subnormals, with a probability proportional to the width of the
+    /// real interval that rounds to it, following Downey's method.
+    ///
+    /// It consumes a variable number of [`next_u64`][Self::next_u64] values,
+    /// 2, rarely more.
+    #[inline]
+    #[must_use]
+    fn next_f64_full(&mut self) -> f64 {
+        float::f64_full(self)
+    }
+}
+
+/// Returns the next `bits` random bits in the least significant part of a `u64`.
+///
+/// Joins as many native words as needed, in little endian order.
+#[inline(always)]
+fn next_bits<P: Prng>(prng: &mut P, bits: u32) -> u64 {
+    let word_bits = P::Native::BITS;
+    if word_bits >= bits {
+        prng.next_native().to_u64()
+    } else {
+        let mut acc = 0;
+        let mut shift = 0;
+        while shift < bits {
+            acc |= prng.next_native().to_u64() << shift;
+            shift += word_bits;
+        }
+        acc
+    }
+}
+
+/// Implements [`Prng`] for a generator, from its inherent methods.
+///
+/// The generator must have the inherent methods `current_<native>`,
+/// `next_<native>` and `next_new`.
+macro_rules! impl_prng {
+    ($t:ty: $native:ty, $current:ident, $next:ident) => {
+        $crate::prng::impl_prng![@[] $t: $native, $current, $next];
+    };
+    (@[$($gen:tt)*] $t:ty: $native:ty, $current:ident, $next:ident) => {
+        impl<$($gen)*> $crate::prng::Prng for $t {
+            type Native = $native;
+
+            #[inline]
+            fn next_native(&mut self) -> $native {
+                <$t>::$next(self)
+            }
+            #[inline]
+            fn current(&self) -> $native {
+                <$t>::$current(self)
+            }
+            #[inline]
+            fn next_new(&self) -> Self {
+                <$t>::next_new(self)
+            }
+        }
+    };
+}
+pub(crate) use impl_prng;
+
+/// Implements `rand_core::RngCore` for a generator, from its [`Prng`] impl.
+#[cfg(feature = "rand_core")]
+macro_rules! impl_rand_core {
+    ($t:ty) => {
+        $crate::prng::impl_rand_core![@[] $t];
+    };
+    (@[$($gen:tt)*] $t:ty) => {
+        impl<$($gen)*> rand_core::RngCore for $t {
+            /// Returns the next random `u32`, as defined by
+            /// [`Prng::next_u32`][crate::Prng::next_u32].
+            fn next_u32(&mut self) -> u32 {
+                $crate::prng::Prng::next_u32(self)
+            }
+
+            /// Returns the next random `u64`, as defined by
+            /// [`Prng::next_u64`][crate::Prng::next_u64].
+            fn next_u64(&mut self) -> u64 {
+                $crate::prng::Prng::next_u64(self)
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                $crate::prng::Prng::fill_bytes(self, dest)
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+                $crate::prng::Prng::fill_bytes(self, dest);
+                Ok(())
+            }
+        }
+    };
+}
+#[cfg(feature = "rand_core")]
+pub(crate) use impl_rand_core;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xorshift::{XorShift32, XorShift64, XorShift8};
+
+    /// Checks that `fill_bytes` writes the concatenated bytes of successive
+    /// `word`s, and discards the unused bytes of the last one.
+    fn check_fill_bytes<P: Prng + Clone, const B: usize>(rng: P, word: fn(&mut P) -> [u8; B]) {
+        for len in [0, 1, 2, 3, 5, 7, 8, 9, 12, 13, 17, 31] {
+            let (mut filled, mut words) = (rng.clone(), rng.clone());
+            let mut bytes = [0; 32];
+            filled.fill_bytes(&mut bytes[..len]);
+            let mut expected = [0; 40];
+            for chunk in expected.chunks_mut(B).take(len.div_ceil(B)) {
+                chunk.copy_from_slice(&word(&mut words));
+            }
+            assert_eq![bytes[..len], expected[..len], "length {len}"];
+            assert_eq![filled.next_native().to_u64(), words.next_native().to_u64()];
+        }
+    }
+
+    #[test]
+    fn fill_bytes() {
+        check_fill_bytes(XorShift8::new(0x17).unwrap(), |rng| [rng.next_u8()]);
+        check_fill_bytes(XorShift32::new(0xDEFA_0017).unwrap(), |rng| {
+            rng.next_u32().to_le_bytes()
+        });
+        check_fill_bytes(XorShift64::new(0xDEFA_0017).unwrap(), |rng| {
+            rng.next_u64().to_le_bytes()
+        });
+    }
+}
@@ -0,0 +1,326 @@
+// alazar::prng::range
+//
+//! Unbiased bounded integer sampling.
+//
+// Every width uses Lemire's widening multiply with rejection, described in
+// "Fast Random Integer Generation in an Interval" (2019),
+// https://arxiv.org/abs/1805.10941
+
+use super::Prng;
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// A range of values that can be uniformly sampled by any [`Prng`].
+///
+/// It's implemented for every primitive integer type and every range kind:
+/// `a..b`, `a..=b`, `a..`, `..b`, `..=b` and the full range `..`.
+///
+/// The sampling is unbiased, and consumes only as many random bits as the
+/// width of the integer type requires, so that 8-bit generators can produce
+/// small bounded values without wider arithmetic. The `usize` and `isize`
+/// ranges are sampled with the narrowest width that fits the span, so the
+/// result doesn't depend on the target's pointer width.
+pub trait SampleRange<T> {
+    /// Returns a uniformly sampled value from the range.
+    ///
+    /// # Panics
+    /// Panics if the range is empty.
+    #[must_use]
+    fn sample<P: Prng>(self, prng: &mut P) -> T;
+
+    /// Returns `true` if the range contains no values.
+    #[must_use]
+    fn is_empty(&self) -> bool;
+}
+
+macro_rules! impl_uniform_unsigned {
+    ($($fn:ident: $t:ty, $wide:ty, $next:ident);+ $(;)?) => { $(
+        /// Returns a uniformly sampled value in `[0, span)`,
+        /// or a full random value if `span` is `0`.
+        #[inline]
+        pub(crate) fn $fn<P: Prng>(prng: &mut P, span: $t) -> $t {
+            if span == 0 {
+                return prng.$next();
+            }
+            let mut m = prng.$next() as $wide * span as $wide;
+            let mut low = m as $t;
+            if low < span {
+                let threshold = span.wrapping_neg() % span;
+                while low < threshold {
+                    m = prng.$next() as $wide * span as $wide;
+                    low = m as $t;
+                }
+            }
+            (m >> <$t>::BITS) as $t
+        }
+    )+ };
+}
+impl_uniform_unsigned![
+    uniform_u8: u8, u16, next_u8;
+    uniform_u16: u16, u32, next_u16;
+    uniform_u32: u32, u64, next_u32;
+    uniform_u64: u64, u128, next_u64;
+];
+
+/// Returns a uniformly sampled value in `[0, span)`,
+/// or a full random value if `span` is `0`.
+#[inline]
+pub(crate) fn uniform_u128<P: Prng>(prng: &mut P, span: u128) -> u128 {
+    if span == 0 {
+        return prng.next_u128();
+    }
+    let (mut hi, mut low) = widening_mul_u128(prng.next_u128(), span);
+    if low < span {
+        let threshold = span.wrapping_neg() % span;
+        while low < threshold {
+            (hi, low) = widening_mul_u128(prng.next_u128(), span);
+        }
+    }
+    hi
+}
+
+/// Returns the `(high, low)` halves of the full 256-bit product of `a` and `b`.
+#[inline]
+const fn widening_mul_u128(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let mid = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let high = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (mid >> 64);
+    let low = (mid << 64) | (lo_lo & MASK);
+    (high, low)
+}
+
+/// Returns a uniformly sampled value in `[0, span)`,
+/// or a full random value if `span` is `0`.
+///
+/// Uses the narrowest width that fits the span, independently of the
+/// target's pointer width.
+#[inline]
+pub(crate) fn uniform_usize<P: Prng>(prng: &mut P, span: usize) -> usize {
+    let span = span as u64;
+    if span == 0 {
+        prng.next_u64() as usize
+    } else if span <= 1 << 8 {
+        uniform_u8(prng, span as u8) as usize
+    } else if span <= 1 << 16 {
+        uniform_u16(prng, span as u16) as usize
+    } else if span <= 1 << 32 {
+        uniform_u32(prng, span as u32) as usize
+    } else {
+        uniform_u64(prng, span) as usize
+    }
+}
+
+macro_rules! impl_sample_range {
+    ($($t:ty, $u:ty, $fn:ident);+ $(;)?) => { $(
+        impl SampleRange<$t> for Range<$t> {
+            #[inline]
+            fn sample<P: Prng>(self, prng: &mut P) -> $t {
+                assert!(self.start < self.end, "cannot sample an empty range");
+                let span = (self.end as $u).wrapping_sub(self.start as $u);
+                self.start.wrapping_add($fn(prng, span) as $t)
+            }
+            #[inline]
+            fn is_empty(&self) -> bool {
+                self.start >= self.end
+            }
+        }
+        impl SampleRange<$t> for RangeInclusive<$t> {
+            #[inline]
+            fn sample<P: Prng>(self, prng: &mut P) -> $t {
+                let (start, end) = self.into_inner();
+                assert!(start <= end, "cannot sample an empty range");
+                let span = (end as $u).wrapping_sub(start as $u).wrapping_add(1);
+                start.wrapping_add($fn(prng, span) as $t)
+            }
+            #[inline]
+            fn is_empty(&self) -> bool {
+                RangeInclusive::is_empty(self)
+            }
+        }
+        impl SampleRange<$t> for RangeFrom<$t> {
+            #[inline]
+            fn sample<P: Prng>(self, prng: &mut P) -> $t {
+                (self.start..=<$t>::MAX).sample(prng)
+            }
+            #[inline]
+            fn is_empty(&self) -> bool {
+                false
+            }
+        }
+        impl SampleRange<$t> for RangeTo<$t> {
+            #[inline]
+            fn sample<P: Prng>(self, prng: &mut P) -> $t {
+                (<$t>::MIN..self.end).sample(prng)
+            }
+            #[inline]
+            fn is_empty(&self) -> bool {
+                self.end == <$t>::MIN
+            }
+        }
+        impl SampleRange<$t> for RangeToInclusive<$t> {
+            #[inline]
+            fn sample<P: Prng>(self, prng: &mut P) -> $t {
+                (<$t>::MIN..=self.end).sample(prng)
+            }
+            #[inline]
+            fn is_empty(&self) -> bool {
+                false
+            }
+        }
+        impl SampleRange<$t> for RangeFull {
+            #[inline]
+            fn sample<P: Prng>(self, prng: &mut P) -> $t {
+                $fn(prng, 0) as $t
+            }
+            #[inline]
+            fn is_empty(&self) -> bool {
+                false
+            }
+        }
+    )+ };
+}
+impl_sample_range![
+    u8, u8, uniform_u8;
+    u16, u16, uniform_u16;
+    u32, u32, uniform_u32;
+    u64, u64, uniform_u64;
+    u128, u128, uniform_u128;
+    usize, usize, uniform_usize;
+    i8, u8, uniform_u8;
+    i16, u16, uniform_u16;
+    i32, u32, uniform_u32;
+    i64, u64, uniform_u64;
+    i128, u128, uniform_u128;
+    isize, usize, uniform_usize;
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        misc::Xabc,
+        xorshift::{XorShift64, Xyza8a},
+    };
+
+    /// An 8-bit generator that panics if a wider word is requested from it.
+    struct Narrow<P>(P);
+
+    impl<P: Prng<Native = u8>> Prng for Narrow<P> {
+        type Native = u8;
+
+        fn next_native(&mut self) -> u8 {
+            self.0.next_native()
+        }
+        fn current(&self) -> u8 {
+            self.0.current()
+        }
+        fn next_new(&self) -> Self {
+            Self(self.0.next_new())
+        }
+        fn next_u16(&mut self) -> u16 {
+            panic!["unexpected 16-bit word"]
+        }
+        fn next_u32(&mut self) -> u32 {
+            panic!["unexpected 32-bit word"]
+        }
+        fn next_u64(&mut self) -> u64 {
+            panic!["unexpected 64-bit word"]
+        }
+        fn next_u128(&mut self) -> u128 {
+            panic!["unexpected 128-bit word"]
+        }
+    }
+
+    /// Returns the smallest and largest of `n` samples of the range.
+    fn extremes<T: Ord + Copy, R: SampleRange<T> + Clone>(range: R, n: usize) -> (T, T) {
+        let mut rng = XorShift64::new(0xDEFA_0017).unwrap();
+        let first = range.clone().sample(&mut rng);
+        (1..n).fold((first, first), |(min, max), _| {
+            let x = range.clone().sample(&mut rng);
+            (min.min(x), max.max(x))
+        })
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq![extremes(10..13_u32, 1000), (10, 12)];
+        assert_eq![extremes(-3..=3_i16, 1000), (-3, 3)];
+        assert_eq![extremes(u64::MAX - 2.., 1000), (u64::MAX - 2, u64::MAX)];
+        assert_eq![extremes(..3_u8, 1000), (0, 2)];
+        assert_eq![extremes(..=-126_i8, 1000), (-128, -126)];
+        assert_eq![extremes(i8::MIN..=i8::MAX, 10_000), (i8::MIN, i8::MAX)];
+        assert_eq![extremes::<i8, _>(.., 10_000), (i8::MIN, i8::MAX)];
+        assert_eq![extremes(0..=u8::MAX, 10_000), (0, u8::MAX)];
+        assert_eq![extremes(-1000..1000_isize, 100_000), (-1000, 999)];
+
+        // the full ranges of the widest types return the random words,
+        // offset from the start of the range
+        let mut rng = XorShift64::new(0xDEFA_0017).unwrap();
+        let mut copy = rng;
+        assert_eq![rng.gen_range(0..=u64::MAX), copy.next_u64()];
+        assert_eq![
+            rng.gen_range(i64::MIN..),
+            (copy.next_u64() as i64) ^ i64::MIN
+        ];
+        assert_eq![rng.gen_range(0..=u128::MAX), copy.next_u128()];
+        assert_eq![rng.gen_range::<i128, _>(..), copy.next_u128() as i128];
+    }
+
+    #[test]
+    fn single_value() {
+        let mut rng = XorShift64::new(0xDEFA_0017).unwrap();
+        for _ in 0..100 {
+            assert_eq![rng.gen_range(5..6_u8), 5];
+            assert_eq![rng.gen_range(-7..=-7_i32), -7];
+            assert_eq![rng.gen_range(u64::MAX..), u64::MAX];
+            assert_eq![rng.gen_range(..=i128::MIN), i128::MIN];
+            assert_eq![rng.gen_range(..1_usize), 0];
+        }
+        assert![SampleRange::<u8>::is_empty(&(5..5))];
+        assert![!SampleRange::<u8>::is_empty(&(5..=5))];
+    }
+
+    #[test]
+    fn narrow_words() {
+        // every range that fits in 8 bits only draws 8-bit words
+        let mut xabc = Narrow(Xabc::default());
+        let mut xyza = Narrow(Xyza8a::default());
+        for _ in 0..1000 {
+            assert![xabc.gen_range(1..=6_u8) <= 6];
+            assert![xyza.gen_range(-100..100_i8) < 100];
+            let _: u8 = xabc.gen_range(..=u8::MAX);
+            let _: i8 = xyza.gen_range(i8::MIN..);
+            assert![xabc.gen_range(3..=256_usize) <= 256];
+            assert![xyza.gen_range(-128..128_isize) < 128];
+        }
+    }
+
+    #[test]
+    fn chi_square() {
+        /// Returns the chi-square statistic of `SAMPLES` draws from `0..3`.
+        fn chi_square<P: Prng>(mut rng: P) -> f64 {
+            const SAMPLES: usize = 30_000;
+            let mut counts = [0; 3];
+            for _ in 0..SAMPLES {
+                counts[rng.gen_range(0..3_usize)] += 1;
+            }
+            let expected = SAMPLES as f64 / 3.0;
+            counts
+                .iter()
+                .map(|&c| (c as f64 - expected) * (c as f64 - expected) / expected)
+                .sum()
+        }
+        // the 99.9th percentile of the chi-square distribution with 2 degrees of freedom
+        const CRITICAL: f64 = 13.816;
+        assert![chi_square(XorShift64::new(0xDEFA_0017).unwrap()) < CRITICAL];
+        assert![chi_square(Xyza8a::default()) < CRITICAL];
+        assert![chi_square(Xabc::default()) < CRITICAL];
+    }
+}
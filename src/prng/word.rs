@@ -0,0 +1,57 @@
+// alazar::prng::word
+//
+//! The native output words of the generators.
+//
+
+/// An unsigned integer that can be the native output word of a [`Prng`].
+///
+/// This trait is sealed and implemented for `u8`, `u16`, `u32` and `u64`.
+///
+/// [`Prng`]: super::Prng
+pub trait PrngWord: Copy + sealed::Sealed {
+    /// The size of the word in bits.
+    const BITS: u32;
+    /// The size of the word in bytes.
+    const BYTES: usize;
+
+    /// Returns the word zero-extended to a `u64`.
+    #[must_use]
+    fn to_u64(self) -> u64;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_word {
+    ($($t:ty),+) => { $(
+        impl sealed::Sealed for $t {}
+        impl PrngWord for $t {
+            const BITS: u32 = <$t>::BITS;
+            const BYTES: usize = core::mem::size_of::<$t>();
+
+            #[inline(always)]
+            fn to_u64(self) -> u64 {
+                self as u64
+            }
+        }
+    )+ };
+}
+impl_word![u8, u16, u32, u64];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes() {
+        fn check<W: PrngWord>(max: W, expected: u64) {
+            assert_eq![W::BYTES * 8, W::BITS as usize];
+            assert_eq![max.to_u64(), expected];
+        }
+        check(u8::MAX, 0xFF);
+        check(u16::MAX, 0xFFFF);
+        check(u32::MAX, 0xFFFF_FFFF);
+        check(u64::MAX, u64::MAX);
+    }
+}
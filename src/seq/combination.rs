@@ -0,0 +1,302 @@
+// alazar::seq::combination
+//
+//! Random combinations, as bitmasks or by rank.
+//
+
+use crate::{
+    prng::{uniform_u128, uniform_usize},
+    Prng,
+};
+
+/// An unsigned integer that can be used as a bitmask.
+///
+/// This trait is sealed and implemented for `u8`, `u16`, `u32`, `u64` and `u128`.
+pub trait Mask: Copy + sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed {
+        const BITS: u32;
+        const ZERO: Self;
+        fn with_bit(self, bit: u32) -> Self;
+        fn has_bit(self, bit: u32) -> bool;
+        fn complement(self) -> Self;
+    }
+}
+use sealed::Sealed;
+
+macro_rules! impl_mask {
+    ($($t:ty),+) => { $(
+        impl Sealed for $t {
+            const BITS: u32 = <$t>::BITS;
+            const ZERO: Self = 0;
+            #[inline(always)]
+            fn with_bit(self, bit: u32) -> Self {
+                self | (1 << bit)
+            }
+            #[inline(always)]
+            fn has_bit(self, bit: u32) -> bool {
+                self & (1 << bit) != 0
+            }
+            #[inline(always)]
+            fn complement(self) -> Self {
+                !self
+            }
+        }
+        impl Mask for $t {}
+    )+ };
+}
+impl_mask![u8, u16, u32, u64, u128];
+
+/// Returns a uniformly random bitmask with exactly `k` bits set,
+/// or `None` if `k` is greater than the number of bits of `T`.
+///
+/// It uses Floyd's algorithm over the bit positions, which makes exactly
+/// `min(k, bits - k)` bounded draws, each one of a single byte.
+///
+/// # Examples
+/// ```
+/// use alazar::{seq::random_mask_with_popcount, xorshift::XorShift64};
+///
+/// let mut rng = XorShift64::default();
+/// let faults = random_mask_with_popcount::<u128>(&mut rng, 3).unwrap();
+/// assert_eq![faults.count_ones(), 3];
+/// ```
+#[must_use]
+pub fn random_mask_with_popcount<T: Mask>(prng: &mut impl Prng, k: u32) -> Option<T> {
+    if k > T::BITS {
+        return None;
+    }
+    // sets the fewest bits, complementing the result if needed
+    let (set, complement) = if k > T::BITS / 2 {
+        (T::BITS - k, true)
+    } else {
+        (k, false)
+    };
+    let mut mask = T::ZERO;
+    for j in T::BITS - set..T::BITS {
+        let t = uniform_usize(prng, j as usize + 1) as u32;
+        mask = mask.with_bit(if mask.has_bit(t) { j } else { t });
+    }
+    Some(if complement { mask.complement() } else { mask })
+}
+
+/// Returns the binomial coefficient `C(n, k)`, the number of `k`-combinations
+/// of `n` elements, or `None` if it doesn't fit in a `u128`.
+#[must_use]
+pub const fn combination_count(n: usize, k: usize) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+    let k = if k > n - k { n - k } else { k };
+    let (n, k) = (n as u128, k as u128);
+    // each step computes C(n - k + j, j) exactly, dividing first by the
+    // common factor, so it only overflows if the next value doesn't fit
+    let mut count: u128 = 1;
+    let mut j = 1;
+    while j <= k {
+        let g = gcd(count, j);
+        let m = (n - k + j) / (j / g);
+        count = match (count / g).checked_mul(m) {
+            Some(count) => count,
+            None => return None,
+        };
+        j += 1;
+    }
+    Some(count)
+}
+
+/// Returns the rank of a combination, given by its elements in ascending
+/// order, in the colexicographic order of the combinations of its size.
+///
+/// The rank is `Σ C(cᵢ, i + 1)` for each element `cᵢ` at index `i`,
+/// independent of the number of elements `n` the combination is taken from.
+///
+/// Returns `None` if the elements are not strictly ascending,
+/// or if the rank doesn't fit in a `u128`.
+#[must_use]
+pub fn rank_combination(combination: &[usize]) -> Option<u128> {
+    let mut rank: u128 = 0;
+    for (i, &c) in combination.iter().enumerate() {
+        if i > 0 && combination[i - 1] >= c {
+            return None;
+        }
+        rank = rank.checked_add(combination_count(c, i + 1)?)?;
+    }
+    Some(rank)
+}
+
+/// Writes to `combination` the `k`-combination of `0..n` with the given `rank`
+/// in colexicographic order, in ascending order, where `k` is its length.
+///
+/// Returns `None` if `k` is greater than `n`, if the rank is not less than
+/// [`combination_count(n, k)`][combination_count], or if that doesn't fit
+/// in a `u128`.
+///
+/// This is the inverse of [`rank_combination`].
+pub fn unrank_combination(n: usize, rank: u128, combination: &mut [usize]) -> Option<()> {
+    let k = combination.len();
+    if k > n || rank >= combination_count(n, k)? {
+        return None;
+    }
+    let (mut rank, mut upper) = (rank, n);
+    for i in (1..=k).rev() {
+        // finds the largest c below upper with C(c, i) <= rank
+        let (mut lo, mut hi) = (i - 1, upper - 1);
+        while lo < hi {
+            let mid = lo + (hi - lo).div_ceil(2);
+            match combination_count(mid, i) {
+                Some(count) if count <= rank => lo = mid,
+                _ => hi = mid - 1,
+            }
+        }
+        rank -= combination_count(lo, i)?;
+        combination[i - 1] = lo;
+        upper = lo;
+    }
+    Some(())
+}
+
+/// Writes to `combination` a uniformly random `k`-combination of `0..n`,
+/// in ascending order, where `k` is its length, and returns its rank.
+///
+/// The rank is drawn without bias in `0..C(n, k)`, and unranked with
+/// [`unrank_combination`].
+///
+/// Returns `None` if `k` is greater than `n`,
+/// or if [`combination_count(n, k)`][combination_count] doesn't fit in a `u128`.
+///
+/// # Examples
+/// ```
+/// use alazar::{seq::{random_combination, rank_combination}, xorshift::XorShift64};
+///
+/// let mut rng = XorShift64::default();
+/// let mut lotto = [0; 6];
+/// let rank = random_combination(&mut rng, 49, &mut lotto).unwrap();
+/// assert![lotto.windows(2).all(|w| w[0] < w[1] && w[1] < 49)];
+/// assert_eq![rank_combination(&lotto), Some(rank)];
+/// ```
+pub fn random_combination<P: Prng>(
+    prng: &mut P,
+    n: usize,
+    combination: &mut [usize],
+) -> Option<u128> {
+    let count = combination_count(n, combination.len())?;
+    if count == 0 {
+        return None;
+    }
+    let rank = uniform_u128(prng, count);
+    unrank_combination(n, rank, combination)?;
+    Some(rank)
+}
+
+/// Returns the greatest common divisor of `a` and `b`.
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::assert_uniform, xorshift::XorShift64};
+
+    #[test]
+    fn count() {
+        assert_eq![combination_count(5, 2), Some(10)];
+        assert_eq![combination_count(5, 0), Some(1)];
+        assert_eq![combination_count(5, 5), Some(1)];
+        assert_eq![combination_count(3, 5), Some(0)];
+        assert_eq![combination_count(0, 0), Some(1)];
+        assert_eq![combination_count(usize::MAX, 1), Some(usize::MAX as u128)];
+        // the largest central coefficients that fit, and the first that doesn't
+        assert_eq![
+            combination_count(130, 65),
+            Some(95_067_625_827_960_698_145_584_333_020_095_113_100)
+        ];
+        assert_eq![
+            combination_count(131, 65),
+            Some(188_694_833_082_770_476_622_296_176_145_946_360_850)
+        ];
+        assert_eq![combination_count(132, 66), None];
+        assert_eq![combination_count(200, 100), None];
+    }
+
+    #[test]
+    fn rank_round_trip() {
+        const N: usize = 7;
+        let mut buf = [0; N];
+        for k in 0..=N {
+            let combination = &mut buf[..k];
+            let count = combination_count(N, k).unwrap();
+            for rank in 0..count {
+                unrank_combination(N, rank, combination).unwrap();
+                assert![combination.windows(2).all(|w| w[0] < w[1])];
+                assert![combination.iter().all(|&c| c < N)];
+                assert_eq![rank_combination(combination), Some(rank)];
+            }
+            assert_eq![unrank_combination(N, count, combination), None];
+        }
+        assert_eq![unrank_combination(3, 0, &mut [0; 4]), None];
+    }
+
+    #[test]
+    fn rank_round_trip_large() {
+        let mut rng = XorShift64::new(0xDEFA_0017).unwrap();
+        let mut combination = [0; 65];
+        let last = combination_count(130, 65).unwrap() - 1;
+        for rank in [0, 1, last - 1, last] {
+            unrank_combination(130, rank, &mut combination).unwrap();
+            assert_eq![rank_combination(&combination), Some(rank)];
+        }
+        assert_eq![combination[0], 65];
+        for _ in 0..100 {
+            let rank = random_combination(&mut rng, 130, &mut combination).unwrap();
+            assert_eq![rank_combination(&combination), Some(rank)];
+        }
+        assert_eq![random_combination(&mut rng, 200, &mut [0; 100]), None];
+    }
+
+    #[test]
+    fn rank_invalid() {
+        assert_eq![rank_combination(&[]), Some(0)];
+        assert_eq![rank_combination(&[1, 1]), None];
+        assert_eq![rank_combination(&[3, 2]), None];
+    }
+
+    #[test]
+    fn mask_limits() {
+        let mut rng = XorShift64::new(0x1234_5678_9ABC).unwrap();
+        macro_rules! check {
+            ($($t:ty),+) => { $(
+                assert_eq![random_mask_with_popcount::<$t>(&mut rng, 0), Some(0)];
+                assert_eq![random_mask_with_popcount::<$t>(&mut rng, <$t>::BITS), Some(<$t>::MAX)];
+                assert_eq![random_mask_with_popcount::<$t>(&mut rng, <$t>::BITS + 1), None];
+                for k in 0..=<$t>::BITS {
+                    let mask = random_mask_with_popcount::<$t>(&mut rng, k).unwrap();
+                    assert_eq![mask.count_ones(), k];
+                }
+            )+ };
+        }
+        check![u8, u16, u32, u64, u128];
+    }
+
+    #[test]
+    fn mask_uniform() {
+        const SAMPLES: usize = 56_000;
+        let mut rng = XorShift64::new(42).unwrap();
+        // both with the bits set directly and with the complement
+        for k in [3, 5] {
+            let mut counts = [0; 256];
+            for _ in 0..SAMPLES {
+                counts[random_mask_with_popcount::<u8>(&mut rng, k).unwrap() as usize] += 1;
+            }
+            let counts: [usize; 56] = core::array::from_fn(|i| {
+                let mask = (0..=255_u8).filter(|m| m.count_ones() == k).nth(i).unwrap();
+                counts[mask as usize]
+            });
+            assert_uniform(&counts, "8-bit masks");
+        }
+    }
+}
@@ -0,0 +1,331 @@
+// alazar::seq::composition
+//
+//! Random integer compositions and partitions.
+//
+
+use super::sorted::sample_sorted;
+use crate::{
+    math::{floor, ln, sqrt},
+    Prng,
+};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Writes to `parts` a uniformly random composition of `total` into
+/// non-negative parts, as many as the length of `parts`.
+///
+/// Every ordered way of writing `total` as a sum of that many non-negative
+/// integers is equally likely. It's sampled with stars and bars, placing the
+/// separators between the parts with [`sample_sorted`][super::sample_sorted],
+/// in constant memory.
+///
+/// Returns `None` if there are no parts and `total` is not `0`,
+/// or if `total` plus the number of parts, minus one, is greater than `2^53`.
+///
+/// # Examples
+/// ```
+/// use alazar::{seq::random_composition, xorshift::XorShift64};
+///
+/// let mut rng = XorShift64::default();
+/// let mut budget = [0; 4];
+/// random_composition(&mut rng, 1000, &mut budget).unwrap();
+/// assert_eq![budget.iter().sum::<u64>(), 1000];
+/// ```
+pub fn random_composition<P: Prng>(prng: &mut P, total: u64, parts: &mut [u64]) -> Option<()> {
+    let Some(bars) = (parts.len() as u64).checked_sub(1) else {
+        return if total == 0 { Some(()) } else { None };
+    };
+    // total stars and k-1 bars in a row of slots, where each part
+    // is the number of stars between two consecutive bars
+    let slots = total.checked_add(bars)?;
+    let mut prev = 0;
+    for (part, bar) in parts.iter_mut().zip(sample_sorted(prng, slots, bars)?) {
+        *part = bar - prev;
+        prev = bar + 1;
+    }
+    parts[bars as usize] = slots - prev;
+    Some(())
+}
+
+/// Writes to `parts` a uniformly random composition of `total` into
+/// positive parts, as many as the length of `parts`.
+///
+/// Every ordered way of writing `total` as a sum of that many positive
+/// integers is equally likely. It's sampled by cutting `total` at distinct
+/// positions chosen with [`sample_sorted`][super::sample_sorted],
+/// in constant memory.
+///
+/// Returns `None` if `total` is less than the number of parts,
+/// if there are no parts and `total` is not `0`,
+/// or if `total` is greater than `2^53 + 1`.
+///
+/// # Examples
+/// ```
+/// use alazar::{seq::random_positive_composition, xorshift::XorShift64};
+///
+/// let mut rng = XorShift64::default();
+/// let mut shards = [0; 8];
+/// random_positive_composition(&mut rng, 100, &mut shards).unwrap();
+/// assert![shards.iter().all(|&s| s > 0)];
+/// assert_eq![shards.iter().sum::<u64>(), 100];
+/// ```
+pub fn random_positive_composition<P: Prng>(
+    prng: &mut P,
+    total: u64,
+    parts: &mut [u64],
+) -> Option<()> {
+    let Some(cuts) = (parts.len() as u64).checked_sub(1) else {
+        return if total == 0 { Some(()) } else { None };
+    };
+    // k-1 distinct cuts among the total-1 gaps between units
+    let mut prev = 0;
+    for (part, cut) in parts
+        .iter_mut()
+        .zip(sample_sorted(prng, total.checked_sub(1)?, cuts)?)
+    {
+        *part = cut + 1 - prev;
+        prev = cut + 1;
+    }
+    parts[cuts as usize] = total - prev;
+    Some(())
+}
+
+/// Returns a uniformly random composition of `total` into `k` non-negative parts.
+///
+/// See [`random_composition`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub fn random_composition_vec<P: Prng>(prng: &mut P, total: u64, k: usize) -> Option<Vec<u64>> {
+    let mut parts = vec![0; k];
+    random_composition(prng, total, &mut parts)?;
+    Some(parts)
+}
+
+/// Returns a uniformly random composition of `total` into `k` positive parts.
+///
+/// See [`random_positive_composition`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub fn random_positive_composition_vec<P: Prng>(
+    prng: &mut P,
+    total: u64,
+    k: usize,
+) -> Option<Vec<u64>> {
+    let mut parts = vec![0; k];
+    random_positive_composition(prng, total, &mut parts)?;
+    Some(parts)
+}
+
+/// Writes to the start of `parts` a uniformly random partition of `n`,
+/// in descending order, and returns the written part.
+///
+/// Every unordered way of writing `n` as a sum of positive integers is
+/// equally likely. It's sampled exactly with a Boltzmann sampler, drawing
+/// the multiplicity of each part size from independent geometric
+/// distributions, and completing it with ones as in the probabilistic
+/// divide-and-conquer of Arratia and DeSalvo, [*Probabilistic divide-and-conquer:
+/// a new exact simulation method, with integer partitions as an example*][pdc]
+/// (2016), with rejection. It takes `O(n^1.25)` expected time,
+/// and doesn't allocate.
+///
+/// Returns `None` if `parts` is shorter than `n`,
+/// which is the largest possible number of parts.
+///
+/// [pdc]: https://doi.org/10.1017/S0963548315000358
+///
+/// # Examples
+/// ```
+/// use alazar::{seq::random_partition, xorshift::XorShift64};
+///
+/// let mut rng = XorShift64::default();
+/// let mut buf = [0; 50];
+/// let parts = random_partition(&mut rng, 50, &mut buf).unwrap();
+/// assert_eq![parts.iter().sum::<usize>(), 50];
+/// assert![parts.windows(2).all(|w| w[0] >= w[1])];
+/// ```
+pub fn random_partition<'b, P: Prng>(
+    prng: &mut P,
+    n: usize,
+    parts: &'b mut [usize],
+) -> Option<&'b mut [usize]> {
+    if parts.len() < n {
+        return None;
+    }
+    // the Boltzmann parameter x = e^-c, with c = π / √(6n),
+    // which makes the expected sum of the parts close to n
+    let ln_x = -core::f64::consts::PI / sqrt(6.0 * n.max(1) as f64);
+    'retry: loop {
+        let (mut len, mut sum) = (0, 0);
+        for size in (2..=n).rev() {
+            // the multiplicity is geometric, with P(m) ∝ x^(size·m)
+            let m = floor(ln(prng.next_f64_open()) / (size as f64 * ln_x));
+            if m > (n - sum) as f64 / size as f64 {
+                continue 'retry;
+            }
+            for _ in 0..m as usize {
+                parts[len] = size;
+                len += 1;
+            }
+            sum += m as usize * size;
+        }
+        // the ones complete the sum, accepted with probability x^ones
+        let ones = n - sum;
+        if ln(prng.next_f64_open()) >= ones as f64 * ln_x {
+            continue 'retry;
+        }
+        parts[len..len + ones].fill(1);
+        return Some(&mut parts[..len + ones]);
+    }
+}
+
+/// Returns a uniformly random partition of `n`, in descending order.
+///
+/// See [`random_partition`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[must_use]
+pub fn random_partition_vec<P: Prng>(prng: &mut P, n: usize) -> Vec<usize> {
+    let mut parts = vec![0; n];
+    let len = random_partition(prng, n, &mut parts).map_or(0, |parts| parts.len());
+    parts.truncate(len);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::assert_uniform, xorshift::XorShift64};
+
+    /// The partitions of 6, in descending order.
+    const PARTITIONS_OF_6: [&[usize]; 11] = [
+        &[6],
+        &[5, 1],
+        &[4, 2],
+        &[4, 1, 1],
+        &[3, 3],
+        &[3, 2, 1],
+        &[3, 1, 1, 1],
+        &[2, 2, 2],
+        &[2, 2, 1, 1],
+        &[2, 1, 1, 1, 1],
+        &[1, 1, 1, 1, 1, 1],
+    ];
+
+    #[test]
+    fn sums() {
+        let mut rng = XorShift64::new(0xDEFA_0017).unwrap();
+        let mut buf = [0; 20];
+        for (total, k) in [(0, 1), (1, 1), (7, 20), (1000, 5), (1 << 40, 20)] {
+            let parts = &mut buf[..k];
+            random_composition(&mut rng, total, parts).unwrap();
+            assert_eq![parts.iter().sum::<u64>(), total];
+        }
+        for (total, k) in [(1, 1), (20, 20), (21, 20), (1000, 5), (1 << 40, 20)] {
+            let parts = &mut buf[..k];
+            random_positive_composition(&mut rng, total, parts).unwrap();
+            assert![parts.iter().all(|&p| p > 0)];
+            assert_eq![parts.iter().sum::<u64>(), total];
+        }
+    }
+
+    #[test]
+    fn uniform() {
+        const SAMPLES: usize = 60_000;
+        let mut rng = XorShift64::new(0x1234_5678_9ABC).unwrap();
+        // the C(6, 2) = 15 compositions of 4 into 3 non-negative parts,
+        // indexed by their first 2 parts
+        let mut counts = [0; 25];
+        let mut parts = [0; 3];
+        for _ in 0..SAMPLES {
+            random_composition(&mut rng, 4, &mut parts).unwrap();
+            counts[parts[0] as usize * 5 + parts[1] as usize] += 1;
+        }
+        let counts: [usize; 15] = core::array::from_fn(|i| {
+            let index = (0..25).filter(|j| j / 5 + j % 5 <= 4).nth(i).unwrap();
+            counts[index]
+        });
+        assert_uniform(&counts, "compositions of 4 into 3 parts");
+
+        // the C(5, 2) = 10 compositions of 6 into 3 positive parts
+        let mut counts = [0; 36];
+        for _ in 0..SAMPLES {
+            random_positive_composition(&mut rng, 6, &mut parts).unwrap();
+            counts[parts[0] as usize * 6 + parts[1] as usize] += 1;
+        }
+        let counts: [usize; 10] = core::array::from_fn(|i| {
+            let index = (0..36)
+                .filter(|j| j / 6 > 0 && j % 6 > 0 && j / 6 + j % 6 < 6)
+                .nth(i)
+                .unwrap();
+            counts[index]
+        });
+        assert_uniform(&counts, "compositions of 6 into 3 positive parts");
+    }
+
+    #[test]
+    fn partitions() {
+        const SAMPLES: usize = 55_000;
+        let mut rng = XorShift64::new(42).unwrap();
+        let mut buf = [0; 6];
+        let mut counts = [0; 11];
+        for _ in 0..SAMPLES {
+            let parts = random_partition(&mut rng, 6, &mut buf).unwrap();
+            counts[PARTITIONS_OF_6.iter().position(|p| *p == parts).unwrap()] += 1;
+        }
+        assert_uniform(&counts, "partitions of 6");
+
+        let mut buf = [0; 1000];
+        for n in [1, 2, 100, 1000] {
+            let parts = random_partition(&mut rng, n, &mut buf).unwrap();
+            assert![parts.windows(2).all(|w| w[0] >= w[1])];
+            assert![parts.iter().all(|&p| p > 0)];
+            assert_eq![parts.iter().sum::<usize>(), n];
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        let mut rng = XorShift64::new(7).unwrap();
+        assert_eq![random_composition(&mut rng, 0, &mut []), Some(())];
+        assert_eq![random_composition(&mut rng, 1, &mut []), None];
+        assert_eq![random_positive_composition(&mut rng, 0, &mut []), Some(())];
+        assert_eq![random_positive_composition(&mut rng, 1, &mut []), None];
+
+        let mut parts = [1; 3];
+        random_composition(&mut rng, 0, &mut parts).unwrap();
+        assert_eq![parts, [0; 3]];
+        assert_eq![random_positive_composition(&mut rng, 0, &mut parts), None];
+        assert_eq![random_positive_composition(&mut rng, 2, &mut parts), None];
+        random_positive_composition(&mut rng, 3, &mut parts).unwrap();
+        assert_eq![parts, [1; 3]];
+
+        // the limits of the sorted sampling
+        assert![random_composition(&mut rng, (1 << 53) - 2, &mut parts).is_some()];
+        assert_eq![
+            random_composition(&mut rng, (1 << 53) - 1, &mut parts),
+            None
+        ];
+        assert![random_positive_composition(&mut rng, (1 << 53) + 1, &mut parts).is_some()];
+        assert_eq![
+            random_positive_composition(&mut rng, (1 << 53) + 2, &mut parts),
+            None
+        ];
+
+        assert_eq![random_partition(&mut rng, 0, &mut []), Some(&mut [][..])];
+        assert_eq![random_partition(&mut rng, 3, &mut [0; 2]), None];
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn vecs() {
+        let mut rng = XorShift64::new(7).unwrap();
+        let parts = random_composition_vec(&mut rng, 10, 4).unwrap();
+        assert_eq![(parts.len(), parts.iter().sum::<u64>()), (4, 10)];
+        let parts = random_positive_composition_vec(&mut rng, 10, 4).unwrap();
+        assert_eq![(parts.len(), parts.iter().sum::<u64>()), (4, 10)];
+        assert_eq![random_positive_composition_vec(&mut rng, 3, 4), None];
+        let parts = random_partition_vec(&mut rng, 10);
+        assert_eq![parts.iter().sum::<usize>(), 10];
+        assert![random_partition_vec(&mut rng, 0).is_empty()];
+    }
+}
@@ -0,0 +1,233 @@
+// alazar::seq::derange
+//
+//! Uniform random derangements.
+//
+// Conrado Martínez, Alois Panholzer and Helmut Prodinger,
+// "Generating random derangements" (2008).
+
+use crate::{prng::uniform_usize, Prng};
+
+/// The mark of a closed position, in the high bit of an index.
+const MARK: usize = 1 << (usize::BITS - 1);
+
+/// Fills `perm` with a uniformly random derangement of `0..perm.len()`,
+/// a permutation without fixed points, so that `perm[i] != i` for every `i`.
+///
+/// Returns `false`, leaving `perm` with the identity, if the length is 1,
+/// since no derangement exists.
+///
+/// It uses the algorithm of Martínez, Panholzer and Prodinger,
+/// described in [*Generating random derangements*][mpp] (2008), which
+/// makes about `2n` draws on average, and doesn't allocate.
+///
+/// See also [`SliceSample::derange`][super::SliceSample::derange], for
+/// deranging the elements of a slice.
+///
+/// [mpp]: https://doi.org/10.1137/1.9781611972986.7
+///
+/// # Examples
+/// ```
+/// use alazar::{seq::derangement, xorshift::Xyza8a};
+///
+/// let mut rng = Xyza8a::default();
+/// let mut santa = [0; 8];
+/// assert!(derangement(&mut rng, &mut santa));
+/// for (giver, receiver) in santa.iter().enumerate() {
+///     assert_ne![giver, *receiver];
+/// }
+/// ```
+pub fn derangement<P: Prng>(prng: &mut P, perm: &mut [usize]) -> bool {
+    for (i, p) in perm.iter_mut().enumerate() {
+        *p = i;
+    }
+    // the indices of a slice of usize never reach the high bit
+    let done = derange_with(
+        prng,
+        perm.len(),
+        |perm: &mut [usize], i, j| perm.swap(i, j),
+        |perm: &[usize], j| perm[j] & MARK != 0,
+        |perm: &mut [usize], j| perm[j] |= MARK,
+        perm,
+    );
+    for p in perm.iter_mut() {
+        *p &= !MARK;
+    }
+    done
+}
+
+/// Applies a uniformly random derangement of `0..len` through `swap`,
+/// keeping the closed positions with `is_marked` and `mark`.
+///
+/// Returns `false` without changes if `len` is 1.
+///
+/// A marked position is never swapped afterwards.
+pub(crate) fn derange_with<P: Prng, S: ?Sized>(
+    prng: &mut P,
+    len: usize,
+    mut swap: impl FnMut(&mut S, usize, usize),
+    is_marked: impl Fn(&S, usize) -> bool,
+    mut mark: impl FnMut(&mut S, usize),
+    state: &mut S,
+) -> bool {
+    if len == 1 {
+        return false;
+    }
+    let (mut i, mut u) = (len, len);
+    while u >= 2 {
+        i -= 1;
+        if !is_marked(state, i) {
+            let mut j = uniform_usize(prng, i);
+            while is_marked(state, j) {
+                j = uniform_usize(prng, i);
+            }
+            swap(state, i, j);
+            // closes the cycle of j with probability (u-1)·D(u-2) / D(u)
+            if prng.next_f64() < close_probability(u) {
+                mark(state, j);
+                u -= 1;
+            }
+            u -= 1;
+        }
+    }
+    true
+}
+
+/// Returns `(u-1)·D(u-2) / D(u)`, where `D(u)` is the number of derangements
+/// of `u` elements, for `u >= 2`.
+///
+/// Since `D(u) = u!·S(u)`, with `S(u)` the partial sums of the series of
+/// `1/e`, the ratio is `S(u-2) / (u·S(u))`.
+fn close_probability(u: usize) -> f64 {
+    let s = |k: usize| PARTIAL_SUMS[k.min(PARTIAL_SUMS.len() - 1)];
+    s(u - 2) / (u as f64 * s(u))
+}
+
+/// The partial sums `Σ (-1)^k / k!`, for `k` up to the index.
+///
+/// The last one equals `1/e` to double precision.
+const PARTIAL_SUMS: [f64; 21] = {
+    let mut sums = [0.0; 21];
+    let (mut sum, mut term) = (0.0, 1.0);
+    let mut k = 0;
+    while k < sums.len() {
+        sum += term;
+        sums[k] = sum;
+        k += 1;
+        term /= -(k as f64);
+    }
+    sums
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{seq::SliceSample, test_utils::assert_uniform, xorshift::XorShift64};
+
+    /// Returns the base-4 code of a permutation of 4 elements.
+    fn code(perm: &[usize]) -> usize {
+        perm.iter().rev().fold(0, |acc, &p| acc * 4 + p)
+    }
+
+    /// Asserts that the counts indexed by `code` are uniform over the 9 derangements of 4.
+    fn assert_derangements(counts: &[usize; 256], what: &str) {
+        let derangements: [usize; 9] = [
+            [1, 0, 3, 2],
+            [1, 2, 3, 0],
+            [1, 3, 0, 2],
+            [2, 0, 3, 1],
+            [2, 3, 0, 1],
+            [2, 3, 1, 0],
+            [3, 0, 1, 2],
+            [3, 2, 0, 1],
+            [3, 2, 1, 0],
+        ]
+        .map(|perm| code(&perm));
+        let found: usize = derangements.iter().map(|&c| counts[c]).sum();
+        assert_eq![
+            found,
+            counts.iter().sum::<usize>(),
+            "{what}: not a derangement"
+        ];
+        assert_uniform(&derangements.map(|c| counts[c]), what);
+    }
+
+    #[test]
+    fn no_fixed_points() {
+        let mut rng = XorShift64::new(0xDEFA_0017).unwrap();
+        for len in [2, 3, 4, 5, 10, 100, 1000] {
+            let mut perm = [0; 1000];
+            for _ in 0..100 {
+                assert![derangement(&mut rng, &mut perm[..len])];
+                let perm = &perm[..len];
+                assert![perm.iter().enumerate().all(|(i, &p)| p != i)];
+                // it's a permutation
+                let mut seen = [false; 1000];
+                perm.iter().for_each(|&p| seen[p] = true);
+                assert![seen[..len].iter().all(|&s| s)];
+            }
+        }
+    }
+
+    #[test]
+    fn uniform() {
+        let mut rng = XorShift64::new(0x1234_5678_9ABC).unwrap();
+        let mut counts = [0; 256];
+        for _ in 0..90_000 {
+            let mut perm = [0; 4];
+            derangement(&mut rng, &mut perm);
+            counts[code(&perm)] += 1;
+        }
+        assert_derangements(&counts, "derangement");
+    }
+
+    #[test]
+    fn short_lengths() {
+        let mut rng = XorShift64::default();
+        assert![derangement(&mut rng, &mut [])];
+        let mut perm = [7];
+        assert![!derangement(&mut rng, &mut perm)];
+        assert_eq![perm, [0]];
+
+        let (mut empty, mut single) = ([0_u8; 0], [7]);
+        empty.shuffle_cyclic(&mut rng);
+        single.shuffle_cyclic(&mut rng);
+        assert_eq![single, [7]];
+        #[cfg(feature = "alloc")]
+        {
+            assert![empty.derange(&mut rng)];
+            assert![!single.derange(&mut rng)];
+            assert_eq![single, [7]];
+        }
+    }
+
+    #[test]
+    fn shuffle_cyclic() {
+        let mut rng = XorShift64::new(42).unwrap();
+        for len in [2, 3, 4, 7, 100] {
+            for _ in 0..100 {
+                let mut perm: [usize; 100] = core::array::from_fn(|i| i);
+                perm[..len].shuffle_cyclic(&mut rng);
+                // following the positions from 0 takes `len` steps to come back
+                let (mut pos, mut steps) = (perm[0], 1);
+                while pos != 0 {
+                    pos = perm[pos];
+                    steps += 1;
+                }
+                assert_eq![steps, len];
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn derange_slice() {
+        let mut rng = XorShift64::new(7).unwrap();
+        let mut counts = [0; 256];
+        for _ in 0..90_000 {
+            let mut perm = [0, 1, 2, 3];
+            assert![perm.derange(&mut rng)];
+            counts[code(&perm)] += 1;
+        }
+        assert_derangements(&counts, "SliceSample::derange");
+    }
+}
@@ -0,0 +1,146 @@
+// alazar::seq::index
+//
+//! Sampling of distinct indices.
+//
+
+use crate::{prng::uniform_usize, Prng};
+
+/// Samples `k` distinct indices from `0..n` uniformly, without replacement,
+/// and writes them to the start of `buf`.
+///
+/// Returns the written part of `buf`,
+/// or `None` if `k` is greater than `n` or than the length of `buf`.
+///
+/// The strategy is chosen from `k` and `n`:
+/// - when `k` is small relative to `n`, it uses Floyd's algorithm, which
+///   makes exactly `k` draws and *O(k²)* comparisons, independently of `n`.
+///   The indices are returned in no particular order.
+/// - otherwise it uses selection sampling (Knuth's algorithm S),
+///   which makes at most `n` draws. The indices are returned in ascending order.
+///
+/// It doesn't allocate, and every draw uses the narrowest width that fits.
+///
+/// # Examples
+/// ```
+/// use alazar::{seq::sample_indices, xorshift::XorShift64};
+///
+/// let mut rng = XorShift64::default();
+/// let mut buf = [0; 10];
+/// let ids = sample_indices(&mut rng, u32::MAX as usize, 10, &mut buf).unwrap();
+/// assert_eq![ids.len(), 10];
+/// ```
+pub fn sample_indices<'b, P: Prng>(
+    prng: &mut P,
+    n: usize,
+    k: usize,
+    buf: &'b mut [usize],
+) -> Option<&'b mut [usize]> {
+    if k > n || k > buf.len() {
+        return None;
+    }
+    let buf = &mut buf[..k];
+    // a comparison costs about an eighth of a draw
+    if n - k <= k.saturating_mul(k) / 8 {
+        sample_selection(prng, n, buf);
+    } else {
+        sample_floyd(prng, n, buf);
+    }
+    Some(buf)
+}
+
+/// Fills `buf` with distinct indices from `0..n`, with Floyd's algorithm.
+//
+// Robert Floyd and Jon Bentley, "Programming Pearls: A sample of brilliance" (1987).
+fn sample_floyd<P: Prng>(prng: &mut P, n: usize, buf: &mut [usize]) {
+    let k = buf.len();
+    for (i, j) in (n - k..n).enumerate() {
+        let t = uniform_usize(prng, j + 1);
+        buf[i] = if buf[..i].contains(&t) { j } else { t };
+    }
+}
+
+/// Fills `buf` with distinct indices from `0..n` in ascending order,
+/// with selection sampling.
+fn sample_selection<P: Prng>(prng: &mut P, n: usize, buf: &mut [usize]) {
+    let mut selected = 0;
+    let mut index = 0;
+    while selected < buf.len() {
+        // selects each index with probability remaining / left
+        if uniform_usize(prng, n - index) < buf.len() - selected {
+            buf[selected] = index;
+            selected += 1;
+        }
+        index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::assert_uniform, xorshift::XorShift64};
+
+    /// Asserts that the `indices` are distinct and less than `n`.
+    fn assert_distinct(indices: &[usize], n: usize) {
+        for (i, &index) in indices.iter().enumerate() {
+            assert![index < n, "index {index} out of 0..{n}"];
+            assert![!indices[..i].contains(&index), "repeated index {index}"];
+        }
+    }
+
+    #[test]
+    fn distinct() {
+        let mut rng = XorShift64::new(0xDEFA_0017).unwrap();
+        let mut buf = [0; 1000];
+        for (n, k) in [
+            (1, 1),
+            (10, 3),
+            (1000, 30),
+            (1000, 900),
+            (1 << 40, 100),
+            (usize::MAX, 10),
+        ] {
+            let indices = sample_indices(&mut rng, n, k, &mut buf).unwrap();
+            assert_eq![indices.len(), k];
+            assert_distinct(indices, n);
+        }
+    }
+
+    #[test]
+    fn all_and_none() {
+        let mut rng = XorShift64::new(0x1234_5678_9ABC).unwrap();
+        let mut buf = [0; 100];
+        let indices = sample_indices(&mut rng, 100, 100, &mut buf).unwrap();
+        assert![indices.iter().copied().eq(0..100)];
+        assert_eq![
+            sample_indices(&mut rng, 100, 0, &mut buf),
+            Some(&mut [][..])
+        ];
+        assert_eq![sample_indices(&mut rng, 0, 0, &mut []), Some(&mut [][..])];
+        assert_eq![sample_indices(&mut rng, 5, 6, &mut buf), None];
+        assert_eq![sample_indices(&mut rng, 100, 11, &mut buf[..10]), None];
+    }
+
+    #[test]
+    fn uniform() {
+        const N: usize = 10;
+        let mut rng = XorShift64::new(42).unwrap();
+        let mut buf = [0; N];
+        // Floyd's algorithm for 5 of 10, selection sampling for 6 of 10
+        for (k, subsets) in [(5, 252), (6, 210)] {
+            let mut counts = [0; 1 << N];
+            for _ in 0..subsets * 200 {
+                let indices = sample_indices(&mut rng, N, k, &mut buf).unwrap();
+                if k == 6 {
+                    assert![indices.windows(2).all(|w| w[0] < w[1])];
+                }
+                counts[indices.iter().fold(0, |mask, i| mask | (1 << i))] += 1;
+            }
+            let masks = (0..1 << N).filter(|m: &usize| m.count_ones() == k as u32);
+            let mut subset_counts = [0; 252];
+            for (count, mask) in subset_counts.iter_mut().zip(masks) {
+                *count = counts[mask];
+            }
+            assert_uniform(&subset_counts[..subsets], "k-subsets of 10");
+        }
+    }
+}
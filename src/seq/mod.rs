@@ -0,0 +1,34 @@
+// alazar::seq
+//
+//! Random sampling and shuffling of sequences.
+//!
+//! Every operation has a version that works without allocation, and draws
+//! its bounded indices with the narrowest integer width that fits the length
+//! of the sequence, so that 8-bit generators can shuffle a slice of up to
+//! 65536 elements with 16-bit draws, without bias.
+//
+
+mod combination;
+mod composition;
+mod derange;
+mod index;
+mod permutation;
+mod reservoir;
+mod slice;
+mod sorted;
+
+pub use combination::{
+    combination_count, random_combination, random_mask_with_popcount, rank_combination,
+    unrank_combination, Mask,
+};
+pub use composition::{random_composition, random_partition, random_positive_composition};
+#[cfg(feature = "alloc")]
+pub use composition::{
+    random_composition_vec, random_partition_vec, random_positive_composition_vec,
+};
+pub use derange::derangement;
+pub use index::sample_indices;
+pub use permutation::{PermutationIter, RandomPermutation};
+pub use reservoir::{Reservoir, WeightedReservoir};
+pub use slice::{ChooseMultiple, SliceSample};
+pub use sorted::{sample_sorted, SortedSample};
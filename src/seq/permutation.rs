@@ -0,0 +1,270 @@
+// alazar::seq::permutation
+//
+//! Bijective random permutations of integer ranges.
+//
+
+use crate::Prng;
+
+/// The number of rounds of the Feistel network.
+const ROUNDS: usize = 8;
+
+/// A pseudo-random permutation of the range `0..n`, for any `n` up to `2^64`,
+/// computed on demand without storing it.
+///
+/// It's a balanced Feistel network over the smallest power of four not below
+/// `n`, keyed with random round keys, and the values outside the range are
+/// mapped back inside by cycle-walking: the network is applied again until the
+/// result falls in the range. Since the domain is at most `4n`, at most 4
+/// applications are needed on average.
+///
+/// It's meant for visiting indices or assigning identifiers in a random order.
+/// It's not a cryptographic permutation.
+///
+/// # Examples
+/// ```
+/// use alazar::{seq::RandomPermutation, xorshift::XorShift64};
+///
+/// let mut rng = XorShift64::default();
+/// let perm = RandomPermutation::new(&mut rng, 1000).unwrap();
+/// let p = perm.index(42).unwrap();
+/// assert_eq![perm.inverse(p), Some(42)];
+///
+/// let mut seen = [false; 1000];
+/// for p in perm.iter() {
+///     assert!(!seen[p as usize]);
+///     seen[p as usize] = true;
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RandomPermutation {
+    // the length of the range, where 0 stands for 2^64
+    len: u64,
+    half_bits: u32,
+    keys: [u64; ROUNDS],
+}
+
+impl RandomPermutation {
+    /// Returns a new random permutation of `0..n`, keyed from `prng`.
+    ///
+    /// Returns `None` if `n` is `0`. See [`full`][Self::full] for `2^64`.
+    #[must_use]
+    pub fn new<P: Prng>(prng: &mut P, n: u64) -> Option<Self> {
+        if n == 0 {
+            None
+        } else {
+            // the bits needed for the largest value n-1, rounded up to even
+            let bits = u64::BITS - (n - 1).leading_zeros();
+            Some(Self::with_half_bits(prng, n, bits.div_ceil(2).max(1)))
+        }
+    }
+
+    /// Returns a new random permutation of every `u64`, keyed from `prng`.
+    #[must_use]
+    pub fn full<P: Prng>(prng: &mut P) -> Self {
+        Self::with_half_bits(prng, 0, u64::BITS / 2)
+    }
+
+    /// Returns the length of the permuted range.
+    #[must_use]
+    pub const fn len(&self) -> u128 {
+        if self.len == 0 {
+            1 << u64::BITS
+        } else {
+            self.len as u128
+        }
+    }
+
+    /// Returns `false`, since the permuted range is never empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the position `i` of the permutation,
+    /// or `None` if `i` is not in the range.
+    #[must_use]
+    pub const fn index(&self, i: u64) -> Option<u64> {
+        if !self.contains(i) {
+            return None;
+        }
+        let mut x = self.encrypt(i);
+        while !self.contains(x) {
+            x = self.encrypt(x);
+        }
+        Some(x)
+    }
+
+    /// Returns the position of the value `p` in the permutation,
+    /// or `None` if `p` is not in the range.
+    #[must_use]
+    pub const fn inverse(&self, p: u64) -> Option<u64> {
+        if !self.contains(p) {
+            return None;
+        }
+        let mut x = self.decrypt(p);
+        while !self.contains(x) {
+            x = self.decrypt(x);
+        }
+        Some(x)
+    }
+
+    /// Returns an iterator over the permuted range, in permutation order.
+    #[must_use]
+    pub const fn iter(&self) -> PermutationIter<'_> {
+        PermutationIter {
+            perm: self,
+            next: 0,
+        }
+    }
+}
+
+// private associated items
+impl RandomPermutation {
+    fn with_half_bits<P: Prng>(prng: &mut P, len: u64, half_bits: u32) -> Self {
+        let mut keys = [0; ROUNDS];
+        for key in &mut keys {
+            *key = prng.next_u64();
+        }
+        Self {
+            len,
+            half_bits,
+            keys,
+        }
+    }
+
+    /// Returns `true` if `x` is in the permuted range.
+    #[inline]
+    const fn contains(&self, x: u64) -> bool {
+        self.len == 0 || x < self.len
+    }
+
+    /// Returns the mask of the bits of each half.
+    #[inline]
+    const fn mask(&self) -> u64 {
+        u64::MAX >> (u64::BITS - self.half_bits)
+    }
+
+    /// Applies the Feistel network to `x`.
+    const fn encrypt(&self, x: u64) -> u64 {
+        let (mask, bits) = (self.mask(), self.half_bits);
+        let (mut left, mut right) = ((x >> bits) & mask, x & mask);
+        let mut i = 0;
+        while i < ROUNDS {
+            (left, right) = (right, left ^ (round(right, self.keys[i]) & mask));
+            i += 1;
+        }
+        (left << bits) | right
+    }
+
+    /// Applies the inverse of the Feistel network to `x`.
+    const fn decrypt(&self, x: u64) -> u64 {
+        let (mask, bits) = (self.mask(), self.half_bits);
+        let (mut left, mut right) = ((x >> bits) & mask, x & mask);
+        let mut i = ROUNDS;
+        while i > 0 {
+            i -= 1;
+            (left, right) = (right ^ (round(left, self.keys[i]) & mask), left);
+        }
+        (left << bits) | right
+    }
+}
+
+/// The keyed round function, a 64-bit finalizer applied to the keyed input.
+#[inline]
+const fn round(x: u64, key: u64) -> u64 {
+    let mut z = x ^ key;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// An iterator over a [`RandomPermutation`], in permutation order.
+///
+/// This `struct` is created by [`RandomPermutation::iter`].
+#[derive(Clone, Debug)]
+pub struct PermutationIter<'a> {
+    perm: &'a RandomPermutation,
+    // the next position, up to 2^64
+    next: u128,
+}
+
+impl Iterator for PermutationIter<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.next < self.perm.len() {
+            let p = self.perm.index(self.next as u64);
+            self.next += 1;
+            p
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.perm.len() - self.next;
+        (
+            usize::try_from(left).unwrap_or(usize::MAX),
+            usize::try_from(left).ok(),
+        )
+    }
+}
+
+impl<'a> IntoIterator for &'a RandomPermutation {
+    type Item = u64;
+    type IntoIter = PermutationIter<'a>;
+
+    fn into_iter(self) -> PermutationIter<'a> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xorshift::XorShift64;
+
+    #[test]
+    fn bijection() {
+        let mut rng = XorShift64::new(0xDEFA_0017).unwrap();
+        for n in [1, 2, 3, 5, 17, 4097] {
+            let perm = RandomPermutation::new(&mut rng, n).unwrap();
+            assert_eq![perm.len(), n as u128];
+            assert_eq![perm.iter().size_hint(), (n as usize, Some(n as usize))];
+            let (mut seen, mut count) = ([false; 4097], 0);
+            for (i, p) in perm.iter().enumerate() {
+                assert![p < n && !seen[p as usize], "n {n}: {p}"];
+                seen[p as usize] = true;
+                assert_eq![perm.index(i as u64), Some(p)];
+                assert_eq![perm.inverse(p), Some(i as u64)];
+                count += 1;
+            }
+            assert_eq![count, n];
+            assert_eq![perm.index(n), None];
+            assert_eq![perm.inverse(n), None];
+            assert_eq![perm.index(u64::MAX), None];
+        }
+        assert![RandomPermutation::new(&mut rng, 0).is_none()];
+    }
+
+    #[test]
+    fn top_of_the_range() {
+        let mut rng = XorShift64::new(0x1234_5678_9ABC).unwrap();
+        let full = RandomPermutation::full(&mut rng);
+        assert_eq![full.len(), 1 << 64];
+        let almost = RandomPermutation::new(&mut rng, u64::MAX).unwrap();
+        assert_eq![almost.len(), u64::MAX as u128];
+        assert_eq![almost.index(u64::MAX), None];
+        assert_eq![almost.inverse(u64::MAX), None];
+        for i in (0..100).chain(u64::MAX - 100..=u64::MAX) {
+            let p = full.index(i).unwrap();
+            assert_eq![full.inverse(p), Some(i)];
+            if i < u64::MAX {
+                let p = almost.index(i).unwrap();
+                assert![p < u64::MAX];
+                assert_eq![almost.inverse(p), Some(i)];
+            }
+        }
+    }
+}
@@ -0,0 +1,377 @@
+// alazar::seq::reservoir
+//
+//! Streaming reservoir sampling.
+//
+
+use crate::{
+    distributions::Weight,
+    math::{exp, floor, ln, ln_1p},
+    prng::uniform_usize,
+    Prng,
+};
+
+/// A uniform random sample of up to `K` items from a stream of unknown length.
+///
+/// It uses Li's Algorithm L, described in [*Reservoir-sampling algorithms of
+/// time complexity O(n(1 + log(N/n)))*][li] (1994), which draws random numbers
+/// only when an item enters the reservoir, and skips a geometrically
+/// distributed number of items in between.
+///
+/// It doesn't allocate.
+///
+/// [li]: https://doi.org/10.1145/198429.198435
+///
+/// # Examples
+/// ```
+/// use alazar::{seq::Reservoir, xorshift::XorShift64};
+///
+/// let mut rng = XorShift64::default();
+/// let mut sample = Reservoir::<u32, 8>::new();
+/// sample.extend(&mut rng, 0..100_000);
+/// assert_eq![sample.len(), 8];
+/// assert_eq![sample.seen(), 100_000];
+/// ```
+#[derive(Clone, Debug)]
+pub struct Reservoir<T, const K: usize> {
+    items: [Option<T>; K],
+    len: usize,
+    seen: u64,
+    // the logarithm of the largest of K uniform keys, once full
+    ln_w: f64,
+    // the number of items to skip before the next replacement
+    skip: u64,
+}
+
+impl<T, const K: usize> Default for Reservoir<T, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const K: usize> Reservoir<T, K> {
+    /// Returns a new empty reservoir.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            items: [const { None }; K],
+            len: 0,
+            seen: 0,
+            ln_w: 0.0,
+            skip: 0,
+        }
+    }
+
+    /// Returns the number of sampled items, which is at most `K`.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no sampled items.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of sampled items.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        K
+    }
+
+    /// Returns the number of items offered to the reservoir.
+    #[must_use]
+    pub const fn seen(&self) -> u64 {
+        self.seen
+    }
+
+    /// Returns an iterator over the sampled items, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items[..self.len].iter().flatten()
+    }
+
+    /// Returns the sampled items, followed by `None` in the unused slots.
+    #[must_use]
+    pub fn into_inner(self) -> [Option<T>; K] {
+        self.items
+    }
+
+    /// Offers an `item` to the reservoir.
+    ///
+    /// Random numbers are only drawn when the item is sampled.
+    pub fn push<P: Prng>(&mut self, prng: &mut P, item: T) {
+        self.seen += 1;
+        if self.len < K {
+            self.items[self.len] = Some(item);
+            self.len += 1;
+            if self.len == K {
+                self.advance(prng);
+            }
+        } else if K > 0 {
+            if self.skip > 0 {
+                self.skip -= 1;
+            } else {
+                self.items[uniform_usize(prng, K)] = Some(item);
+                self.advance(prng);
+            }
+        }
+    }
+
+    /// Offers every item of an iterator to the reservoir.
+    pub fn extend<P: Prng, I: IntoIterator<Item = T>>(&mut self, prng: &mut P, items: I) {
+        for item in items {
+            self.push(prng, item);
+        }
+    }
+
+    /// Updates the largest key and draws the next skip length.
+    fn advance<P: Prng>(&mut self, prng: &mut P) {
+        self.ln_w += ln(prng.next_f64_open()) / K as f64;
+        let w = exp(self.ln_w);
+        // the float to integer cast saturates on huge and infinite skips
+        self.skip = floor(ln(prng.next_f64_open()) / ln_1p(-w)) as u64;
+    }
+}
+
+/// A weighted random sample of up to `K` items from a stream of unknown length.
+///
+/// Each item is sampled with a probability proportional to its weight,
+/// without replacement, following the A-ExpJ algorithm of Efraimidis and
+/// Spirakis, described in [*Weighted random sampling with a reservoir*][es]
+/// (2006). It draws random numbers only when an item enters the reservoir,
+/// and jumps over the intermediate items by subtracting their weights.
+///
+/// The keys are kept as logarithms, so that tiny keys don't underflow.
+/// It doesn't allocate.
+///
+/// [es]: https://doi.org/10.1016/j.ipl.2005.11.003
+///
+/// # Examples
+/// ```
+/// use alazar::{seq::WeightedReservoir, xorshift::XorShift64};
+///
+/// let mut rng = XorShift64::default();
+/// let mut sample = WeightedReservoir::<&str, 2>::new();
+/// sample.extend(&mut rng, [("error", 10_u32), ("warn", 5), ("info", 1), ("debug", 1)]);
+/// assert_eq![sample.len(), 2];
+/// ```
+#[derive(Clone, Debug)]
+pub struct WeightedReservoir<T, const K: usize> {
+    items: [Option<T>; K],
+    // the logarithms of the keys of the sampled items
+    keys: [f64; K],
+    len: usize,
+    seen: u64,
+    // the index of the smallest key, once full
+    min: usize,
+    // the weight left to jump over before the next replacement
+    jump: f64,
+}
+
+impl<T, const K: usize> Default for WeightedReservoir<T, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const K: usize> WeightedReservoir<T, K> {
+    /// Returns a new empty reservoir.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            items: [const { None }; K],
+            keys: [0.0; K],
+            len: 0,
+            seen: 0,
+            min: 0,
+            jump: 0.0,
+        }
+    }
+
+    /// Returns the number of sampled items, which is at most `K`.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no sampled items.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of sampled items.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        K
+    }
+
+    /// Returns the number of items offered to the reservoir.
+    #[must_use]
+    pub const fn seen(&self) -> u64 {
+        self.seen
+    }
+
+    /// Returns an iterator over the sampled items, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items[..self.len].iter().flatten()
+    }
+
+    /// Returns the sampled items, followed by `None` in the unused slots.
+    #[must_use]
+    pub fn into_inner(self) -> [Option<T>; K] {
+        self.items
+    }
+
+    /// Offers an `item` with the given `weight` to the reservoir.
+    ///
+    /// Items with a weight that is not positive and finite are never sampled.
+    /// Random numbers are only drawn when the item is sampled.
+    pub fn push<P: Prng, W: Weight>(&mut self, prng: &mut P, item: T, weight: W) {
+        self.seen += 1;
+        let weight = weight.to_f64();
+        if !(weight > 0.0 && weight.is_finite()) {
+            return;
+        }
+        if self.len < K {
+            // the key is U^(1/weight)
+            self.keys[self.len] = ln(prng.next_f64_open()) / weight;
+            self.items[self.len] = Some(item);
+            self.len += 1;
+            if self.len == K {
+                self.advance(prng);
+            }
+        } else if K > 0 {
+            self.jump -= weight;
+            if self.jump <= 0.0 {
+                // the new key is uniform in (t, 1), with t = min_key^weight,
+                // so that it's larger than the smallest key
+                let t = exp(self.keys[self.min] * weight);
+                let r = t + prng.next_f64_open() * (1.0 - t);
+                self.keys[self.min] = ln(r) / weight;
+                self.items[self.min] = Some(item);
+                self.advance(prng);
+            }
+        }
+    }
+
+    /// Offers every `(item, weight)` pair of an iterator to the reservoir.
+    pub fn extend<P: Prng, W: Weight, I: IntoIterator<Item = (T, W)>>(
+        &mut self,
+        prng: &mut P,
+        items: I,
+    ) {
+        for (item, weight) in items {
+            self.push(prng, item, weight);
+        }
+    }
+
+    /// Finds the smallest key and draws the next weight to jump over.
+    fn advance<P: Prng>(&mut self, prng: &mut P) {
+        let mut min = 0;
+        for i in 1..K {
+            if self.keys[i] < self.keys[min] {
+                min = i;
+            }
+        }
+        self.min = min;
+        // both logarithms are negative
+        self.jump = ln(prng.next_f64_open()) / self.keys[min];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_frequencies, assert_uniform},
+        xorshift::XorShift64,
+    };
+
+    #[test]
+    fn invariants() {
+        let mut rng = XorShift64::new(0xDEFA_0017).unwrap();
+        for n in 0..40 {
+            let mut sample = Reservoir::<usize, 5>::new();
+            let mut weighted = WeightedReservoir::<usize, 5>::new();
+            for i in 0..n {
+                sample.push(&mut rng, i);
+                weighted.push(&mut rng, i, 1 + i % 3);
+                assert_eq![sample.len(), (i + 1).min(5)];
+                assert_eq![weighted.len(), (i + 1).min(5)];
+            }
+            assert_eq![sample.seen(), n as u64];
+            assert_eq![weighted.seen(), n as u64];
+            // distinct items from the stream
+            let mut seen = [false; 40];
+            for &i in sample.iter() {
+                assert![i < n && !seen[i]];
+                seen[i] = true;
+            }
+            let mut seen = [false; 40];
+            for &i in weighted.iter() {
+                assert![i < n && !seen[i]];
+                seen[i] = true;
+            }
+        }
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut rng = XorShift64::default();
+        let mut sample = Reservoir::<u8, 0>::new();
+        sample.extend(&mut rng, 0..100);
+        assert![sample.is_empty()];
+        assert_eq![sample.seen(), 100];
+        let mut weighted = WeightedReservoir::<u8, 0>::new();
+        weighted.extend(&mut rng, (0..100).map(|i| (i, 1.5)));
+        assert![weighted.is_empty()];
+        assert_eq![weighted.seen(), 100];
+    }
+
+    #[test]
+    fn uniform_subsets() {
+        // the 35 subsets of 3 items out of 7
+        let mut rng = XorShift64::new(0x1234_5678_9ABC).unwrap();
+        let mut counts = [0; 128];
+        for _ in 0..70_000 {
+            let mut sample = Reservoir::<u8, 3>::new();
+            sample.extend(&mut rng, 0..7);
+            counts[sample.iter().fold(0, |mask, &i| mask | (1 << i))] += 1;
+        }
+        let subsets: [usize; 35] = {
+            let mut masks = (0..128_usize).filter(|m| m.count_ones() == 3);
+            core::array::from_fn(|_| masks.next().unwrap())
+        };
+        assert_eq![subsets.iter().map(|&m| counts[m]).sum::<usize>(), 70_000];
+        assert_uniform(&subsets.map(|m| counts[m]), "Reservoir subsets");
+    }
+
+    #[test]
+    fn uniform_inclusion() {
+        // long streams exercise the skips
+        let mut rng = XorShift64::new(42).unwrap();
+        let mut counts = [0; 1000];
+        for _ in 0..2000 {
+            let mut sample = Reservoir::<usize, 10>::new();
+            sample.extend(&mut rng, 0..1000);
+            sample.iter().for_each(|&i| counts[i] += 1);
+        }
+        assert_uniform(&counts, "Reservoir inclusion");
+    }
+
+    #[test]
+    fn weighted_frequencies() {
+        const SAMPLES: usize = 100_000;
+        let weights = [1.0, 2.0, 0.0, 3.0, f64::NAN, 4.0, -1.0, 10.0];
+        let mut rng = XorShift64::new(7).unwrap();
+        let mut counts = [0; 8];
+        for _ in 0..SAMPLES {
+            let mut sample = WeightedReservoir::<usize, 1>::new();
+            sample.extend(&mut rng, weights.iter().copied().enumerate());
+            counts[*sample.iter().next().unwrap()] += 1;
+        }
+        let weights = weights.map(|w| if w > 0.0 { w } else { 0.0 });
+        assert_frequencies(&counts, &weights, "WeightedReservoir");
+    }
+}
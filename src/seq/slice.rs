@@ -0,0 +1,297 @@
+// alazar::seq::slice
+//
+//! Sampling and shuffling of slices.
+//
+
+use crate::{prng::uniform_usize, Prng};
+#[cfg(feature = "alloc")]
+use {super::derange::derange_with, alloc::vec};
+
+/// Random sampling and shuffling methods for slices.
+///
+/// # Examples
+/// ```
+/// use alazar::{seq::SliceSample, xorshift::Xyza8a};
+///
+/// let mut rng = Xyza8a::default();
+/// let mut deck: [u8; 52] = core::array::from_fn(|i| i as u8);
+/// deck.shuffle(&mut rng);
+/// let (hand, _rest) = deck.partial_shuffle(&mut rng, 5);
+/// assert_eq![hand.len(), 5];
+/// ```
+pub trait SliceSample {
+    /// The type of the elements.
+    type Item;
+
+    /// Shuffles the slice in place, with the Fisher–Yates algorithm.
+    ///
+    /// Every permutation is equally likely.
+    fn shuffle<P: Prng>(&mut self, prng: &mut P);
+
+    /// Shuffles the slice in place into a single cycle, with Sattolo's algorithm.
+    ///
+    /// Every cyclic permutation is equally likely, so that following the moves
+    /// from any position visits every position once before coming back, and
+    /// no element stays in its position, unless the slice has a single one.
+    fn shuffle_cyclic<P: Prng>(&mut self, prng: &mut P);
+
+    /// Shuffles the slice in place into a uniformly random derangement,
+    /// so that no element stays in its position.
+    ///
+    /// Returns `false` without changes if the length is 1,
+    /// since no derangement exists.
+    ///
+    /// It allocates one flag per element.
+    /// See [`derangement`][super::derangement] for a version without allocation.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    fn derange<P: Prng>(&mut self, prng: &mut P) -> bool;
+
+    /// Shuffles `amount` elements to the front of the slice,
+    /// with a partial Fisher–Yates algorithm.
+    ///
+    /// Returns the shuffled part, which is a uniform random sample of
+    /// `amount` elements in random order, and the rest of the slice.
+    /// If `amount` is greater than the length, the whole slice is shuffled.
+    fn partial_shuffle<P: Prng>(
+        &mut self,
+        prng: &mut P,
+        amount: usize,
+    ) -> (&mut [Self::Item], &mut [Self::Item]);
+
+    /// Returns a reference to a uniformly chosen element,
+    /// or `None` if the slice is empty.
+    #[must_use]
+    fn choose<P: Prng>(&self, prng: &mut P) -> Option<&Self::Item>;
+
+    /// Returns a mutable reference to a uniformly chosen element,
+    /// or `None` if the slice is empty.
+    #[must_use]
+    fn choose_mut<P: Prng>(&mut self, prng: &mut P) -> Option<&mut Self::Item>;
+
+    /// Returns an iterator over `amount` distinct uniformly chosen elements.
+    ///
+    /// The elements are yielded in the order they have in the slice,
+    /// and each one is chosen with selection sampling (Knuth's algorithm S).
+    /// If `amount` is greater than the length, every element is yielded.
+    #[must_use]
+    fn choose_multiple<'a, P: Prng>(
+        &'a self,
+        prng: &'a mut P,
+        amount: usize,
+    ) -> ChooseMultiple<'a, Self::Item, P>;
+}
+
+impl<T> SliceSample for [T] {
+    type Item = T;
+
+    fn shuffle<P: Prng>(&mut self, prng: &mut P) {
+        for i in (1..self.len()).rev() {
+            self.swap(i, uniform_usize(prng, i + 1));
+        }
+    }
+
+    fn shuffle_cyclic<P: Prng>(&mut self, prng: &mut P) {
+        for i in (1..self.len()).rev() {
+            self.swap(i, uniform_usize(prng, i));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn derange<P: Prng>(&mut self, prng: &mut P) -> bool {
+        let len = self.len();
+        derange_with(
+            prng,
+            len,
+            |(slice, _): &mut (&mut [T], _), i, j| slice.swap(i, j),
+            |(_, marks), j| marks[j],
+            |(_, marks), j| marks[j] = true,
+            &mut (self, vec![false; len]),
+        )
+    }
+
+    fn partial_shuffle<P: Prng>(&mut self, prng: &mut P, amount: usize) -> (&mut [T], &mut [T]) {
+        let len = self.len();
+        let amount = amount.min(len);
+        for i in 0..amount {
+            self.swap(i, i + uniform_usize(prng, len - i));
+        }
+        self.split_at_mut(amount)
+    }
+
+    #[inline]
+    fn choose<P: Prng>(&self, prng: &mut P) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(&self[uniform_usize(prng, self.len())])
+        }
+    }
+
+    #[inline]
+    fn choose_mut<P: Prng>(&mut self, prng: &mut P) -> Option<&mut T> {
+        if self.is_empty() {
+            None
+        } else {
+            let index = uniform_usize(prng, self.len());
+            Some(&mut self[index])
+        }
+    }
+
+    #[inline]
+    fn choose_multiple<'a, P: Prng>(
+        &'a self,
+        prng: &'a mut P,
+        amount: usize,
+    ) -> ChooseMultiple<'a, T, P> {
+        ChooseMultiple {
+            slice: self,
+            prng,
+            index: 0,
+            remaining: amount.min(self.len()),
+        }
+    }
+}
+
+/// An iterator over distinct uniformly chosen elements of a slice.
+///
+/// This `struct` is created by [`SliceSample::choose_multiple`].
+#[derive(Debug)]
+pub struct ChooseMultiple<'a, T, P: Prng> {
+    slice: &'a [T],
+    prng: &'a mut P,
+    index: usize,
+    remaining: usize,
+}
+
+impl<'a, T, P: Prng> Iterator for ChooseMultiple<'a, T, P> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        while self.remaining > 0 {
+            let (index, left) = (self.index, self.slice.len() - self.index);
+            self.index += 1;
+            // selects each element with probability remaining / left
+            if uniform_usize(self.prng, left) < self.remaining {
+                self.remaining -= 1;
+                return Some(&self.slice[index]);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, P: Prng> ExactSizeIterator for ChooseMultiple<'_, T, P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::assert_uniform,
+        xorshift::{XorShift64, Xyza8a},
+    };
+
+    /// Returns the rank of a permutation of `0..perm.len()`, from its Lehmer code.
+    fn rank(perm: &[usize]) -> usize {
+        perm.iter().enumerate().fold(0, |acc, (i, &p)| {
+            let smaller_after = perm[i + 1..].iter().filter(|&&q| q < p).count();
+            acc * (perm.len() - i) + smaller_after
+        })
+    }
+
+    /// Checks the uniformity of each method over its outcomes with `prng`.
+    fn check_uniform<P: Prng>(prng: &mut P, what: &str) {
+        const ROUNDS: usize = 1000;
+        // the 24 permutations of 4 elements
+        let mut counts = [0; 24];
+        for _ in 0..24 * ROUNDS {
+            let mut perm = [0, 1, 2, 3];
+            perm.shuffle(prng);
+            counts[rank(&perm)] += 1;
+        }
+        assert_uniform(&counts, what);
+
+        // the 20 ordered pairs of 5 elements, indexed by the 2 elements
+        let mut counts = [0; 25];
+        for _ in 0..20 * ROUNDS {
+            let mut items = [0, 1, 2, 3, 4];
+            let (head, _) = items.partial_shuffle(prng, 2);
+            counts[head[0] * 5 + head[1]] += 1;
+        }
+        let pairs: [usize; 20] =
+            core::array::from_fn(|i| counts[(0..25).filter(|j| j / 5 != j % 5).nth(i).unwrap()]);
+        assert_uniform(&pairs, what);
+
+        // the 10 subsets of 2 of 5 elements, yielded in ascending order
+        let mut counts = [0; 25];
+        let items = [0, 1, 2, 3, 4];
+        for _ in 0..10 * ROUNDS {
+            let mut chosen = items.choose_multiple(prng, 2);
+            let (a, b) = (chosen.next().unwrap(), chosen.next().unwrap());
+            assert![a < b && chosen.next().is_none()];
+            counts[a * 5 + b] += 1;
+        }
+        let subsets: [usize; 10] =
+            core::array::from_fn(|i| counts[(0..25).filter(|j| j / 5 < j % 5).nth(i).unwrap()]);
+        assert_uniform(&subsets, what);
+    }
+
+    #[test]
+    fn uniform_64_bits() {
+        check_uniform(&mut XorShift64::new(0xDEFA_0017).unwrap(), "XorShift64");
+    }
+
+    #[test]
+    fn uniform_8_bits() {
+        check_uniform(&mut Xyza8a::default(), "Xyza8a");
+    }
+
+    #[test]
+    fn permutations() {
+        let mut rng = XorShift64::new(0x1234_5678_9ABC).unwrap();
+        let mut items: [usize; 100] = core::array::from_fn(|i| i);
+        items.shuffle(&mut rng);
+        let mut sorted = items;
+        sorted.sort_unstable();
+        assert![sorted.iter().copied().eq(0..100)];
+
+        let (head, rest) = items.partial_shuffle(&mut rng, 30);
+        assert_eq![(head.len(), rest.len()), (30, 70)];
+        let mut sorted = items;
+        sorted.sort_unstable();
+        assert![sorted.iter().copied().eq(0..100)];
+
+        let (head, rest) = items.partial_shuffle(&mut rng, 101);
+        assert_eq![(head.len(), rest.len()), (100, 0)];
+
+        let chosen = items.choose_multiple(&mut rng, 40);
+        assert_eq![chosen.len(), 40];
+        let positions = chosen.map(|x| items.iter().position(|y| y == x).unwrap());
+        let mut prev = None;
+        for position in positions {
+            assert![prev < Some(position)];
+            prev = Some(position);
+        }
+        assert![items.choose_multiple(&mut rng, 200).eq(items.iter())];
+    }
+
+    #[test]
+    fn empty() {
+        let mut rng = XorShift64::new(42).unwrap();
+        let mut empty: [u8; 0] = [];
+        empty.shuffle(&mut rng);
+        assert_eq![
+            empty.partial_shuffle(&mut rng, 3),
+            (&mut [][..], &mut [][..])
+        ];
+        assert_eq![empty.choose(&mut rng), None];
+        assert_eq![empty.choose_mut(&mut rng), None];
+        assert_eq![empty.choose_multiple(&mut rng, 3).next(), None];
+        assert_eq![[7].choose(&mut rng), Some(&7)];
+    }
+}
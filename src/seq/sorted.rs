@@ -0,0 +1,220 @@
+// alazar::seq::sorted
+//
+//! Sequential sampling of sorted positions.
+//
+
+use crate::{
+    math::{exp, floor, ln},
+    prng::uniform_u64,
+    Prng,
+};
+
+/// The largest population size, so that every position is an exact `f64`.
+const MAX_POPULATION: u64 = 1 << 53;
+
+/// Returns an iterator over `k` distinct positions from `0..n`, sampled
+/// uniformly without replacement, in ascending order.
+///
+/// The positions are generated one at a time in *O(k)* expected time and
+/// constant memory, with Vitter's Algorithm D, described in [*An efficient
+/// algorithm for sequential random sampling*][vitter] (1987). It falls back
+/// to the simpler Algorithm A when the remaining positions to select are
+/// more than a thirteenth of the remaining population, as recommended there.
+///
+/// Returns `None` if `k` is greater than `n`, or `n` is greater than `2^53`.
+///
+/// [vitter]: https://doi.org/10.1145/23002.23003
+///
+/// # Examples
+/// ```
+/// use alazar::{seq::sample_sorted, xorshift::XorShift64};
+///
+/// let mut rng = XorShift64::default();
+/// let mut prev = None;
+/// for pos in sample_sorted(&mut rng, 1 << 40, 1000).unwrap() {
+///     assert!(prev < Some(pos));
+///     prev = Some(pos);
+/// }
+/// ```
+pub fn sample_sorted<P: Prng>(prng: &mut P, n: u64, k: u64) -> Option<SortedSample<'_, P>> {
+    if k > n || n > MAX_POPULATION {
+        return None;
+    }
+    Some(SortedSample {
+        prng,
+        next: 0,
+        remaining: n,
+        left: k,
+        vprime: None,
+    })
+}
+
+/// An iterator over sorted positions sampled without replacement.
+///
+/// This `struct` is created by [`sample_sorted`].
+#[derive(Debug)]
+pub struct SortedSample<'a, P: Prng> {
+    prng: &'a mut P,
+    // the first position not yet skipped or selected
+    next: u64,
+    // the number of positions from `next` to the end
+    remaining: u64,
+    // the number of positions left to select
+    left: u64,
+    // the pending uniform variate of Algorithm D, raised to 1/left
+    vprime: Option<f64>,
+}
+
+impl<P: Prng> Iterator for SortedSample<'_, P> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let skip = match self.left {
+            0 => return None,
+            1 => uniform_u64(self.prng, self.remaining),
+            left if left * 13 < self.remaining => self.skip_d(),
+            _ => {
+                self.vprime = None;
+                self.skip_a()
+            }
+        };
+        let pos = self.next + skip;
+        self.next = pos + 1;
+        self.remaining -= skip + 1;
+        self.left -= 1;
+        Some(pos)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = usize::try_from(self.left).unwrap_or(usize::MAX);
+        (left, usize::try_from(self.left).ok())
+    }
+}
+
+impl<P: Prng> SortedSample<'_, P> {
+    /// Returns the number of positions to skip before the next selected one,
+    /// with Algorithm A, in *O(skip)* time.
+    fn skip_a(&mut self) -> u64 {
+        let v = self.prng.next_f64();
+        let mut skip = 0;
+        let mut top = (self.remaining - self.left) as f64;
+        let mut total = self.remaining as f64;
+        let mut quot = top / total;
+        while quot > v {
+            skip += 1;
+            top -= 1.0;
+            total -= 1.0;
+            quot *= top / total;
+        }
+        skip
+    }
+
+    /// Returns the number of positions to skip before the next selected one,
+    /// with Algorithm D, in constant expected time.
+    ///
+    /// It needs at least 2 positions left to select.
+    fn skip_d(&mut self) -> u64 {
+        let n = self.left as f64;
+        let total = self.remaining as f64;
+        let (ninv, nmin1inv) = (1.0 / n, 1.0 / (n - 1.0));
+        let qu1 = total - n + 1.0;
+        let prng = &mut *self.prng;
+        let mut vprime = match self.vprime.take() {
+            Some(vprime) => vprime,
+            None => exp(ln(prng.next_f64_open()) * ninv),
+        };
+        loop {
+            // D2: generate X from the approximating continuous distribution
+            let (x, s) = loop {
+                let x = total * (1.0 - vprime);
+                let s = floor(x);
+                if s < qu1 {
+                    break (x, s);
+                }
+                vprime = exp(ln(prng.next_f64_open()) * ninv);
+            };
+            // D3: accept cheaply if U is below the squeeze function,
+            // and then reuse the ratio as the next vprime
+            let y1 = exp(ln(prng.next_f64_open() * total / qu1) * nmin1inv);
+            vprime = y1 * (1.0 - x / total) * (qu1 / (qu1 - s));
+            if vprime <= 1.0 {
+                self.vprime = Some(vprime);
+                return s as u64;
+            }
+            // D4: otherwise accept if U is below the exact density ratio
+            let mut y2 = 1.0;
+            let mut top = total - 1.0;
+            let (mut bottom, limit) = if n - 1.0 > s {
+                (total - n, total - s)
+            } else {
+                (total - s - 1.0, qu1)
+            };
+            let mut t = total - 1.0;
+            while t >= limit {
+                y2 = (y2 * top) / bottom;
+                top -= 1.0;
+                bottom -= 1.0;
+                t -= 1.0;
+            }
+            if total / (total - x) >= y1 * exp(ln(y2) * nmin1inv) {
+                self.vprime = Some(exp(ln(prng.next_f64_open()) * nmin1inv));
+                return s as u64;
+            }
+            vprime = exp(ln(prng.next_f64_open()) * ninv);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xorshift::XorShift64;
+
+    /// Checks that the sample has exactly `k` strictly increasing positions below `n`.
+    fn check_sample(n: u64, k: u64, seed: u64) {
+        let mut rng = XorShift64::new(seed).unwrap();
+        let mut sample = sample_sorted(&mut rng, n, k).unwrap();
+        assert_eq![sample.size_hint(), (k as usize, Some(k as usize))];
+        let (mut count, mut prev) = (0, None);
+        for pos in sample.by_ref() {
+            assert![prev < Some(pos), "n {n}, k {k}: {pos} after {prev:?}"];
+            assert![pos < n, "n {n}, k {k}: {pos}"];
+            prev = Some(pos);
+            count += 1;
+        }
+        assert_eq![count, k];
+        assert_eq![sample.next(), None];
+    }
+
+    #[test]
+    fn algorithm_d() {
+        // with more than 13 positions in the population for each selected one
+        check_sample(100_000, 100, 0xDEFA_0017);
+        check_sample(1 << 40, 1000, 0x1234_5678_9ABC);
+        check_sample(MAX_POPULATION, 50, 42);
+        check_sample(27, 2, 7);
+        // which keeps its pending variate between positions
+        let mut rng = XorShift64::default();
+        let mut sample = sample_sorted(&mut rng, 100_000, 100).unwrap();
+        let _ = sample.next();
+        assert![sample.vprime.is_some()];
+    }
+
+    #[test]
+    fn algorithm_a() {
+        check_sample(100, 50, 0xDEFA_0017);
+        check_sample(1000, 999, 0x1234_5678_9ABC);
+        check_sample(13, 1, 42);
+    }
+
+    #[test]
+    fn edge_cases() {
+        for (n, k) in [(0, 0), (1, 1), (1000, 1000), (1000, 0), (MAX_POPULATION, 0)] {
+            check_sample(n, k, 0xDEFA_0017);
+        }
+        let mut rng = XorShift64::default();
+        assert![sample_sorted(&mut rng, 10, 11).is_none()];
+        assert![sample_sorted(&mut rng, MAX_POPULATION + 1, 1).is_none()];
+    }
+}
@@ -0,0 +1,76 @@
+// alazar::test_utils
+//
+//! Helpers shared by the tests.
+//
+
+use crate::math::{abs, sqrt};
+
+/// Asserts that the `counts` of equally likely outcomes are uniform,
+/// with a chi-square test at the 0.1% significance level.
+pub(crate) fn assert_uniform(counts: &[usize], what: &str) {
+    let total: usize = counts.iter().sum();
+    let expected = total as f64 / counts.len() as f64;
+    let chi2: f64 = counts
+        .iter()
+        .map(|&c| (c as f64 - expected) * (c as f64 - expected) / expected)
+        .sum();
+    // the Wilson–Hilferty approximation of the 99.9th percentile
+    let dof = (counts.len() - 1) as f64;
+    let h = 2.0 / (9.0 * dof);
+    let root = 1.0 - h + 3.09 * sqrt(h);
+    let critical = dof * root * root * root;
+    assert!(
+        chi2 < critical,
+        "{what}: chi-square {chi2} with {dof} degrees of freedom, over {critical}"
+    );
+}
+
+/// Asserts that each of the `counts` is within 5 standard errors of the
+/// binomial count expected from its weight, and zero for a zero weight.
+pub(crate) fn assert_frequencies(counts: &[usize], weights: &[f64], what: &str) {
+    let n = counts.iter().sum::<usize>() as f64;
+    let total: f64 = weights.iter().sum();
+    for (i, (&count, &w)) in counts.iter().zip(weights).enumerate() {
+        let p = w / total;
+        let error = sqrt(n * p * (1.0 - p));
+        let expected = n * p;
+        assert!(
+            abs(count as f64 - expected) <= 5.0 * error,
+            "{what}, index {i}: count {count}, expected {expected}"
+        );
+    }
+}
+
+/// Asserts that the mean and variance of 100000 draws from `sample` are within
+/// 5 standard errors of the expected `mean` and `variance`.
+///
+/// The variance of the sample variance is about `σ⁴(2 + kurtosis) / n`,
+/// where `kurtosis` is the excess kurtosis of the distribution.
+pub(crate) fn assert_moments(
+    mut sample: impl FnMut() -> f64,
+    mean: f64,
+    variance: f64,
+    kurtosis: f64,
+    what: core::fmt::Arguments,
+) {
+    const SAMPLES: usize = 100_000;
+    let (mut sum, mut sum2) = (0.0, 0.0);
+    for _ in 0..SAMPLES {
+        let x = sample();
+        sum += x;
+        sum2 += x * x;
+    }
+    let n = SAMPLES as f64;
+    let sample_mean = sum / n;
+    let sample_variance = sum2 / n - sample_mean * sample_mean;
+    let mean_error = sqrt(variance / n);
+    let variance_error = variance * sqrt((2.0 + kurtosis) / n);
+    assert!(
+        abs(sample_mean - mean) < 5.0 * mean_error,
+        "{what}: mean {sample_mean}, expected {mean}"
+    );
+    assert!(
+        abs(sample_variance - variance) < 5.0 * variance_error,
+        "{what}: variance {sample_variance}, expected {variance}"
+    );
+}
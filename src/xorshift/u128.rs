@@ -3,6 +3,7 @@
 //! 128-bit versions of XorShift generators.
 //
 
+use crate::{misc::SplitMix64, prng::impl_prng};
 use devela::convert::{
     u128_into_u32_le, u128_into_u64_le, u32_from_u16_le, u32_from_u8_le, u64_from_u16_le,
     u64_from_u32_le, u64_from_u8_le, u64_into_u32_le,
@@ -164,6 +165,15 @@ impl XorShift128 {
             u32_from_u8_le([seeds[12], seeds[13], seeds[14], seeds[15]]),
         ])
     }
+
+    /// Returns a seeded `XorShift128` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_u32s(seed))
+    }
 }
 
 /// The `XorShift128+` pseudo-random number generator.
@@ -314,48 +324,28 @@ impl XorShift128p {
             u64_from_u8_le([s[8], s[9], s[10], s[11], s[12], s[13], s[14], s[15]]),
         ])
     }
+
+    /// Returns a seeded `XorShift128+` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_u64s(seed))
+    }
 }
 
+impl_prng![XorShift128: u64, current_u64, next_u64];
+impl_prng![XorShift128p: u64, current_u64, next_u64];
+
 #[cfg(feature = "rand_core")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
 mod impl_rand {
     use super::{XorShift128, XorShift128p};
-    use rand_core::{Error, RngCore, SeedableRng};
-
-    impl RngCore for XorShift128 {
-        /// Returns the next random `u32`,
-        /// from the first 32-bits of `next_u64`.
-        fn next_u32(&mut self) -> u32 {
-            (self.next_u64() & 0xFFFF_FFFF) as u32
-        }
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
 
-        /// Returns the next random `u64`.
-        fn next_u64(&mut self) -> u64 {
-            self.next_u64()
-        }
-
-        fn fill_bytes(&mut self, dest: &mut [u8]) {
-            let mut i = 0;
-            while i < dest.len() {
-                let random_u64 = self.next_u64();
-                let bytes = random_u64.to_le_bytes();
-                let remaining = dest.len() - i;
-
-                if remaining >= 8 {
-                    dest[i..i + 8].copy_from_slice(&bytes);
-                    i += 8;
-                } else {
-                    dest[i..].copy_from_slice(&bytes[..remaining]);
-                    break;
-                }
-            }
-        }
-
-        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-            self.fill_bytes(dest);
-            Ok(())
-        }
-    }
+    impl_rand_core![XorShift128];
 
     impl SeedableRng for XorShift128 {
         type Seed = [u8; 16];
@@ -380,40 +370,7 @@ mod impl_rand {
         }
     }
 
-    impl RngCore for XorShift128p {
-        /// Returns the next random `u32`,
-        /// from the first 32-bits of `next_u64`.
-        fn next_u32(&mut self) -> u32 {
-            (self.next_u64() & 0xFFFF_FFFF) as u32
-        }
-
-        /// Returns the next random `u64`.
-        fn next_u64(&mut self) -> u64 {
-            self.next_u64()
-        }
-
-        fn fill_bytes(&mut self, dest: &mut [u8]) {
-            let mut i = 0;
-            while i < dest.len() {
-                let random_u64 = self.next_u64();
-                let bytes = random_u64.to_le_bytes();
-                let remaining = dest.len() - i;
-
-                if remaining >= 8 {
-                    dest[i..i + 8].copy_from_slice(&bytes);
-                    i += 8;
-                } else {
-                    dest[i..].copy_from_slice(&bytes[..remaining]);
-                    break;
-                }
-            }
-        }
-
-        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-            self.fill_bytes(dest);
-            Ok(())
-        }
-    }
+    impl_rand_core![XorShift128p];
 
     impl SeedableRng for XorShift128p {
         type Seed = [u8; 16];
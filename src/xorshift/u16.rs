@@ -3,6 +3,7 @@
 //! 16-bit versions of XorShift generators.
 //
 
+use crate::prng::impl_prng;
 use devela::convert::u16_from_u8_le;
 
 /// The `XorShift16` pseudo-random number generator.
@@ -113,52 +114,16 @@ impl XorShift16 {
     }
 }
 
+impl_prng![XorShift16: u16, current_u16, next_u16];
+
 #[cfg(feature = "rand_core")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
 mod impl_rand {
     use super::XorShift16;
-    use devela::convert::{u32_from_u16_le, u64_from_u16_le};
-    use rand_core::{Error, RngCore, SeedableRng};
-
-    impl RngCore for XorShift16 {
-        /// Returns the next 2 × random `u16` combined as a single `u32`.
-        fn next_u32(&mut self) -> u32 {
-            u32_from_u16_le([self.next_u16(), self.next_u16()])
-        }
-
-        /// Returns the next 4 × random `u16` combined as a single `u64`.
-        fn next_u64(&mut self) -> u64 {
-            u64_from_u16_le([
-                self.next_u16(),
-                self.next_u16(),
-                self.next_u16(),
-                self.next_u16(),
-            ])
-        }
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
 
-        fn fill_bytes(&mut self, dest: &mut [u8]) {
-            let mut i = 0;
-            while i < dest.len() {
-                let random_u16 = self.next_u16();
-                let bytes = random_u16.to_le_bytes();
-                let remaining = dest.len() - i;
-
-                if remaining >= 2 {
-                    dest[i] = bytes[0];
-                    dest[i + 1] = bytes[1];
-                    i += 2;
-                } else {
-                    dest[i] = bytes[0];
-                    i += 1;
-                }
-            }
-        }
-
-        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-            self.fill_bytes(dest);
-            Ok(())
-        }
-    }
+    impl_rand_core![XorShift16];
 
     impl SeedableRng for XorShift16 {
         type Seed = [u8; 2];
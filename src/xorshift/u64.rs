@@ -3,6 +3,7 @@
 //! 64-bit versions of XorShift generators.
 //
 
+use crate::prng::impl_prng;
 use devela::convert::{u64_from_u16_le, u64_from_u32_le, u64_from_u8_le};
 
 /// The `XorShift64` pseudo-random number generator.
@@ -65,6 +66,14 @@ impl XorShift64 {
     /// Returns the current random `u64`.
     #[inline(always)]
     #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    #[deprecated(since = "0.0.3", note = "use `current_u64` instead")]
     pub const fn current_u16(&self) -> u64 {
         self.0
     }
@@ -128,46 +137,16 @@ impl XorShift64 {
     }
 }
 
+impl_prng![XorShift64: u64, current_u64, next_u64];
+
 #[cfg(feature = "rand_core")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
 mod impl_rand {
     use super::XorShift64;
-    use rand_core::{Error, RngCore, SeedableRng};
-
-    impl RngCore for XorShift64 {
-        /// Returns the next random `u32`,
-        /// from the first 32-bits of `next_u64`.
-        fn next_u32(&mut self) -> u32 {
-            (self.next_u64() & 0xFFFF_FFFF) as u32
-        }
-
-        /// Returns the next random `u64`.
-        fn next_u64(&mut self) -> u64 {
-            self.next_u64()
-        }
-
-        fn fill_bytes(&mut self, dest: &mut [u8]) {
-            let mut i = 0;
-            while i < dest.len() {
-                let random_u64 = self.next_u64();
-                let bytes = random_u64.to_le_bytes();
-                let remaining = dest.len() - i;
-
-                if remaining >= 8 {
-                    dest[i..i + 8].copy_from_slice(&bytes);
-                    i += 8;
-                } else {
-                    dest[i..].copy_from_slice(&bytes[..remaining]);
-                    break;
-                }
-            }
-        }
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
 
-        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-            self.fill_bytes(dest);
-            Ok(())
-        }
-    }
+    impl_rand_core![XorShift64];
 
     impl SeedableRng for XorShift64 {
         type Seed = [u8; 8];
@@ -3,6 +3,8 @@
 //! 8-bit versions of XorShift generators.
 //
 
+use crate::prng::impl_prng;
+
 /// The `XorShift8` pseudo-random number generator.
 ///
 /// It has an 8-bit state and generates 8-bit numbers.
@@ -211,48 +213,17 @@ impl<const SH1: usize, const SH2: usize, const SH3: usize> XorShift8Custom<SH1,
     }
 }
 
+impl_prng![XorShift8: u8, current_u8, next_u8];
+impl_prng![@[const SH1: usize, const SH2: usize, const SH3: usize] XorShift8Custom<SH1, SH2, SH3>: u8, current_u8, next_u8];
+
 #[cfg(feature = "rand_core")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
 mod impl_rand {
     use super::{XorShift8, XorShift8Custom};
-    use rand_core::{Error, RngCore, SeedableRng};
-
-    impl RngCore for XorShift8 {
-        /// Returns the next 4 × random `u8` combined as a single `u32`.
-        fn next_u32(&mut self) -> u32 {
-            u32::from_le_bytes([
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-            ])
-        }
-
-        /// Returns the next 8 × random `u8` combined as a single `u64`.
-        fn next_u64(&mut self) -> u64 {
-            u64::from_le_bytes([
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-            ])
-        }
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
 
-        fn fill_bytes(&mut self, dest: &mut [u8]) {
-            for byte in dest {
-                *byte = self.next_u8();
-            }
-        }
-
-        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-            self.fill_bytes(dest);
-            Ok(())
-        }
-    }
+    impl_rand_core![XorShift8];
 
     impl SeedableRng for XorShift8 {
         type Seed = [u8; 1];
@@ -268,44 +239,7 @@ mod impl_rand {
         }
     }
 
-    impl<const SH1: usize, const SH2: usize, const SH3: usize> RngCore
-        for XorShift8Custom<SH1, SH2, SH3>
-    {
-        /// Returns the next 4 × random `u8` combined as a single `u32`.
-        fn next_u32(&mut self) -> u32 {
-            u32::from_le_bytes([
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-            ])
-        }
-
-        /// Returns the next 8 × random `u8` combined as a single `u64`.
-        fn next_u64(&mut self) -> u64 {
-            u64::from_le_bytes([
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-            ])
-        }
-
-        fn fill_bytes(&mut self, dest: &mut [u8]) {
-            for byte in dest {
-                *byte = self.next_u8();
-            }
-        }
-
-        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-            self.fill_bytes(dest);
-            Ok(())
-        }
-    }
+    impl_rand_core![@[const SH1: usize, const SH2: usize, const SH3: usize] XorShift8Custom<SH1, SH2, SH3>];
 
     impl<const SH1: usize, const SH2: usize, const SH3: usize> SeedableRng
         for XorShift8Custom<SH1, SH2, SH3>
@@ -1,8 +1,9 @@
-// alazar::xorshift::other::xyza8
+// alazar::xorshift::xyza8
 //
-//!
+//! 8-bit generators with a 32-bit state, loosely based on XorShift.
 //
 
+use crate::{misc::SplitMix64, prng::impl_prng};
 use devela::convert::{u16_into_u8_le, u32_into_u8_le};
 
 /// A simple 8-bit pseudo-random number generator with 32-bit of state,
@@ -111,6 +112,15 @@ impl Xyza8a {
     pub const fn new4_u8(seeds: [u8; 4]) -> Self {
         Self::new(seeds)
     }
+
+    /// Returns a seeded `Xyza8a` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new(SplitMix64::seed_u8s(seed))
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -212,50 +222,28 @@ impl Xyza8b {
     pub const fn new4_u8(seeds: [u8; 4]) -> Self {
         Self::new(seeds)
     }
+
+    /// Returns a seeded `Xyza8b` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new(SplitMix64::seed_u8s(seed))
+    }
 }
 
+impl_prng![Xyza8a: u8, current_u8, next_u8];
+impl_prng![Xyza8b: u8, current_u8, next_u8];
+
 #[cfg(feature = "rand_core")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
 mod impl_rand {
     use super::{Xyza8a, Xyza8b};
-    use rand_core::{Error, RngCore, SeedableRng};
-
-    impl RngCore for Xyza8a {
-        /// Returns the next 4 × random `u8` combined as a single `u32`.
-        fn next_u32(&mut self) -> u32 {
-            u32::from_le_bytes([
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-            ])
-        }
-
-        /// Returns the next 8 × random `u8` combined as a single `u64`.
-        fn next_u64(&mut self) -> u64 {
-            u64::from_le_bytes([
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-            ])
-        }
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
 
-        fn fill_bytes(&mut self, dest: &mut [u8]) {
-            for byte in dest {
-                *byte = self.next_u8();
-            }
-        }
-
-        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-            self.fill_bytes(dest);
-            Ok(())
-        }
-    }
+    impl_rand_core![Xyza8a];
 
     impl SeedableRng for Xyza8a {
         type Seed = [u8; 4];
@@ -265,42 +253,7 @@ mod impl_rand {
         }
     }
 
-    impl RngCore for Xyza8b {
-        /// Returns the next 4 × random `u8` combined as a single `u32`.
-        fn next_u32(&mut self) -> u32 {
-            u32::from_le_bytes([
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-            ])
-        }
-
-        /// Returns the next 8 × random `u8` combined as a single `u64`.
-        fn next_u64(&mut self) -> u64 {
-            u64::from_le_bytes([
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-                self.next_u8(),
-            ])
-        }
-
-        fn fill_bytes(&mut self, dest: &mut [u8]) {
-            for byte in dest {
-                *byte = self.next_u8();
-            }
-        }
-
-        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-            self.fill_bytes(dest);
-            Ok(())
-        }
-    }
+    impl_rand_core![Xyza8b];
 
     impl SeedableRng for Xyza8b {
         type Seed = [u8; 4];
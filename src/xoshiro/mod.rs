@@ -0,0 +1,29 @@
+// alazar::xoshiro
+//
+//! Pseudo-random number generators of the [xoshiro / xoroshiro] family.
+//!
+//! This module defines several types:
+//! - *xoshiro* generators with a 256-bit state:
+//!   ([`Xoshiro256StarStar`], [`Xoshiro256PlusPlus`], [`Xoshiro256Plus`]).
+//! - *xoshiro* generators with a 128-bit state:
+//!   ([`Xoshiro128StarStar`], [`Xoshiro128PlusPlus`]).
+//! - *xoroshiro* generators with a 128-bit state:
+//!   ([`Xoroshiro128PlusPlus`], [`Xoroshiro128StarStar`]).
+//! - a *xoroshiro* generator with a 64-bit state: ([`Xoroshiro64Star`]).
+//!
+//! They're the successors of the *XorShift* generators by the same authors.
+//! All but the smallest one have `jump` and `long_jump` methods, for
+//! splitting their sequence into non-overlapping parallel streams.
+//!
+//! [xoshiro / xoroshiro]: https://prng.di.unimi.it/
+//
+
+mod u128;
+mod u256;
+mod u64;
+
+pub use u128::{
+    Xoroshiro128PlusPlus, Xoroshiro128StarStar, Xoshiro128PlusPlus, Xoshiro128StarStar,
+};
+pub use u256::{Xoshiro256Plus, Xoshiro256PlusPlus, Xoshiro256StarStar};
+pub use u64::Xoroshiro64Star;
@@ -0,0 +1,1075 @@
+// alazar::xoshiro::u128
+//
+//! 128-bit versions of xoshiro and xoroshiro generators.
+//
+
+use crate::{misc::SplitMix64, prng::impl_prng};
+use devela::convert::{
+    u128_into_u32_le, u128_into_u64_le, u32_from_u16_le, u32_from_u8_le, u64_from_u16_le,
+    u64_from_u32_le, u64_from_u8_le, u64_into_u32_le,
+};
+
+/// The `Xoshiro128**` pseudo-random number generator.
+///
+/// It has a 128-bit state and generates 32-bit numbers.
+///
+/// It's an all-purpose generator with excellent statistical properties,
+/// and the recommended default of the family for 32-bit outputs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Xoshiro128StarStar([u32; 4]);
+
+impl Default for Xoshiro128StarStar {
+    fn default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Xoshiro128StarStar {
+    const DEFAULT_SEED: [u32; 4] = [0xDEFA0017; 4];
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+
+    #[cold]
+    #[inline]
+    #[allow(dead_code)]
+    const fn cold_path_default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+
+    /// Advances the generator with the given jump polynomial.
+    const fn jump_with(&mut self, poly: [u32; 4]) {
+        let mut state = [0; 4];
+        let mut i = 0;
+        while i < 4 {
+            let mut b = 0;
+            while b < u32::BITS {
+                if poly[i] & (1 << b) != 0 {
+                    state[0] ^= self.0[0];
+                    state[1] ^= self.0[1];
+                    state[2] ^= self.0[2];
+                    state[3] ^= self.0[3];
+                }
+                *self = self.next_new();
+                b += 1;
+            }
+            i += 1;
+        }
+        self.0 = state;
+    }
+}
+
+impl Xoshiro128StarStar {
+    /// Returns a seeded `Xoshiro128**` generator from the given 4 × 32-bit seeds.
+    ///
+    /// Returns `None` if all given seeds are `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(seeds: [u32; 4]) -> Option<Self> {
+        if (seeds[0] | seeds[1] | seeds[2] | seeds[3]) == 0 {
+            Self::cold_path_result()
+        } else {
+            Some(Self(seeds))
+        }
+    }
+
+    /// Returns a seeded `Xoshiro128**` generator from the given 4 × 32-bit seeds,
+    /// unchecked.
+    ///
+    /// The seeds must not be all `0`, otherwise every result will also be `0`.
+    ///
+    /// # Panics
+    /// Panics in debug if the seeds are all `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(seeds: [u32; 4]) -> Self {
+        debug_assert![
+            (seeds[0] | seeds[1] | seeds[2] | seeds[3]) != 0,
+            "Seeds must be non-zero"
+        ];
+        Self(seeds)
+    }
+
+    /// Returns the current random `u32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u32(&self) -> u32 {
+        let s = self.0;
+        s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9)
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        let result = self.current_u32();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut s = self.0;
+        let t = s[1] << 9;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(11);
+        Self(s)
+    }
+
+    /// Advances the generator `2^64` steps, as if calling
+    /// [`next_u32`][Self::next_u32] that many times.
+    ///
+    /// It can be used to generate `2^64` non-overlapping
+    /// subsequences for parallel computations.
+    pub const fn jump(&mut self) {
+        const JUMP: [u32; 4] = [0x8764_000B, 0xF542_D2D3, 0x6FA0_35C3, 0x77F2_DB5B];
+        self.jump_with(JUMP);
+    }
+
+    /// Advances the generator `2^96` steps, as if calling
+    /// [`next_u32`][Self::next_u32] that many times.
+    ///
+    /// It can be used to generate `2^32` starting points,
+    /// from each of which [`jump`][Self::jump] will generate
+    /// `2^64` non-overlapping subsequences.
+    pub const fn long_jump(&mut self) {
+        const LONG_JUMP: [u32; 4] = [0xB523_952E, 0x0B6F_099F, 0xCCF5_A0EF, 0x1C58_0662];
+        self.jump_with(LONG_JUMP);
+    }
+}
+
+/// # Extra constructors
+impl Xoshiro128StarStar {
+    /// Returns a seeded `Xoshiro128**` generator from the given 128-bit seed.
+    ///
+    /// The seed will be split in little endian order.
+    #[inline]
+    pub const fn new1_u128(seed: u128) -> Option<Self> {
+        Self::new(u128_into_u32_le(seed))
+    }
+
+    /// Returns a seeded `Xoshiro128**` generator from the given 2 × 64-bit seeds.
+    ///
+    /// The seeds will be split in little endian order.
+    #[inline]
+    pub const fn new2_u64(seeds: [u64; 2]) -> Option<Self> {
+        let [x, y] = u64_into_u32_le(seeds[0]);
+        let [z, a] = u64_into_u32_le(seeds[1]);
+        Self::new([x, y, z, a])
+    }
+
+    /// Returns a seeded `Xoshiro128**` generator from the given 4 × 32-bit seeds.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new4_u32(seeds: [u32; 4]) -> Option<Self> {
+        Self::new(seeds)
+    }
+
+    /// Returns a seeded `Xoshiro128**` generator from the given 8 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u16(seeds: [u16; 8]) -> Option<Self> {
+        Self::new([
+            u32_from_u16_le([seeds[0], seeds[1]]),
+            u32_from_u16_le([seeds[2], seeds[3]]),
+            u32_from_u16_le([seeds[4], seeds[5]]),
+            u32_from_u16_le([seeds[6], seeds[7]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro128**` generator from the given 16 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new16_u8(seeds: [u8; 16]) -> Option<Self> {
+        Self::new([
+            u32_from_u8_le([seeds[0], seeds[1], seeds[2], seeds[3]]),
+            u32_from_u8_le([seeds[4], seeds[5], seeds[6], seeds[7]]),
+            u32_from_u8_le([seeds[8], seeds[9], seeds[10], seeds[11]]),
+            u32_from_u8_le([seeds[12], seeds[13], seeds[14], seeds[15]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro128**` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_u32s(seed))
+    }
+}
+
+/// The `Xoshiro128++` pseudo-random number generator.
+///
+/// It has a 128-bit state and generates 32-bit numbers.
+///
+/// It's an all-purpose generator with excellent statistical properties,
+/// similar to [`Xoshiro128StarStar`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Xoshiro128PlusPlus([u32; 4]);
+
+impl Default for Xoshiro128PlusPlus {
+    fn default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Xoshiro128PlusPlus {
+    const DEFAULT_SEED: [u32; 4] = [0xDEFA0017; 4];
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+
+    #[cold]
+    #[inline]
+    #[allow(dead_code)]
+    const fn cold_path_default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+
+    /// Advances the generator with the given jump polynomial.
+    const fn jump_with(&mut self, poly: [u32; 4]) {
+        let mut state = [0; 4];
+        let mut i = 0;
+        while i < 4 {
+            let mut b = 0;
+            while b < u32::BITS {
+                if poly[i] & (1 << b) != 0 {
+                    state[0] ^= self.0[0];
+                    state[1] ^= self.0[1];
+                    state[2] ^= self.0[2];
+                    state[3] ^= self.0[3];
+                }
+                *self = self.next_new();
+                b += 1;
+            }
+            i += 1;
+        }
+        self.0 = state;
+    }
+}
+
+impl Xoshiro128PlusPlus {
+    /// Returns a seeded `Xoshiro128++` generator from the given 4 × 32-bit seeds.
+    ///
+    /// Returns `None` if all given seeds are `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(seeds: [u32; 4]) -> Option<Self> {
+        if (seeds[0] | seeds[1] | seeds[2] | seeds[3]) == 0 {
+            Self::cold_path_result()
+        } else {
+            Some(Self(seeds))
+        }
+    }
+
+    /// Returns a seeded `Xoshiro128++` generator from the given 4 × 32-bit seeds,
+    /// unchecked.
+    ///
+    /// The seeds must not be all `0`, otherwise every result will also be `0`.
+    ///
+    /// # Panics
+    /// Panics in debug if the seeds are all `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(seeds: [u32; 4]) -> Self {
+        debug_assert![
+            (seeds[0] | seeds[1] | seeds[2] | seeds[3]) != 0,
+            "Seeds must be non-zero"
+        ];
+        Self(seeds)
+    }
+
+    /// Returns the current random `u32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u32(&self) -> u32 {
+        let s = self.0;
+        s[0].wrapping_add(s[3]).rotate_left(7).wrapping_add(s[0])
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        let result = self.current_u32();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut s = self.0;
+        let t = s[1] << 9;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(11);
+        Self(s)
+    }
+
+    /// Advances the generator `2^64` steps, as if calling
+    /// [`next_u32`][Self::next_u32] that many times.
+    ///
+    /// It can be used to generate `2^64` non-overlapping
+    /// subsequences for parallel computations.
+    pub const fn jump(&mut self) {
+        const JUMP: [u32; 4] = [0x8764_000B, 0xF542_D2D3, 0x6FA0_35C3, 0x77F2_DB5B];
+        self.jump_with(JUMP);
+    }
+
+    /// Advances the generator `2^96` steps, as if calling
+    /// [`next_u32`][Self::next_u32] that many times.
+    ///
+    /// It can be used to generate `2^32` starting points,
+    /// from each of which [`jump`][Self::jump] will generate
+    /// `2^64` non-overlapping subsequences.
+    pub const fn long_jump(&mut self) {
+        const LONG_JUMP: [u32; 4] = [0xB523_952E, 0x0B6F_099F, 0xCCF5_A0EF, 0x1C58_0662];
+        self.jump_with(LONG_JUMP);
+    }
+}
+
+/// # Extra constructors
+impl Xoshiro128PlusPlus {
+    /// Returns a seeded `Xoshiro128++` generator from the given 128-bit seed.
+    ///
+    /// The seed will be split in little endian order.
+    #[inline]
+    pub const fn new1_u128(seed: u128) -> Option<Self> {
+        Self::new(u128_into_u32_le(seed))
+    }
+
+    /// Returns a seeded `Xoshiro128++` generator from the given 2 × 64-bit seeds.
+    ///
+    /// The seeds will be split in little endian order.
+    #[inline]
+    pub const fn new2_u64(seeds: [u64; 2]) -> Option<Self> {
+        let [x, y] = u64_into_u32_le(seeds[0]);
+        let [z, a] = u64_into_u32_le(seeds[1]);
+        Self::new([x, y, z, a])
+    }
+
+    /// Returns a seeded `Xoshiro128++` generator from the given 4 × 32-bit seeds.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new4_u32(seeds: [u32; 4]) -> Option<Self> {
+        Self::new(seeds)
+    }
+
+    /// Returns a seeded `Xoshiro128++` generator from the given 8 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u16(seeds: [u16; 8]) -> Option<Self> {
+        Self::new([
+            u32_from_u16_le([seeds[0], seeds[1]]),
+            u32_from_u16_le([seeds[2], seeds[3]]),
+            u32_from_u16_le([seeds[4], seeds[5]]),
+            u32_from_u16_le([seeds[6], seeds[7]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro128++` generator from the given 16 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new16_u8(seeds: [u8; 16]) -> Option<Self> {
+        Self::new([
+            u32_from_u8_le([seeds[0], seeds[1], seeds[2], seeds[3]]),
+            u32_from_u8_le([seeds[4], seeds[5], seeds[6], seeds[7]]),
+            u32_from_u8_le([seeds[8], seeds[9], seeds[10], seeds[11]]),
+            u32_from_u8_le([seeds[12], seeds[13], seeds[14], seeds[15]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro128++` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_u32s(seed))
+    }
+}
+
+/// The `Xoroshiro128++` pseudo-random number generator.
+///
+/// It has a 128-bit state and generates 64-bit numbers.
+///
+/// It's an all-purpose generator with a smaller state than [`Xoshiro256StarStar`],
+/// and the successor of [`XorShift128p`].
+///
+/// [`Xoshiro256StarStar`]: super::Xoshiro256StarStar
+/// [`XorShift128p`]: crate::xorshift::XorShift128p
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Xoroshiro128PlusPlus([u64; 2]);
+
+impl Default for Xoroshiro128PlusPlus {
+    fn default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Xoroshiro128PlusPlus {
+    const DEFAULT_SEED: [u64; 2] = [0xDEFA0017_DEFA0017; 2];
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+
+    #[cold]
+    #[inline]
+    #[allow(dead_code)]
+    const fn cold_path_default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+
+    /// Advances the generator with the given jump polynomial.
+    const fn jump_with(&mut self, poly: [u64; 2]) {
+        let mut state = [0; 2];
+        let mut i = 0;
+        while i < 2 {
+            let mut b = 0;
+            while b < u64::BITS {
+                if poly[i] & (1 << b) != 0 {
+                    state[0] ^= self.0[0];
+                    state[1] ^= self.0[1];
+                }
+                *self = self.next_new();
+                b += 1;
+            }
+            i += 1;
+        }
+        self.0 = state;
+    }
+}
+
+impl Xoroshiro128PlusPlus {
+    /// Returns a seeded `Xoroshiro128++` generator from the given 2 × 64-bit seeds.
+    ///
+    /// Returns `None` if all given seeds are `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(seeds: [u64; 2]) -> Option<Self> {
+        if (seeds[0] | seeds[1]) == 0 {
+            Self::cold_path_result()
+        } else {
+            Some(Self(seeds))
+        }
+    }
+
+    /// Returns a seeded `Xoroshiro128++` generator from the given 2 × 64-bit seeds,
+    /// unchecked.
+    ///
+    /// The seeds must not be all `0`, otherwise every result will also be `0`.
+    ///
+    /// # Panics
+    /// Panics in debug if the seeds are all `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(seeds: [u64; 2]) -> Self {
+        debug_assert![(seeds[0] | seeds[1]) != 0, "Seeds must be non-zero"];
+        Self(seeds)
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        let s = self.0;
+        s[0].wrapping_add(s[1]).rotate_left(17).wrapping_add(s[0])
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.current_u64();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut s = self.0;
+        let s0 = s[0];
+        let s1 = s[1] ^ s0;
+        s[0] = s0.rotate_left(49) ^ s1 ^ (s1 << 21); // a, b
+        s[1] = s1.rotate_left(28); // c
+        Self(s)
+    }
+
+    /// Advances the generator `2^64` steps, as if calling
+    /// [`next_u64`][Self::next_u64] that many times.
+    ///
+    /// It can be used to generate `2^64` non-overlapping
+    /// subsequences for parallel computations.
+    pub const fn jump(&mut self) {
+        const JUMP: [u64; 2] = [0x2BD7_A6A6_E99C_2DDC, 0x0992_CCAF_6A6F_CA05];
+        self.jump_with(JUMP);
+    }
+
+    /// Advances the generator `2^96` steps, as if calling
+    /// [`next_u64`][Self::next_u64] that many times.
+    ///
+    /// It can be used to generate `2^32` starting points,
+    /// from each of which [`jump`][Self::jump] will generate
+    /// `2^64` non-overlapping subsequences.
+    pub const fn long_jump(&mut self) {
+        const LONG_JUMP: [u64; 2] = [0x360F_D5F2_CF8D_5D99, 0x9C6E_6877_736C_46E3];
+        self.jump_with(LONG_JUMP);
+    }
+}
+
+/// # Extra constructors
+impl Xoroshiro128PlusPlus {
+    /// Returns a seeded `Xoroshiro128++` generator from the given 128-bit seed.
+    ///
+    /// The seed will be split in little endian order.
+    #[inline]
+    pub const fn new1_u128(seed: u128) -> Option<Self> {
+        Self::new(u128_into_u64_le(seed))
+    }
+
+    /// Returns a seeded `Xoroshiro128++` generator from the given 2 × 64-bit seeds.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new2_u64(seeds: [u64; 2]) -> Option<Self> {
+        Self::new(seeds)
+    }
+
+    /// Returns a seeded `Xoroshiro128++` generator from the given 4 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u32(seeds: [u32; 4]) -> Option<Self> {
+        Self::new([
+            u64_from_u32_le([seeds[0], seeds[1]]),
+            u64_from_u32_le([seeds[2], seeds[3]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoroshiro128++` generator from the given 8 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u16(seeds: [u16; 8]) -> Option<Self> {
+        Self::new([
+            u64_from_u16_le([seeds[0], seeds[1], seeds[2], seeds[3]]),
+            u64_from_u16_le([seeds[4], seeds[5], seeds[6], seeds[7]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoroshiro128++` generator from the given 16 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new16_u8(seeds: [u8; 16]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u8_le([s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]]),
+            u64_from_u8_le([s[8], s[9], s[10], s[11], s[12], s[13], s[14], s[15]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoroshiro128++` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_u64s(seed))
+    }
+}
+
+/// The `Xoroshiro128**` pseudo-random number generator.
+///
+/// It has a 128-bit state and generates 64-bit numbers.
+///
+/// It's an all-purpose generator with a smaller state than [`Xoshiro256StarStar`],
+/// similar to [`Xoroshiro128PlusPlus`].
+///
+/// [`Xoshiro256StarStar`]: super::Xoshiro256StarStar
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Xoroshiro128StarStar([u64; 2]);
+
+impl Default for Xoroshiro128StarStar {
+    fn default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Xoroshiro128StarStar {
+    const DEFAULT_SEED: [u64; 2] = [0xDEFA0017_DEFA0017; 2];
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+
+    #[cold]
+    #[inline]
+    #[allow(dead_code)]
+    const fn cold_path_default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+
+    /// Advances the generator with the given jump polynomial.
+    const fn jump_with(&mut self, poly: [u64; 2]) {
+        let mut state = [0; 2];
+        let mut i = 0;
+        while i < 2 {
+            let mut b = 0;
+            while b < u64::BITS {
+                if poly[i] & (1 << b) != 0 {
+                    state[0] ^= self.0[0];
+                    state[1] ^= self.0[1];
+                }
+                *self = self.next_new();
+                b += 1;
+            }
+            i += 1;
+        }
+        self.0 = state;
+    }
+}
+
+impl Xoroshiro128StarStar {
+    /// Returns a seeded `Xoroshiro128**` generator from the given 2 × 64-bit seeds.
+    ///
+    /// Returns `None` if all given seeds are `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(seeds: [u64; 2]) -> Option<Self> {
+        if (seeds[0] | seeds[1]) == 0 {
+            Self::cold_path_result()
+        } else {
+            Some(Self(seeds))
+        }
+    }
+
+    /// Returns a seeded `Xoroshiro128**` generator from the given 2 × 64-bit seeds,
+    /// unchecked.
+    ///
+    /// The seeds must not be all `0`, otherwise every result will also be `0`.
+    ///
+    /// # Panics
+    /// Panics in debug if the seeds are all `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(seeds: [u64; 2]) -> Self {
+        debug_assert![(seeds[0] | seeds[1]) != 0, "Seeds must be non-zero"];
+        Self(seeds)
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        let s = self.0;
+        s[0].wrapping_mul(5).rotate_left(7).wrapping_mul(9)
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.current_u64();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut s = self.0;
+        let s0 = s[0];
+        let s1 = s[1] ^ s0;
+        s[0] = s0.rotate_left(24) ^ s1 ^ (s1 << 16); // a, b
+        s[1] = s1.rotate_left(37); // c
+        Self(s)
+    }
+
+    /// Advances the generator `2^64` steps, as if calling
+    /// [`next_u64`][Self::next_u64] that many times.
+    ///
+    /// It can be used to generate `2^64` non-overlapping
+    /// subsequences for parallel computations.
+    pub const fn jump(&mut self) {
+        const JUMP: [u64; 2] = [0xDF90_0294_D8F5_54A5, 0x1708_65DF_4B32_01FC];
+        self.jump_with(JUMP);
+    }
+
+    /// Advances the generator `2^96` steps, as if calling
+    /// [`next_u64`][Self::next_u64] that many times.
+    ///
+    /// It can be used to generate `2^32` starting points,
+    /// from each of which [`jump`][Self::jump] will generate
+    /// `2^64` non-overlapping subsequences.
+    pub const fn long_jump(&mut self) {
+        const LONG_JUMP: [u64; 2] = [0xD2A9_8B26_625E_EE7B, 0xDDDF_9B10_90AA_7AC1];
+        self.jump_with(LONG_JUMP);
+    }
+}
+
+/// # Extra constructors
+impl Xoroshiro128StarStar {
+    /// Returns a seeded `Xoroshiro128**` generator from the given 128-bit seed.
+    ///
+    /// The seed will be split in little endian order.
+    #[inline]
+    pub const fn new1_u128(seed: u128) -> Option<Self> {
+        Self::new(u128_into_u64_le(seed))
+    }
+
+    /// Returns a seeded `Xoroshiro128**` generator from the given 2 × 64-bit seeds.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new2_u64(seeds: [u64; 2]) -> Option<Self> {
+        Self::new(seeds)
+    }
+
+    /// Returns a seeded `Xoroshiro128**` generator from the given 4 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u32(seeds: [u32; 4]) -> Option<Self> {
+        Self::new([
+            u64_from_u32_le([seeds[0], seeds[1]]),
+            u64_from_u32_le([seeds[2], seeds[3]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoroshiro128**` generator from the given 8 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u16(seeds: [u16; 8]) -> Option<Self> {
+        Self::new([
+            u64_from_u16_le([seeds[0], seeds[1], seeds[2], seeds[3]]),
+            u64_from_u16_le([seeds[4], seeds[5], seeds[6], seeds[7]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoroshiro128**` generator from the given 16 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new16_u8(seeds: [u8; 16]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u8_le([s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]]),
+            u64_from_u8_le([s[8], s[9], s[10], s[11], s[12], s[13], s[14], s[15]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoroshiro128**` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_u64s(seed))
+    }
+}
+
+impl_prng![Xoshiro128StarStar: u32, current_u32, next_u32];
+impl_prng![Xoshiro128PlusPlus: u32, current_u32, next_u32];
+impl_prng![Xoroshiro128PlusPlus: u64, current_u64, next_u64];
+impl_prng![Xoroshiro128StarStar: u64, current_u64, next_u64];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::{
+        Xoroshiro128PlusPlus, Xoroshiro128StarStar, Xoshiro128PlusPlus, Xoshiro128StarStar,
+    };
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![Xoshiro128StarStar];
+
+    impl SeedableRng for Xoshiro128StarStar {
+        type Seed = [u8; 16];
+
+        /// When seeded with zero this implementation uses the default seed
+        /// value as the cold path.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut seed_u32s = [0u32; 4];
+            if seed == [0; 16] {
+                Self::cold_path_default()
+            } else {
+                for i in 0..4 {
+                    seed_u32s[i] = u32::from_le_bytes([
+                        seed[i * 4],
+                        seed[i * 4 + 1],
+                        seed[i * 4 + 2],
+                        seed[i * 4 + 3],
+                    ]);
+                }
+                Self::new_unchecked(seed_u32s)
+            }
+        }
+    }
+
+    impl_rand_core![Xoshiro128PlusPlus];
+
+    impl SeedableRng for Xoshiro128PlusPlus {
+        type Seed = [u8; 16];
+
+        /// When seeded with zero this implementation uses the default seed
+        /// value as the cold path.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut seed_u32s = [0u32; 4];
+            if seed == [0; 16] {
+                Self::cold_path_default()
+            } else {
+                for i in 0..4 {
+                    seed_u32s[i] = u32::from_le_bytes([
+                        seed[i * 4],
+                        seed[i * 4 + 1],
+                        seed[i * 4 + 2],
+                        seed[i * 4 + 3],
+                    ]);
+                }
+                Self::new_unchecked(seed_u32s)
+            }
+        }
+    }
+
+    impl_rand_core![Xoroshiro128PlusPlus];
+
+    impl SeedableRng for Xoroshiro128PlusPlus {
+        type Seed = [u8; 16];
+
+        /// When seeded with zero this implementation uses the default seed
+        /// value as the cold path.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut seed_u64s = [0u64; 2];
+            if seed == [0; 16] {
+                Self::cold_path_default()
+            } else {
+                for i in 0..2 {
+                    seed_u64s[i] = u64::from_le_bytes([
+                        seed[i * 8],
+                        seed[i * 8 + 1],
+                        seed[i * 8 + 2],
+                        seed[i * 8 + 3],
+                        seed[i * 8 + 4],
+                        seed[i * 8 + 5],
+                        seed[i * 8 + 6],
+                        seed[i * 8 + 7],
+                    ]);
+                }
+                Self::new_unchecked(seed_u64s)
+            }
+        }
+    }
+
+    impl_rand_core![Xoroshiro128StarStar];
+
+    impl SeedableRng for Xoroshiro128StarStar {
+        type Seed = [u8; 16];
+
+        /// When seeded with zero this implementation uses the default seed
+        /// value as the cold path.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut seed_u64s = [0u64; 2];
+            if seed == [0; 16] {
+                Self::cold_path_default()
+            } else {
+                for i in 0..2 {
+                    seed_u64s[i] = u64::from_le_bytes([
+                        seed[i * 8],
+                        seed[i * 8 + 1],
+                        seed[i * 8 + 2],
+                        seed[i * 8 + 3],
+                        seed[i * 8 + 4],
+                        seed[i * 8 + 5],
+                        seed[i * 8 + 6],
+                        seed[i * 8 + 7],
+                    ]);
+                }
+                Self::new_unchecked(seed_u64s)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The reference values were produced with the reference implementations
+    // at https://prng.di.unimi.it/, seeded with the words 1, 2, 3 and 4,
+    // and the jump values with `rand_xoshiro`.
+
+    #[test]
+    fn xoshiro128starstar_reference() {
+        let mut rng = Xoshiro128StarStar::new([1, 2, 3, 4]).unwrap();
+        for expected in [
+            11520, 0, 5927040, 70819200, 2031721883, 1637235492, 1287239034, 3734860849,
+            3729100597, 4258142804,
+        ] {
+            assert_eq![rng.next_u32(), expected];
+        }
+    }
+
+    #[test]
+    fn xoshiro128starstar_jump() {
+        let mut rng = Xoshiro128StarStar::new([1, 2, 3, 4]).unwrap();
+        rng.jump();
+        for expected in [1194304935, 745561276, 25819468, 3320478005] {
+            assert_eq![rng.next_u32(), expected];
+        }
+        let mut rng = Xoshiro128StarStar::new([1, 2, 3, 4]).unwrap();
+        rng.long_jump();
+        for expected in [4148901660, 60341234, 3638978148, 2927796021] {
+            assert_eq![rng.next_u32(), expected];
+        }
+    }
+
+    #[test]
+    fn xoshiro128plusplus_reference() {
+        let mut rng = Xoshiro128PlusPlus::new([1, 2, 3, 4]).unwrap();
+        for expected in [
+            641, 1573767, 3222811527, 3517856514, 836907274, 4247214768, 3867114732, 1355841295,
+            495546011, 621204420,
+        ] {
+            assert_eq![rng.next_u32(), expected];
+        }
+    }
+
+    #[test]
+    fn xoshiro128plusplus_jump() {
+        let mut rng = Xoshiro128PlusPlus::new([1, 2, 3, 4]).unwrap();
+        rng.jump();
+        for expected in [3129740764, 111290574, 1158071106, 1835317750] {
+            assert_eq![rng.next_u32(), expected];
+        }
+        let mut rng = Xoshiro128PlusPlus::new([1, 2, 3, 4]).unwrap();
+        rng.long_jump();
+        for expected in [2580293941, 2135890358, 163124449, 1843864296] {
+            assert_eq![rng.next_u32(), expected];
+        }
+    }
+
+    #[test]
+    fn xoroshiro128plusplus_reference() {
+        let mut rng = Xoroshiro128PlusPlus::new([1, 2]).unwrap();
+        for expected in [
+            393217,
+            669327710093319,
+            1732421326133921491,
+            11394790081659126983,
+            9555452776773192676,
+            3586421180005889563,
+            1691397964866707553,
+            10735626796753111697,
+            15216282715349408991,
+            14247243556711267923,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn xoroshiro128plusplus_jump() {
+        let mut rng = Xoroshiro128PlusPlus::new([1, 2]).unwrap();
+        rng.jump();
+        for expected in [
+            6995778298204176446,
+            17606341508358386873,
+            18268233585225622342,
+            1634122034616564957,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+        let mut rng = Xoroshiro128PlusPlus::new([1, 2]).unwrap();
+        rng.long_jump();
+        for expected in [
+            13476878559037916028,
+            4599739792799904096,
+            9592342027630475676,
+            16396948912373680941,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn xoroshiro128starstar_reference() {
+        let mut rng = Xoroshiro128StarStar::new([1, 2]).unwrap();
+        for expected in [
+            5760,
+            97769243520,
+            9706862127477703552,
+            9223447511460779954,
+            8358291023205304566,
+            15695619998649302768,
+            8517900938696309774,
+            16586480348202605369,
+            6959129367028440372,
+            16822147227405758281,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn xoroshiro128starstar_jump() {
+        let mut rng = Xoroshiro128StarStar::new([1, 2]).unwrap();
+        rng.jump();
+        for expected in [
+            2464231652016875657,
+            11602794600843324846,
+            733764001042591551,
+            5324733124812429005,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+        let mut rng = Xoroshiro128StarStar::new([1, 2]).unwrap();
+        rng.long_jump();
+        for expected in [
+            1154914562721061336,
+            6059381922964790418,
+            15458620134926953352,
+            6449629845481199462,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+}
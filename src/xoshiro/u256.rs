@@ -0,0 +1,907 @@
+// alazar::xoshiro::u256
+//
+//! 256-bit versions of xoshiro generators.
+//
+
+use crate::{misc::SplitMix64, prng::impl_prng};
+use devela::convert::{u128_into_u64_le, u64_from_u16_le, u64_from_u32_le, u64_from_u8_le};
+
+/// The `Xoshiro256**` pseudo-random number generator.
+///
+/// It has a 256-bit state and generates 64-bit numbers.
+///
+/// It's an all-purpose generator with excellent statistical properties,
+/// and the recommended default of the family for 64-bit outputs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Xoshiro256StarStar([u64; 4]);
+
+impl Default for Xoshiro256StarStar {
+    fn default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Xoshiro256StarStar {
+    const DEFAULT_SEED: [u64; 4] = [0xDEFA0017_DEFA0017; 4];
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+
+    #[cold]
+    #[inline]
+    #[allow(dead_code)]
+    const fn cold_path_default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+
+    /// Advances the generator with the given jump polynomial.
+    const fn jump_with(&mut self, poly: [u64; 4]) {
+        let mut state = [0; 4];
+        let mut i = 0;
+        while i < 4 {
+            let mut b = 0;
+            while b < u64::BITS {
+                if poly[i] & (1 << b) != 0 {
+                    state[0] ^= self.0[0];
+                    state[1] ^= self.0[1];
+                    state[2] ^= self.0[2];
+                    state[3] ^= self.0[3];
+                }
+                *self = self.next_new();
+                b += 1;
+            }
+            i += 1;
+        }
+        self.0 = state;
+    }
+}
+
+impl Xoshiro256StarStar {
+    /// Returns a seeded `Xoshiro256**` generator from the given 4 × 64-bit seeds.
+    ///
+    /// Returns `None` if all given seeds are `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(seeds: [u64; 4]) -> Option<Self> {
+        if (seeds[0] | seeds[1] | seeds[2] | seeds[3]) == 0 {
+            Self::cold_path_result()
+        } else {
+            Some(Self(seeds))
+        }
+    }
+
+    /// Returns a seeded `Xoshiro256**` generator from the given 4 × 64-bit seeds,
+    /// unchecked.
+    ///
+    /// The seeds must not be all `0`, otherwise every result will also be `0`.
+    ///
+    /// # Panics
+    /// Panics in debug if the seeds are all `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(seeds: [u64; 4]) -> Self {
+        debug_assert![
+            (seeds[0] | seeds[1] | seeds[2] | seeds[3]) != 0,
+            "Seeds must be non-zero"
+        ];
+        Self(seeds)
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        let s = self.0;
+        s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9)
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.current_u64();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut s = self.0;
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        Self(s)
+    }
+
+    /// Advances the generator `2^128` steps, as if calling
+    /// [`next_u64`][Self::next_u64] that many times.
+    ///
+    /// It can be used to generate `2^128` non-overlapping
+    /// subsequences for parallel computations.
+    pub const fn jump(&mut self) {
+        const JUMP: [u64; 4] = [
+            0x180E_C6D3_3CFD_0ABA,
+            0xD5A6_1266_F0C9_392C,
+            0xA958_2618_E03F_C9AA,
+            0x39AB_DC45_29B1_661C,
+        ];
+        self.jump_with(JUMP);
+    }
+
+    /// Advances the generator `2^192` steps, as if calling
+    /// [`next_u64`][Self::next_u64] that many times.
+    ///
+    /// It can be used to generate `2^64` starting points,
+    /// from each of which [`jump`][Self::jump] will generate
+    /// `2^128` non-overlapping subsequences.
+    pub const fn long_jump(&mut self) {
+        const LONG_JUMP: [u64; 4] = [
+            0x76E1_5D3E_FEFD_CBBF,
+            0xC500_4E44_1C52_2FB3,
+            0x7771_0069_854E_E241,
+            0x3910_9BB0_2ACB_E635,
+        ];
+        self.jump_with(LONG_JUMP);
+    }
+}
+
+/// # Extra constructors
+impl Xoshiro256StarStar {
+    /// Returns a seeded `Xoshiro256**` generator from the given 2 × 128-bit seeds.
+    ///
+    /// The seeds will be split in little endian order.
+    #[inline]
+    pub const fn new2_u128(seeds: [u128; 2]) -> Option<Self> {
+        let [a, b] = u128_into_u64_le(seeds[0]);
+        let [c, d] = u128_into_u64_le(seeds[1]);
+        Self::new([a, b, c, d])
+    }
+
+    /// Returns a seeded `Xoshiro256**` generator from the given 4 × 64-bit seeds.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new4_u64(seeds: [u64; 4]) -> Option<Self> {
+        Self::new(seeds)
+    }
+
+    /// Returns a seeded `Xoshiro256**` generator from the given 8 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u32(seeds: [u32; 8]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u32_le([s[0], s[1]]),
+            u64_from_u32_le([s[2], s[3]]),
+            u64_from_u32_le([s[4], s[5]]),
+            u64_from_u32_le([s[6], s[7]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro256**` generator from the given 16 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new16_u16(seeds: [u16; 16]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u16_le([s[0], s[1], s[2], s[3]]),
+            u64_from_u16_le([s[4], s[5], s[6], s[7]]),
+            u64_from_u16_le([s[8], s[9], s[10], s[11]]),
+            u64_from_u16_le([s[12], s[13], s[14], s[15]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro256**` generator from the given 32 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new32_u8(seeds: [u8; 32]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u8_le([s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]]),
+            u64_from_u8_le([s[8], s[9], s[10], s[11], s[12], s[13], s[14], s[15]]),
+            u64_from_u8_le([s[16], s[17], s[18], s[19], s[20], s[21], s[22], s[23]]),
+            u64_from_u8_le([s[24], s[25], s[26], s[27], s[28], s[29], s[30], s[31]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro256**` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_u64s(seed))
+    }
+}
+
+/// The `Xoshiro256++` pseudo-random number generator.
+///
+/// It has a 256-bit state and generates 64-bit numbers.
+///
+/// It's an all-purpose generator with excellent statistical properties,
+/// similar to [`Xoshiro256StarStar`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Xoshiro256PlusPlus([u64; 4]);
+
+impl Default for Xoshiro256PlusPlus {
+    fn default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Xoshiro256PlusPlus {
+    const DEFAULT_SEED: [u64; 4] = [0xDEFA0017_DEFA0017; 4];
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+
+    #[cold]
+    #[inline]
+    #[allow(dead_code)]
+    const fn cold_path_default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+
+    /// Advances the generator with the given jump polynomial.
+    const fn jump_with(&mut self, poly: [u64; 4]) {
+        let mut state = [0; 4];
+        let mut i = 0;
+        while i < 4 {
+            let mut b = 0;
+            while b < u64::BITS {
+                if poly[i] & (1 << b) != 0 {
+                    state[0] ^= self.0[0];
+                    state[1] ^= self.0[1];
+                    state[2] ^= self.0[2];
+                    state[3] ^= self.0[3];
+                }
+                *self = self.next_new();
+                b += 1;
+            }
+            i += 1;
+        }
+        self.0 = state;
+    }
+}
+
+impl Xoshiro256PlusPlus {
+    /// Returns a seeded `Xoshiro256++` generator from the given 4 × 64-bit seeds.
+    ///
+    /// Returns `None` if all given seeds are `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(seeds: [u64; 4]) -> Option<Self> {
+        if (seeds[0] | seeds[1] | seeds[2] | seeds[3]) == 0 {
+            Self::cold_path_result()
+        } else {
+            Some(Self(seeds))
+        }
+    }
+
+    /// Returns a seeded `Xoshiro256++` generator from the given 4 × 64-bit seeds,
+    /// unchecked.
+    ///
+    /// The seeds must not be all `0`, otherwise every result will also be `0`.
+    ///
+    /// # Panics
+    /// Panics in debug if the seeds are all `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(seeds: [u64; 4]) -> Self {
+        debug_assert![
+            (seeds[0] | seeds[1] | seeds[2] | seeds[3]) != 0,
+            "Seeds must be non-zero"
+        ];
+        Self(seeds)
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        let s = self.0;
+        s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0])
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.current_u64();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut s = self.0;
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        Self(s)
+    }
+
+    /// Advances the generator `2^128` steps, as if calling
+    /// [`next_u64`][Self::next_u64] that many times.
+    ///
+    /// It can be used to generate `2^128` non-overlapping
+    /// subsequences for parallel computations.
+    pub const fn jump(&mut self) {
+        const JUMP: [u64; 4] = [
+            0x180E_C6D3_3CFD_0ABA,
+            0xD5A6_1266_F0C9_392C,
+            0xA958_2618_E03F_C9AA,
+            0x39AB_DC45_29B1_661C,
+        ];
+        self.jump_with(JUMP);
+    }
+
+    /// Advances the generator `2^192` steps, as if calling
+    /// [`next_u64`][Self::next_u64] that many times.
+    ///
+    /// It can be used to generate `2^64` starting points,
+    /// from each of which [`jump`][Self::jump] will generate
+    /// `2^128` non-overlapping subsequences.
+    pub const fn long_jump(&mut self) {
+        const LONG_JUMP: [u64; 4] = [
+            0x76E1_5D3E_FEFD_CBBF,
+            0xC500_4E44_1C52_2FB3,
+            0x7771_0069_854E_E241,
+            0x3910_9BB0_2ACB_E635,
+        ];
+        self.jump_with(LONG_JUMP);
+    }
+}
+
+/// # Extra constructors
+impl Xoshiro256PlusPlus {
+    /// Returns a seeded `Xoshiro256++` generator from the given 2 × 128-bit seeds.
+    ///
+    /// The seeds will be split in little endian order.
+    #[inline]
+    pub const fn new2_u128(seeds: [u128; 2]) -> Option<Self> {
+        let [a, b] = u128_into_u64_le(seeds[0]);
+        let [c, d] = u128_into_u64_le(seeds[1]);
+        Self::new([a, b, c, d])
+    }
+
+    /// Returns a seeded `Xoshiro256++` generator from the given 4 × 64-bit seeds.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new4_u64(seeds: [u64; 4]) -> Option<Self> {
+        Self::new(seeds)
+    }
+
+    /// Returns a seeded `Xoshiro256++` generator from the given 8 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u32(seeds: [u32; 8]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u32_le([s[0], s[1]]),
+            u64_from_u32_le([s[2], s[3]]),
+            u64_from_u32_le([s[4], s[5]]),
+            u64_from_u32_le([s[6], s[7]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro256++` generator from the given 16 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new16_u16(seeds: [u16; 16]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u16_le([s[0], s[1], s[2], s[3]]),
+            u64_from_u16_le([s[4], s[5], s[6], s[7]]),
+            u64_from_u16_le([s[8], s[9], s[10], s[11]]),
+            u64_from_u16_le([s[12], s[13], s[14], s[15]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro256++` generator from the given 32 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new32_u8(seeds: [u8; 32]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u8_le([s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]]),
+            u64_from_u8_le([s[8], s[9], s[10], s[11], s[12], s[13], s[14], s[15]]),
+            u64_from_u8_le([s[16], s[17], s[18], s[19], s[20], s[21], s[22], s[23]]),
+            u64_from_u8_le([s[24], s[25], s[26], s[27], s[28], s[29], s[30], s[31]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro256++` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_u64s(seed))
+    }
+}
+
+/// The `Xoshiro256+` pseudo-random number generator.
+///
+/// It has a 256-bit state and generates 64-bit numbers.
+///
+/// It's slightly faster than [`Xoshiro256StarStar`], but its lowest bits have
+/// low linear complexity, so it's recommended only for generating floats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Xoshiro256Plus([u64; 4]);
+
+impl Default for Xoshiro256Plus {
+    fn default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Xoshiro256Plus {
+    const DEFAULT_SEED: [u64; 4] = [0xDEFA0017_DEFA0017; 4];
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+
+    #[cold]
+    #[inline]
+    #[allow(dead_code)]
+    const fn cold_path_default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+
+    /// Advances the generator with the given jump polynomial.
+    const fn jump_with(&mut self, poly: [u64; 4]) {
+        let mut state = [0; 4];
+        let mut i = 0;
+        while i < 4 {
+            let mut b = 0;
+            while b < u64::BITS {
+                if poly[i] & (1 << b) != 0 {
+                    state[0] ^= self.0[0];
+                    state[1] ^= self.0[1];
+                    state[2] ^= self.0[2];
+                    state[3] ^= self.0[3];
+                }
+                *self = self.next_new();
+                b += 1;
+            }
+            i += 1;
+        }
+        self.0 = state;
+    }
+}
+
+impl Xoshiro256Plus {
+    /// Returns a seeded `Xoshiro256+` generator from the given 4 × 64-bit seeds.
+    ///
+    /// Returns `None` if all given seeds are `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(seeds: [u64; 4]) -> Option<Self> {
+        if (seeds[0] | seeds[1] | seeds[2] | seeds[3]) == 0 {
+            Self::cold_path_result()
+        } else {
+            Some(Self(seeds))
+        }
+    }
+
+    /// Returns a seeded `Xoshiro256+` generator from the given 4 × 64-bit seeds,
+    /// unchecked.
+    ///
+    /// The seeds must not be all `0`, otherwise every result will also be `0`.
+    ///
+    /// # Panics
+    /// Panics in debug if the seeds are all `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(seeds: [u64; 4]) -> Self {
+        debug_assert![
+            (seeds[0] | seeds[1] | seeds[2] | seeds[3]) != 0,
+            "Seeds must be non-zero"
+        ];
+        Self(seeds)
+    }
+
+    /// Returns the current random `u64`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u64(&self) -> u64 {
+        let s = self.0;
+        s[0].wrapping_add(s[3])
+    }
+
+    /// Returns the next random `u64`.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.current_u64();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut s = self.0;
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        Self(s)
+    }
+
+    /// Advances the generator `2^128` steps, as if calling
+    /// [`next_u64`][Self::next_u64] that many times.
+    ///
+    /// It can be used to generate `2^128` non-overlapping
+    /// subsequences for parallel computations.
+    pub const fn jump(&mut self) {
+        const JUMP: [u64; 4] = [
+            0x180E_C6D3_3CFD_0ABA,
+            0xD5A6_1266_F0C9_392C,
+            0xA958_2618_E03F_C9AA,
+            0x39AB_DC45_29B1_661C,
+        ];
+        self.jump_with(JUMP);
+    }
+
+    /// Advances the generator `2^192` steps, as if calling
+    /// [`next_u64`][Self::next_u64] that many times.
+    ///
+    /// It can be used to generate `2^64` starting points,
+    /// from each of which [`jump`][Self::jump] will generate
+    /// `2^128` non-overlapping subsequences.
+    pub const fn long_jump(&mut self) {
+        const LONG_JUMP: [u64; 4] = [
+            0x76E1_5D3E_FEFD_CBBF,
+            0xC500_4E44_1C52_2FB3,
+            0x7771_0069_854E_E241,
+            0x3910_9BB0_2ACB_E635,
+        ];
+        self.jump_with(LONG_JUMP);
+    }
+}
+
+/// # Extra constructors
+impl Xoshiro256Plus {
+    /// Returns a seeded `Xoshiro256+` generator from the given 2 × 128-bit seeds.
+    ///
+    /// The seeds will be split in little endian order.
+    #[inline]
+    pub const fn new2_u128(seeds: [u128; 2]) -> Option<Self> {
+        let [a, b] = u128_into_u64_le(seeds[0]);
+        let [c, d] = u128_into_u64_le(seeds[1]);
+        Self::new([a, b, c, d])
+    }
+
+    /// Returns a seeded `Xoshiro256+` generator from the given 4 × 64-bit seeds.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new4_u64(seeds: [u64; 4]) -> Option<Self> {
+        Self::new(seeds)
+    }
+
+    /// Returns a seeded `Xoshiro256+` generator from the given 8 × 32-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u32(seeds: [u32; 8]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u32_le([s[0], s[1]]),
+            u64_from_u32_le([s[2], s[3]]),
+            u64_from_u32_le([s[4], s[5]]),
+            u64_from_u32_le([s[6], s[7]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro256+` generator from the given 16 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new16_u16(seeds: [u16; 16]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u16_le([s[0], s[1], s[2], s[3]]),
+            u64_from_u16_le([s[4], s[5], s[6], s[7]]),
+            u64_from_u16_le([s[8], s[9], s[10], s[11]]),
+            u64_from_u16_le([s[12], s[13], s[14], s[15]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro256+` generator from the given 32 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new32_u8(seeds: [u8; 32]) -> Option<Self> {
+        let s = seeds;
+        Self::new([
+            u64_from_u8_le([s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7]]),
+            u64_from_u8_le([s[8], s[9], s[10], s[11], s[12], s[13], s[14], s[15]]),
+            u64_from_u8_le([s[16], s[17], s[18], s[19], s[20], s[21], s[22], s[23]]),
+            u64_from_u8_le([s[24], s[25], s[26], s[27], s[28], s[29], s[30], s[31]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoshiro256+` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_u64s(seed))
+    }
+}
+
+impl_prng![Xoshiro256StarStar: u64, current_u64, next_u64];
+impl_prng![Xoshiro256PlusPlus: u64, current_u64, next_u64];
+impl_prng![Xoshiro256Plus: u64, current_u64, next_u64];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::{Xoshiro256Plus, Xoshiro256PlusPlus, Xoshiro256StarStar};
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![Xoshiro256StarStar];
+
+    impl SeedableRng for Xoshiro256StarStar {
+        type Seed = [u8; 32];
+
+        /// When seeded with zero this implementation uses the default seed
+        /// value as the cold path.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut seed_u64s = [0u64; 4];
+            if seed == [0; 32] {
+                Self::cold_path_default()
+            } else {
+                for i in 0..4 {
+                    seed_u64s[i] = u64::from_le_bytes([
+                        seed[i * 8],
+                        seed[i * 8 + 1],
+                        seed[i * 8 + 2],
+                        seed[i * 8 + 3],
+                        seed[i * 8 + 4],
+                        seed[i * 8 + 5],
+                        seed[i * 8 + 6],
+                        seed[i * 8 + 7],
+                    ]);
+                }
+                Self::new_unchecked(seed_u64s)
+            }
+        }
+    }
+
+    impl_rand_core![Xoshiro256PlusPlus];
+
+    impl SeedableRng for Xoshiro256PlusPlus {
+        type Seed = [u8; 32];
+
+        /// When seeded with zero this implementation uses the default seed
+        /// value as the cold path.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut seed_u64s = [0u64; 4];
+            if seed == [0; 32] {
+                Self::cold_path_default()
+            } else {
+                for i in 0..4 {
+                    seed_u64s[i] = u64::from_le_bytes([
+                        seed[i * 8],
+                        seed[i * 8 + 1],
+                        seed[i * 8 + 2],
+                        seed[i * 8 + 3],
+                        seed[i * 8 + 4],
+                        seed[i * 8 + 5],
+                        seed[i * 8 + 6],
+                        seed[i * 8 + 7],
+                    ]);
+                }
+                Self::new_unchecked(seed_u64s)
+            }
+        }
+    }
+
+    impl_rand_core![Xoshiro256Plus];
+
+    impl SeedableRng for Xoshiro256Plus {
+        type Seed = [u8; 32];
+
+        /// When seeded with zero this implementation uses the default seed
+        /// value as the cold path.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut seed_u64s = [0u64; 4];
+            if seed == [0; 32] {
+                Self::cold_path_default()
+            } else {
+                for i in 0..4 {
+                    seed_u64s[i] = u64::from_le_bytes([
+                        seed[i * 8],
+                        seed[i * 8 + 1],
+                        seed[i * 8 + 2],
+                        seed[i * 8 + 3],
+                        seed[i * 8 + 4],
+                        seed[i * 8 + 5],
+                        seed[i * 8 + 6],
+                        seed[i * 8 + 7],
+                    ]);
+                }
+                Self::new_unchecked(seed_u64s)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The reference values were produced with the reference implementations
+    // at https://prng.di.unimi.it/, seeded with the words 1, 2, 3 and 4,
+    // and the jump values with `rand_xoshiro`.
+
+    #[test]
+    fn xoshiro256starstar_reference() {
+        let mut rng = Xoshiro256StarStar::new([1, 2, 3, 4]).unwrap();
+        for expected in [
+            11520,
+            0,
+            1509978240,
+            1215971899390074240,
+            1216172134540287360,
+            607988272756665600,
+            16172922978634559625,
+            8476171486693032832,
+            10595114339597558777,
+            2904607092377533576,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn xoshiro256starstar_jump() {
+        let mut rng = Xoshiro256StarStar::new([1, 2, 3, 4]).unwrap();
+        rng.jump();
+        for expected in [
+            13534147089533256664,
+            7126240192422241655,
+            3805973808039778091,
+            11547880530658420384,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+        let mut rng = Xoshiro256StarStar::new([1, 2, 3, 4]).unwrap();
+        rng.long_jump();
+        for expected in [
+            5942309088398569549,
+            15625447729937358436,
+            6925613901769781251,
+            16198770605655666946,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn xoshiro256plusplus_reference() {
+        let mut rng = Xoshiro256PlusPlus::new([1, 2, 3, 4]).unwrap();
+        for expected in [
+            41943041,
+            58720359,
+            3588806011781223,
+            3591011842654386,
+            9228616714210784205,
+            9973669472204895162,
+            14011001112246962877,
+            12406186145184390807,
+            15849039046786891736,
+            10450023813501588000,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn xoshiro256plusplus_jump() {
+        let mut rng = Xoshiro256PlusPlus::new([1, 2, 3, 4]).unwrap();
+        rng.jump();
+        for expected in [
+            17043750140134683703,
+            2364973248208838314,
+            13951431646535487319,
+            8066193832155293345,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+        let mut rng = Xoshiro256PlusPlus::new([1, 2, 3, 4]).unwrap();
+        rng.long_jump();
+        for expected in [
+            13097851138432240629,
+            5869259491745178931,
+            2145365994275058833,
+            16694938170147227233,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn xoshiro256plus_reference() {
+        let mut rng = Xoshiro256Plus::new([1, 2, 3, 4]).unwrap();
+        for expected in [
+            5,
+            211106232532999,
+            211106635186183,
+            9223759065350669058,
+            9250833439874351877,
+            13862484359527728515,
+            2346507365006083650,
+            1168864526675804870,
+            34095955243042024,
+            3466914240207415127,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+
+    #[test]
+    fn xoshiro256plus_jump() {
+        let mut rng = Xoshiro256Plus::new([1, 2, 3, 4]).unwrap();
+        rng.jump();
+        for expected in [
+            1153146630064993313,
+            12314415065245919719,
+            6215237862445749542,
+            16777907402320790505,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+        let mut rng = Xoshiro256Plus::new([1, 2, 3, 4]).unwrap();
+        rng.long_jump();
+        for expected in [
+            4237864540600467441,
+            12093458965634073548,
+            15742032294781686688,
+            1104482975493234836,
+        ] {
+            assert_eq![rng.next_u64(), expected];
+        }
+    }
+}
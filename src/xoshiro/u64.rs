@@ -0,0 +1,202 @@
+// alazar::xoshiro::u64
+//
+//! 64-bit versions of xoroshiro generators.
+//
+
+use crate::{misc::SplitMix64, prng::impl_prng};
+use devela::convert::{u32_from_u16_le, u32_from_u8_le, u64_into_u32_le};
+
+/// The `Xoroshiro64*` pseudo-random number generator.
+///
+/// It has a 64-bit state and generates 32-bit numbers.
+///
+/// It's meant for generating 32-bit floats, since its lowest bits have
+/// low linear complexity. It has no jump functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Xoroshiro64Star([u32; 2]);
+
+impl Default for Xoroshiro64Star {
+    fn default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+// private associated items
+impl Xoroshiro64Star {
+    const DEFAULT_SEED: [u32; 2] = [0xDEFA0017; 2];
+
+    #[cold]
+    #[inline]
+    const fn cold_path_result() -> Option<Self> {
+        None
+    }
+
+    #[cold]
+    #[inline]
+    #[allow(dead_code)]
+    const fn cold_path_default() -> Self {
+        Self::new_unchecked(Self::DEFAULT_SEED)
+    }
+}
+
+impl Xoroshiro64Star {
+    /// Returns a seeded `Xoroshiro64*` generator from the given 2 × 32-bit seeds.
+    ///
+    /// Returns `None` if all given seeds are `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new(seeds: [u32; 2]) -> Option<Self> {
+        if (seeds[0] | seeds[1]) == 0 {
+            Self::cold_path_result()
+        } else {
+            Some(Self(seeds))
+        }
+    }
+
+    /// Returns a seeded `Xoroshiro64*` generator from the given 2 × 32-bit seeds,
+    /// unchecked.
+    ///
+    /// The seeds must not be all `0`, otherwise every result will also be `0`.
+    ///
+    /// # Panics
+    /// Panics in debug if the seeds are all `0`.
+    #[inline]
+    #[must_use]
+    pub const fn new_unchecked(seeds: [u32; 2]) -> Self {
+        debug_assert![(seeds[0] | seeds[1]) != 0, "Seeds must be non-zero"];
+        Self(seeds)
+    }
+
+    /// Returns the current random `u32`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn current_u32(&self) -> u32 {
+        let s = self.0;
+        s[0].wrapping_mul(0x9E37_79BB)
+    }
+
+    /// Returns the next random `u32`.
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        let result = self.current_u32();
+        *self = self.next_new();
+        result
+    }
+
+    /// Returns a copy of the next new random state.
+    #[inline]
+    #[must_use]
+    pub const fn next_new(&self) -> Self {
+        let mut s = self.0;
+        let s0 = s[0];
+        let s1 = s[1] ^ s0;
+        s[0] = s0.rotate_left(26) ^ s1 ^ (s1 << 9); // a, b
+        s[1] = s1.rotate_left(13); // c
+        Self(s)
+    }
+}
+
+/// # Extra constructors
+impl Xoroshiro64Star {
+    /// Returns a seeded `Xoroshiro64*` generator from the given 64-bit seed.
+    ///
+    /// The seed will be split in little endian order.
+    #[inline]
+    pub const fn new1_u64(seed: u64) -> Option<Self> {
+        Self::new(u64_into_u32_le(seed))
+    }
+
+    /// Returns a seeded `Xoroshiro64*` generator from the given 2 × 32-bit seeds.
+    ///
+    /// This is an alias of [`new`][Self#method.new].
+    #[inline]
+    pub const fn new2_u32(seeds: [u32; 2]) -> Option<Self> {
+        Self::new(seeds)
+    }
+
+    /// Returns a seeded `Xoroshiro64*` generator from the given 4 × 16-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new4_u16(seeds: [u16; 4]) -> Option<Self> {
+        Self::new([
+            u32_from_u16_le([seeds[0], seeds[1]]),
+            u32_from_u16_le([seeds[2], seeds[3]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoroshiro64*` generator from the given 8 × 8-bit seeds.
+    ///
+    /// The seeds will be joined in little endian order.
+    #[inline]
+    pub const fn new8_u8(seeds: [u8; 8]) -> Option<Self> {
+        Self::new([
+            u32_from_u8_le([seeds[0], seeds[1], seeds[2], seeds[3]]),
+            u32_from_u8_le([seeds[4], seeds[5], seeds[6], seeds[7]]),
+        ])
+    }
+
+    /// Returns a seeded `Xoroshiro64*` generator from the given 64-bit seed,
+    /// expanded through [`SplitMix64`].
+    ///
+    /// Nearby seeds result in unrelated states, which are never all `0`.
+    #[inline]
+    pub const fn from_u64_seed(seed: u64) -> Self {
+        Self::new_unchecked(SplitMix64::seed_u32s(seed))
+    }
+}
+
+impl_prng![Xoroshiro64Star: u32, current_u32, next_u32];
+
+#[cfg(feature = "rand_core")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rand_core")))]
+mod impl_rand {
+    use super::Xoroshiro64Star;
+    use crate::prng::impl_rand_core;
+    use rand_core::SeedableRng;
+
+    impl_rand_core![Xoroshiro64Star];
+
+    impl SeedableRng for Xoroshiro64Star {
+        type Seed = [u8; 8];
+
+        /// When seeded with zero this implementation uses the default seed
+        /// value as the cold path.
+        fn from_seed(seed: Self::Seed) -> Self {
+            let mut seed_u32s = [0u32; 2];
+            if seed == [0; 8] {
+                Self::cold_path_default()
+            } else {
+                for i in 0..2 {
+                    seed_u32s[i] = u32::from_le_bytes([
+                        seed[i * 4],
+                        seed[i * 4 + 1],
+                        seed[i * 4 + 2],
+                        seed[i * 4 + 3],
+                    ]);
+                }
+                Self::new_unchecked(seed_u32s)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The reference values were produced with the reference implementations
+    // at https://prng.di.unimi.it/, seeded with the words 1, 2, 3 and 4.
+
+    #[test]
+    fn xoroshiro64star_reference() {
+        let mut rng = Xoroshiro64Star::new([1, 2]).unwrap();
+        for expected in [
+            2654435771, 327208753, 4063491769, 4259754937, 261922412, 168123673, 552743735,
+            1672597395, 1031040050, 2755315674,
+        ] {
+            assert_eq![rng.next_u32(), expected];
+        }
+    }
+}